@@ -0,0 +1,27 @@
+//! Compare the Bresenham circle fill with the exact (distance) fill.
+//!
+//! Run with `cargo bench --bench fill_circle`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use textcanvas::TextCanvas;
+
+fn fill_circle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fill_circle");
+
+    for radius in [8, 32, 128] {
+        let mut canvas = TextCanvas::new(radius + 1, radius / 2 + 1);
+        let (x, y) = (canvas.cx(), canvas.cy());
+
+        group.bench_with_input(BenchmarkId::new("bresenham", radius), &radius, |b, &r| {
+            b.iter(|| canvas.fill_circle(black_box(x), black_box(y), black_box(r)));
+        });
+        group.bench_with_input(BenchmarkId::new("exact", radius), &radius, |b, &r| {
+            b.iter(|| canvas.fill_circle_exact(black_box(x), black_box(y), black_box(r)));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, fill_circle);
+criterion_main!(benches);
@@ -111,8 +111,8 @@ fn world_to_screen(point: Vec3D) -> Vec2D {
     let [x, y, _] = point;
     let w = CANVAS_WIDTH * 2.0;
     let h = CANVAS_HEIGHT * 4.0;
-    let x = ((x + 1.0) / 2.0) * w;
-    let y = h - ((y + 1.0) / 2.0) * h;
+    let x = f64::midpoint(x, 1.0) * w;
+    let y = h - f64::midpoint(y, 1.0) * h;
     [x, y]
 }
 
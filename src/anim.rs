@@ -0,0 +1,166 @@
+use std::time;
+
+use crate::TextCanvas;
+
+/// Cursor-home escape sequence (first row, first column).
+const CURSOR_HOME: &str = "\x1b[H";
+
+/// Help produce animation frames from a [`TextCanvas`].
+///
+/// This is a lightweight alternative to
+/// [`GameLoop`](crate::utils::GameLoop), when you want to keep control
+/// of the loop and of the output. [`Animation::frame()`] clears the
+/// canvas, lets you draw, and returns the rendered frame, prefixed with
+/// a cursor-home sequence so that printing it overwrites the previous
+/// frame in-place.
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::anim::Animation;
+/// use textcanvas::TextCanvas;
+///
+/// let mut animation = Animation::new(TextCanvas::new(3, 1));
+///
+/// for i in 0..3 {
+///     let frame = animation.frame(|canvas| {
+///         canvas.draw_text("o", i, 0);
+///     });
+///     print!("{frame}");
+/// }
+///
+/// assert_eq!(animation.canvas().to_string(), "⠀⠀o\n");
+/// ```
+pub struct Animation {
+    canvas: TextCanvas,
+    last_frame: Option<time::Instant>,
+    delta_time: f64,
+}
+
+impl Animation {
+    /// Create new `Animation`, wrapping `canvas`.
+    #[must_use]
+    pub fn new(canvas: TextCanvas) -> Self {
+        Self {
+            canvas,
+            last_frame: None,
+            delta_time: 0.0,
+        }
+    }
+
+    /// Produce a frame.
+    ///
+    /// The canvas is cleared, `draw` is called to draw the new frame,
+    /// and the canvas is rendered.
+    ///
+    /// The render is prefixed with a cursor-home escape sequence
+    /// (`\x1b[H`), and any terminating newline character is stripped,
+    /// so that the frame overwrites the previous one in-place, without
+    /// scrolling. Since there is no newline, you may have to flush
+    /// stdout after printing.
+    pub fn frame(&mut self, draw: impl FnOnce(&mut TextCanvas)) -> String {
+        let now = time::Instant::now();
+        self.delta_time = self
+            .last_frame
+            .map_or(0.0, |last_frame| (now - last_frame).as_secs_f64());
+        self.last_frame = Some(now);
+
+        self.canvas.clear();
+        draw(&mut self.canvas);
+
+        let render = self.canvas.to_string();
+        let render = render.strip_suffix('\n').unwrap_or(&render);
+        format!("{CURSOR_HOME}{render}")
+    }
+
+    /// Time elapsed between the last two frames, in seconds.
+    ///
+    /// This is `0.0` until the second frame. Like with
+    /// [`GameLoop::loop_variable()`](crate::utils::GameLoop::loop_variable),
+    /// use it to make animation speed independent of the frame rate.
+    #[must_use]
+    pub fn delta_time(&self) -> f64 {
+        self.delta_time
+    }
+
+    /// Get the wrapped canvas.
+    #[must_use]
+    pub fn canvas(&self) -> &TextCanvas {
+        &self.canvas
+    }
+
+    /// Get the wrapped canvas, mutably.
+    ///
+    /// Useful to change settings that survive `clear()` (e.g., the
+    /// inverted mode).
+    #[must_use]
+    pub fn canvas_mut(&mut self) -> &mut TextCanvas {
+        &mut self.canvas
+    }
+
+    /// Unwrap the canvas.
+    #[must_use]
+    pub fn into_canvas(self) -> TextCanvas {
+        self.canvas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame() {
+        let mut animation = Animation::new(TextCanvas::new(3, 2));
+
+        let first = animation.frame(|canvas| {
+            canvas.draw_text("1", 0, 0);
+        });
+        let second = animation.frame(|canvas| {
+            canvas.draw_text("2", 1, 1);
+        });
+
+        assert_eq!(first, "\x1b[H1⠀⠀\n⠀⠀⠀");
+        assert_eq!(second, "\x1b[H⠀⠀⠀\n⠀2⠀");
+    }
+
+    #[test]
+    fn frame_clears_pixels() {
+        let mut animation = Animation::new(TextCanvas::new(2, 1));
+
+        animation.frame(|canvas| {
+            canvas.set_pixel(0, 0, true);
+        });
+        let frame = animation.frame(|canvas| {
+            canvas.set_pixel(3, 3, true);
+        });
+
+        assert_eq!(frame, "\x1b[H⠀⢀");
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn delta_time() {
+        let mut animation = Animation::new(TextCanvas::new(1, 1));
+
+        animation.frame(|_| {});
+        assert_eq!(animation.delta_time(), 0.0);
+
+        std::thread::sleep(time::Duration::from_millis(5));
+        animation.frame(|_| {});
+        assert!(animation.delta_time() >= 0.005);
+    }
+
+    #[test]
+    fn canvas_accessors() {
+        let mut animation = Animation::new(TextCanvas::new(2, 1));
+
+        animation.canvas_mut().invert();
+        animation.frame(|canvas| {
+            canvas.fill_rect(0, 0, 2, 4);
+        });
+
+        assert!(animation.canvas().is_inverted);
+        assert_eq!(animation.into_canvas().to_string(), "⠀⠀\n");
+    }
+}
@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 
-use crate::TextCanvas;
+pub use crate::textcanvas::Rounding;
+use crate::{text_width, Color, TextCanvas};
 
 #[allow(clippy::trivially_copy_pass_by_ref)]
 fn cmp_f64(a: &&f64, b: &&f64) -> Ordering {
@@ -13,10 +14,76 @@ fn cmp_f64(a: &&f64, b: &&f64) -> Ordering {
     }
 }
 
+/// Min and max of the finite values (`NaN` and `±Inf` are ignored).
+pub(crate) fn finite_min_max(values: &[f64]) -> Option<(f64, f64)> {
+    values
+        .iter()
+        .filter(|value| value.is_finite())
+        .fold(None, |min_max, &value| match min_max {
+            None => Some((value, value)),
+            Some((min, max)) => Some((min.min(value), max.max(value))),
+        })
+}
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 enum PlotType {
     Line,
+    /// Line plot, joining points in the order they are given.
+    Path,
     Scatter,
+    /// Scatter plot, with a shape drawn at each point.
+    Marker(Marker),
+}
+
+/// Shape of the points of a scatter plot.
+///
+/// Except for [`Dot`](Marker::Dot), markers are 3×3 pixels, centered
+/// on the point.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Marker {
+    /// A single pixel (`•`).
+    Dot,
+    /// A plus sign (`+`).
+    Plus,
+    /// A diagonal cross (`×`).
+    Cross,
+    /// A hollow square (`□`).
+    Square,
+}
+
+impl Marker {
+    fn offsets(self) -> &'static [(i32, i32)] {
+        match self {
+            Self::Dot => &[(0, 0)],
+            Self::Plus => &[(0, -1), (-1, 0), (0, 0), (1, 0), (0, 1)],
+            Self::Cross => &[(-1, -1), (1, -1), (0, 0), (-1, 1), (1, 1)],
+            Self::Square => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        }
+    }
+
+    fn draw(self, canvas: &mut TextCanvas, x: i32, y: i32) {
+        for (dx, dy) in self.offsets() {
+            canvas.set_pixel(x + dx, y + dy, true);
+        }
+    }
+}
+
+/// Axis of a chart.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Axis {
+    /// Horizontal axis (bottom border of the chart).
+    X,
+    /// Vertical axis (left border of the chart).
+    Y,
 }
 
 /// Helper functions to plot data on a [`TextCanvas`].
@@ -53,6 +120,16 @@ enum PlotType {
 /// canvas. Use [`draw_canvas()`](TextCanvas::draw_canvas) or
 /// [`merge_canvas()`](TextCanvas::merge_canvas) from [`TextCanvas`] to
 /// do this easily.
+///
+/// # Note on non-finite values
+///
+/// Non-finite values (`NaN`, `±Inf`), like those resulting from `1/0`
+/// or `log(-1)`, cannot be plotted. They are ignored by auto-scaling,
+/// and are not drawn. Line plots are broken into segments at these
+/// points, leaving a gap instead of joining the values on either side.
+///
+/// This can be used on purpose, to hide discontinuities (see
+/// [`Plot::function()`]).
 pub struct Plot;
 
 impl Plot {
@@ -198,6 +275,155 @@ impl Plot {
         canvas.stroke_line(0, y, canvas.w(), y);
     }
 
+    /// Stroke dashed horizontal lines at the min, max, and mean of Y.
+    ///
+    /// This is a quick way to annotate the distribution of the data.
+    /// Lines are positioned like with
+    /// [`stroke_line_at_y()`](Plot::stroke_line_at_y).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+    ///
+    /// Plot::stroke_stats(&mut canvas, &y);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠉⠀⠉⠀⠉⠀⠉⠀⠉⠀⠉⠀⠉⠀⠉
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠤⠀⠤⠀⠤⠀⠤⠀⠤⠀⠤⠀⠤⠀⠤
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⣀⠀⣀⠀⣀⠀⣀⠀⣀⠀⣀⠀⣀⠀⣀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_stats(canvas: &mut TextCanvas, y: &[f64]) {
+        const DASH: i32 = 2;
+        const GAP: i32 = 2;
+
+        let (Some(min), Some(max)) = (y.iter().min_by(cmp_f64), y.iter().max_by(cmp_f64)) else {
+            return;
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let mean = y.iter().sum::<f64>() / y.len() as f64;
+
+        for value in [*min, *max, mean] {
+            if let Some(screen_y) = Self::compute_screen_y(canvas, value, y) {
+                canvas.stroke_line_dashed(0, screen_y, canvas.w(), screen_y, DASH, GAP);
+            }
+        }
+    }
+
+    /// Shade horizontal band between Y = `y_low` and Y = `y_high`.
+    ///
+    /// The band spans the full width of the canvas, and is positioned
+    /// like with [`stroke_line_at_y()`](Plot::stroke_line_at_y). It is
+    /// filled with a sparse pattern of pixels, in `color`, so that
+    /// lines drawn over it remain visible. This is useful to mark a
+    /// target or an acceptable range of values, behind a plot.
+    ///
+    /// The context color of the canvas is left untouched.
+    ///
+    /// If a bound is out of the range of Y values, the band is
+    /// clipped to the canvas.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+    /// let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+    ///
+    /// Plot::shade_band(&mut canvas, -2.5, 2.5, &y, Color::new().bright_gray());
+    /// Plot::line(&mut canvas, &x, &y);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_plain_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⠤⠒⠉
+    /// ⡂⡂⡂⡂⡂⡂⡂⡂⣂⡦⡊⡃⡂⡂⡂
+    /// ⡂⡂⡂⡂⡂⣂⡦⡊⡃⡂⡂⡂⡂⡂⡂
+    /// ⡂⡂⣂⡦⡊⡃⡂⡂⡂⡂⡂⡂⡂⡂⡂
+    /// ⡠⠊⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn shade_band(
+        canvas: &mut TextCanvas,
+        y_low: f64,
+        y_high: f64,
+        y_data: &[f64],
+        color: &Color,
+    ) {
+        const PATTERN: [[bool; 2]; 2] = [[true, false], [false, false]];
+
+        let (Some(low), Some(high)) = (
+            Self::compute_screen_y(canvas, y_low, y_data),
+            Self::compute_screen_y(canvas, y_high, y_data),
+        ) else {
+            return;
+        };
+        let top = low.min(high).max(0);
+        let bottom = low.max(high).min(canvas.h());
+        if top > bottom {
+            return;
+        }
+
+        let mut band = TextCanvas::new(canvas.output.width(), canvas.output.height());
+        band.set_color(color);
+        band.fill_rect_stipple(0, top, canvas.screen.width(), bottom - top + 1, &PATTERN);
+
+        canvas.merge_canvas(&band, 0, 0);
+    }
+
+    /// Set rounding policy of screen coordinates, for this canvas.
+    ///
+    /// All `Plot` functions drawing on `canvas` afterward round screen
+    /// coordinates according to `rounding`. Data and axes share the
+    /// policy, and with [`Round`](Rounding::Round) or
+    /// [`Floor`](Rounding::Floor), axes align exactly with the data.
+    /// [`Chart`] functions drawing on `canvas` use the policy too.
+    ///
+    /// The default is [`Trunc`](Rounding::Trunc).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::{Plot, Rounding}};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+    /// let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+    ///
+    /// Plot::set_rounding(&mut canvas, Rounding::Round);
+    /// Plot::stroke_xy_axes(&mut canvas, &x, &y);
+    /// Plot::line(&mut canvas, &x, &y);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⣀⠔⠊
+    /// ⠀⠀⠀⠀⠀⠀⠀⢸⠀⣀⠔⠊⠀⠀⠀
+    /// ⠤⠤⠤⠤⠤⠤⣤⢼⠮⠤⠤⠤⠤⠤⠤
+    /// ⠀⠀⠀⡠⠔⠉⠀⢸⠀⠀⠀⠀⠀⠀⠀
+    /// ⡠⠔⠉⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn set_rounding(canvas: &mut TextCanvas, rounding: Rounding) {
+        canvas.plot_rounding = rounding;
+    }
+
     /// Compute X position of a value on the canvas.
     ///
     /// Remember, values are auto-scaled to fit the canvas. If X goes
@@ -219,14 +445,18 @@ impl Plot {
     /// assert_eq!(29, Plot::compute_screen_x(&canvas, 10.0, &x).unwrap());
     /// assert_eq!(14, Plot::compute_screen_x(&canvas, 0.0, &x).unwrap());
     /// ```
-    #[allow(clippy::cast_possible_truncation, clippy::missing_panics_doc)]
+    ///
+    /// Non-finite values (`NaN`, `±Inf`) have no position, and are
+    /// ignored when computing the range of X.
+    ///
+    /// The position is rounded according to
+    /// [`set_rounding()`](Plot::set_rounding).
+    #[allow(clippy::cast_possible_truncation)]
     pub fn compute_screen_x(canvas: &TextCanvas, value: f64, x: &[f64]) -> Option<i32> {
-        if x.is_empty() {
+        if !value.is_finite() {
             return None;
         }
-
-        let min_x = x.iter().min_by(cmp_f64).expect("cannot be empty");
-        let max_x = x.iter().max_by(cmp_f64).expect("cannot be empty");
+        let (min_x, max_x) = finite_min_max(x)?;
         let range_x = max_x - min_x;
         let scale_x = canvas.fw() / range_x;
 
@@ -238,7 +468,7 @@ impl Plot {
 
         // Shift data left, so that `min_x` would = 0, then scale so
         // that `max_x` would = width.
-        let x = ((value - min_x) * scale_x).trunc() as i32;
+        let x = canvas.plot_rounding.apply((value - min_x) * scale_x) as i32;
 
         Some(x)
     }
@@ -264,14 +494,18 @@ impl Plot {
     /// assert_eq!(0, Plot::compute_screen_y(&canvas, 10.0, &y).unwrap());
     /// assert_eq!(10, Plot::compute_screen_y(&canvas, 0.0, &y).unwrap());
     /// ```
-    #[allow(clippy::cast_possible_truncation, clippy::missing_panics_doc)]
+    ///
+    /// Non-finite values (`NaN`, `±Inf`) have no position, and are
+    /// ignored when computing the range of Y.
+    ///
+    /// The position is rounded according to
+    /// [`set_rounding()`](Plot::set_rounding).
+    #[allow(clippy::cast_possible_truncation)]
     pub fn compute_screen_y(canvas: &TextCanvas, value: f64, y: &[f64]) -> Option<i32> {
-        if y.is_empty() {
+        if !value.is_finite() {
             return None;
         }
-
-        let min_y = y.iter().min_by(cmp_f64).expect("cannot be empty");
-        let max_y = y.iter().max_by(cmp_f64).expect("cannot be empty");
+        let (min_y, max_y) = finite_min_max(y)?;
         let range_y = max_y - min_y;
         let scale_y = canvas.fh() / range_y;
 
@@ -283,10 +517,14 @@ impl Plot {
 
         // Shift data down, so that `min_y` would = 0, then scale so
         // that `max_y` would = height.
-        let mut y = ((value - min_y) * scale_y).trunc() as i32;
-        y = canvas.h() - y; // Y-axis is inverted.
+        let y = (value - min_y) * scale_y;
+        let y = match canvas.plot_rounding {
+            // Truncate before inverting (see `Rounding::Trunc`).
+            Rounding::Trunc => canvas.fh() - y.trunc(),
+            rounding => rounding.apply(canvas.fh() - y), // Y-axis is inverted.
+        };
 
-        Some(y)
+        Some(y as i32)
     }
 
     /// Stroke X and Y axes, given a function.
@@ -507,7 +745,9 @@ impl Plot {
 
     /// Plot line-joined points.
     ///
-    /// The data is scaled to take up the entire canvas.
+    /// The data is scaled to take up the entire canvas. Points are
+    /// sorted by X before plotting (use
+    /// [`line_unsorted()`](Plot::line_unsorted) to keep their order).
     ///
     /// <div class="warning">
     ///
@@ -545,6 +785,112 @@ impl Plot {
         Self::plot(canvas, x, y, PlotType::Line);
     }
 
+    /// Plot a line, joining points in the order they are given.
+    ///
+    /// Contrary to [`line()`](Plot::line), points are not sorted by X
+    /// before plotting. Use this when the order of the points is
+    /// meaningful (e.g., parametric data, paths, trajectories), or to
+    /// skip sorting when the data is already sorted by X.
+    ///
+    /// The data is scaled to take up the entire canvas.
+    ///
+    /// <div class="warning">
+    ///
+    /// `x` and `y` _should_ match in length,
+    ///
+    /// If `x` and `y` are not the same length, plotting will stop once
+    /// the smallest of the two collections is consumed.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// // Triangle, going back to where it started.
+    /// let x: Vec<f64> = vec![0.0, 2.0, 1.0, 0.0];
+    /// let y: Vec<f64> = vec![0.0, 0.0, 2.0, 0.0];
+    ///
+    /// Plot::line_unsorted(&mut canvas, &x, &y);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⡰⠑⡄⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⢀⠎⠀⠀⠈⠢⡀⠀⠀⠀⠀
+    /// ⠀⠀⠀⡰⠁⠀⠀⠀⠀⠀⠑⢄⠀⠀⠀
+    /// ⠀⢀⠎⠀⠀⠀⠀⠀⠀⠀⠀⠈⠢⡀⠀
+    /// ⣰⣁⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣘⣄
+    /// "
+    /// );
+    /// ```
+    pub fn line_unsorted(canvas: &mut TextCanvas, x: &[f64], y: &[f64]) {
+        Self::plot(canvas, x, y, PlotType::Path);
+    }
+
+    /// Plot a sequence of values, with their indices as X.
+    ///
+    /// This saves building the vector of indices by hand. Values are
+    /// line-joined, in order.
+    ///
+    /// `inclusive_end` sets the end of the X axis. If it is `false`, the
+    /// axis maps `0..n` (the indices themselves), and the last value is
+    /// on the right edge of the canvas. If it is `true`, the axis maps
+    /// `0..=n`, as if for the next value to come, which leaves one step
+    /// of space after the last value. The latter is useful to keep the
+    /// scale aligned with bars or cells of width 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let y = [0.0, 3.0, 1.0, 2.0];
+    ///
+    /// let mut canvas = TextCanvas::new(12, 3);
+    /// Plot::sequence(&mut canvas, &y, false);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⢀⠎⠢⡀⠀⠀⠀⠀⠀⣀
+    /// ⠀⢠⠃⠀⠀⠈⠑⢄⠤⠒⠉⠀
+    /// ⡰⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    ///
+    /// let mut canvas = TextCanvas::new(12, 3);
+    /// Plot::sequence(&mut canvas, &y, true);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⡜⠢⡀⠀⠀⠀⢀⠀⠀⠀
+    /// ⠀⡜⠀⠀⠑⢄⠤⠊⠁⠀⠀⠀
+    /// ⡜⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn sequence(canvas: &mut TextCanvas, y: &[f64], inclusive_end: bool) {
+        #[allow(clippy::cast_precision_loss)]
+        let mut x: Vec<f64> = (0..y.len()).map(|i| i as f64).collect();
+        let mut y = y.to_vec();
+
+        if inclusive_end && !y.is_empty() {
+            // Extend the range of X to `n`, without plotting anything
+            // there (non-finite values are skipped, but X still counts
+            // towards the range).
+            #[allow(clippy::cast_precision_loss)]
+            x.push(y.len() as f64);
+            y.push(f64::NAN);
+        }
+
+        Self::plot(canvas, &x, &y, PlotType::Path);
+    }
+
     /// Plot scattered points.
     ///
     /// The data is scaled to take up the entire canvas.
@@ -585,58 +931,321 @@ impl Plot {
         Self::plot(canvas, x, y, PlotType::Scatter);
     }
 
-    #[allow(clippy::cast_possible_truncation)]
-    fn plot(canvas: &mut TextCanvas, x: &[f64], y: &[f64], plot_type: PlotType) {
+    /// Plot scattered points, drawn as markers.
+    ///
+    /// This is like [`scatter()`](Plot::scatter), but each point is
+    /// drawn with the shape of `marker`, which makes points stand out
+    /// more. Markers may overflow the canvas a little at the edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::{Marker, Plot}};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let x = [0.0, 1.0, 2.0];
+    /// let y = [0.0, 1.0, 0.0];
+    ///
+    /// Plot::scatter_with_marker(&mut canvas, &x, &y, Marker::Plus);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠈⠋⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⣄⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣠
+    /// "
+    /// );
+    /// ```
+    pub fn scatter_with_marker(canvas: &mut TextCanvas, x: &[f64], y: &[f64], marker: Marker) {
+        Self::plot(canvas, x, y, PlotType::Marker(marker));
+    }
+
+    /// Plot line-joined points, with markers on the points.
+    ///
+    /// This is [`line()`](Plot::line) and
+    /// [`scatter_with_marker()`](Plot::scatter_with_marker) in one
+    /// call. Both share the same auto-scale, so markers sit exactly on
+    /// the vertices of the line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::{Marker, Plot}};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let x = [0.0, 1.0, 2.0, 3.0];
+    /// let y = [0.0, 2.0, 1.0, 3.0];
+    ///
+    /// Plot::line_with_markers(&mut canvas, &x, &y, Marker::Square);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢠⠛
+    /// ⠀⠀⠀⠀⣶⣆⠀⠀⠀⠀⠀⢀⠔⠁⠀
+    /// ⠀⠀⠀⡔⠁⠀⠉⠢⢄⣀⡠⠃⠀⠀⠀
+    /// ⠀⢠⠊⠀⠀⠀⠀⠀⠀⠛⠃⠀⠀⠀⠀
+    /// ⣴⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn line_with_markers(canvas: &mut TextCanvas, x: &[f64], y: &[f64], marker: Marker) {
+        Self::line(canvas, x, y);
+        Self::scatter_with_marker(canvas, x, y, marker);
+    }
+
+    /// Plot bars, from a baseline.
+    ///
+    /// For each point, a vertical bar is drawn from the baseline to the
+    /// point. Values above the baseline make upward bars, values below
+    /// it make downward bars. Use a baseline of _0_ for signed data.
+    ///
+    /// The data is scaled to take up the entire canvas. The baseline
+    /// is always made visible, even if it falls outside of the values.
+    ///
+    /// <div class="warning">
+    ///
+    /// `x` and `y` _should_ match in length,
+    ///
+    /// If `x` and `y` are not the same length, plotting will stop once
+    /// the smallest of the two collections is consumed.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let x: Vec<f64> = (0..15).map(f64::from).collect();
+    /// let y: Vec<f64> = vec![
+    ///     3.0, 5.0, 2.0, -1.0, -4.0, -2.0, 1.0, 4.0, 5.0, 3.0, -3.0, -5.0, -2.0, 2.0, 1.0,
+    /// ];
+    ///
+    /// Plot::bars_from(&mut canvas, &x, &y, 0.0);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⡇⠀⠀⠀⠀⠀⡄⡇⠀⠀⠀⠀⠀⠀
+    /// ⡇⡇⡄⠀⠀⠀⠀⡇⡇⡇⠀⠀⠀⡄⠀
+    /// ⠇⠇⠇⡄⡄⡄⠇⠇⠇⠇⡄⡄⡄⠇⠸
+    /// ⠀⠀⠀⠁⡇⠇⠀⠀⠀⠀⡇⡇⠇⠀⠀
+    /// ⠀⠀⠀⠀⠇⠀⠀⠀⠀⠀⠁⡇⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn bars_from(canvas: &mut TextCanvas, x: &[f64], y: &[f64], baseline: f64) {
         if x.is_empty() || y.is_empty() {
             return;
         }
 
-        let mut pairs: Vec<(&f64, &f64)> = x.iter().zip(y).collect();
-        if plot_type == PlotType::Line {
-            // Sort by `x`;
-            pairs.sort_by(|a, b| cmp_f64(&a.0, &b.0));
-        }
-
-        let min_x = x.iter().min_by(cmp_f64).expect("cannot be empty");
-        let max_x = x.iter().max_by(cmp_f64).expect("cannot be empty");
-        let range_x = max_x - min_x;
-        let scale_x = canvas.fw() / range_x;
+        // Include the baseline in the range, so that it is visible.
+        let mut range_y = y.to_vec();
+        range_y.push(baseline);
 
-        let min_y = y.iter().min_by(cmp_f64).expect("cannot be empty");
-        let max_y = y.iter().max_by(cmp_f64).expect("cannot be empty");
-        let range_y = max_y - min_y;
-        let scale_y = canvas.fh() / range_y;
+        let Some(screen_baseline) = Self::compute_screen_y(canvas, baseline, &range_y) else {
+            return;
+        };
 
-        // If `range = 0`. Division of a positive number by zero
-        // results in +Inf.
-        if scale_x.is_infinite() || scale_y.is_infinite() {
-            // One or both axis have no range. This doesn't make sense
-            // for plotting with auto-scale.
-            return Self::handle_axes_without_range(
-                canvas,
-                x,
-                y,
-                plot_type,
-                scale_x.is_infinite(),
-                scale_y.is_infinite(),
-            );
+        for (&value_x, &value_y) in x.iter().zip(y) {
+            let (Some(screen_x), Some(screen_y)) = (
+                Self::compute_screen_x(canvas, value_x, x),
+                Self::compute_screen_y(canvas, value_y, &range_y),
+            ) else {
+                continue;
+            };
+            canvas.stroke_line(screen_x, screen_baseline, screen_x, screen_y);
         }
+    }
+
+    /// Plot bars of varying widths.
+    ///
+    /// Each bar spans from one edge to the next, and goes from _0_ to
+    /// its height: `edges[i]` and `edges[i + 1]` are the left and right
+    /// X of the bar of height `heights[i]`. This properly represents
+    /// non-uniform bins (e.g., histograms with unequal bins).
+    ///
+    /// The data is scaled to take up the entire canvas. _0_ is always
+    /// made visible, even if it falls outside of the heights. Bars with
+    /// non-finite edges or height (`NaN`, `±Inf`) are skipped.
+    ///
+    /// <div class="warning">
+    ///
+    /// `edges` _should_ have one more element than `heights`.
+    ///
+    /// If not, plotting will stop once either collection is consumed.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let edges = [0.0, 1.0, 3.0];
+    /// let heights = [2.0, 1.0];
+    ///
+    /// Plot::bars_ranged(&mut canvas, &edges, &heights);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⣿⣿⣿⣿⣿⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⣿⣿⣿⣿⣿⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⣿⣿⣿⣿⣿⣤⣤⣤⣤⣤⣤⣤⣤⣤⣤
+    /// ⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
+    /// ⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
+    /// "
+    /// );
+    /// ```
+    pub fn bars_ranged(canvas: &mut TextCanvas, edges: &[f64], heights: &[f64]) {
+        if edges.len() < 2 || heights.is_empty() {
+            return;
+        }
+
+        // Include 0 in the range, so that bars have a visible base.
+        let mut range_y = heights.to_vec();
+        range_y.push(0.0);
+
+        let Some(screen_baseline) = Self::compute_screen_y(canvas, 0.0, &range_y) else {
+            return;
+        };
+
+        for (edge, &height) in edges.windows(2).zip(heights) {
+            let (Some(left), Some(right), Some(top)) = (
+                Self::compute_screen_x(canvas, edge[0], edges),
+                Self::compute_screen_x(canvas, edge[1], edges),
+                Self::compute_screen_y(canvas, height, &range_y),
+            ) else {
+                continue;
+            };
+            let (left, right) = (left.min(right), left.max(right));
+            let (top, bottom) = (top.min(screen_baseline), top.max(screen_baseline));
+            canvas.fill_rect(left, top, right - left + 1, bottom - top + 1);
+        }
+    }
+
+    /// Render values as a sparkline.
+    ///
+    /// A sparkline is a tiny, one-line chart, meant to be inlined in
+    /// text (e.g., in a status line). Contrary to the other functions,
+    /// this does not draw onto a canvas, but returns a string directly.
+    ///
+    /// Each value is drawn as a bar, one dot wide, so there are two
+    /// values per character. Braille characters are 4 dots high, so
+    /// bars are 1 (min value) to 4 (max value) dots high. If all values
+    /// are the same, bars are drawn at mid-height.
+    ///
+    /// Non-finite values (`NaN`, `±Inf`) are left blank. The string
+    /// does not end with a newline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::charts::Plot;
+    ///
+    /// let values = [1.0, 5.0, 2.0, 8.0, 3.0, 7.0, 4.0, 6.0];
+    ///
+    /// assert_eq!(Plot::sparkline(&values), "⣰⣸⣼⣴");
+    /// ```
+    #[must_use]
+    pub fn sparkline(values: &[f64]) -> String {
+        let finite = values.iter().filter(|value| value.is_finite());
+        let Some(min) = finite.clone().min_by(cmp_f64) else {
+            // Empty, or no finite values.
+            return values.iter().step_by(2).map(|_| '⠀').collect();
+        };
+        let max = finite.max_by(cmp_f64).unwrap_or(min);
+        let range = max - min;
+
+        let width = i32::try_from(values.len().div_ceil(2)).unwrap_or(i32::MAX);
+        let mut canvas = TextCanvas::new(width, 1);
+
+        for (x, &value) in (0..).zip(values) {
+            if !value.is_finite() {
+                continue;
+            }
+            let t = if range == 0.0 {
+                0.5
+            } else {
+                (value - min) / range
+            };
+            // `t` is in `[0; 1]`, height is in `[1; 4]`.
+            #[allow(clippy::cast_possible_truncation)]
+            let height = 1 + (t * 3.0).round() as i32;
+            canvas.stroke_line(x, 3, x, 4 - height);
+        }
+
+        let mut sparkline = canvas.to_string();
+        sparkline.pop(); // Trailing newline.
+        sparkline
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn plot(canvas: &mut TextCanvas, x: &[f64], y: &[f64], plot_type: PlotType) {
+        let (Some((min_x, max_x)), Some((min_y, max_y))) = (finite_min_max(x), finite_min_max(y))
+        else {
+            return; // Empty, or no finite values.
+        };
+
+        let mut pairs: Vec<(&f64, &f64)> = x.iter().zip(y).collect();
+        if plot_type == PlotType::Line {
+            // Sort by `x` (`Path` keeps the order of the data);
+            pairs.sort_by(|a, b| a.0.total_cmp(b.0));
+        }
+
+        let range_x = max_x - min_x;
+        let scale_x = canvas.fw() / range_x;
+
+        let range_y = max_y - min_y;
+        let scale_y = canvas.fh() / range_y;
+
+        // If `range = 0`. Division of a positive number by zero
+        // results in +Inf.
+        if scale_x.is_infinite() || scale_y.is_infinite() {
+            // One or both axis have no range. This doesn't make sense
+            // for plotting with auto-scale.
+            return Self::handle_axes_without_range(
+                canvas,
+                x,
+                y,
+                plot_type,
+                scale_x.is_infinite(),
+                scale_y.is_infinite(),
+            );
+        }
+
+        let rounding = canvas.plot_rounding;
+        let mut previous: Option<(i32, i32)> = None; // For line plot.
+        for (x, y) in pairs {
+            if !x.is_finite() || !y.is_finite() {
+                previous = None; // Break the line.
+                continue;
+            }
 
-        let mut previous: Option<(i32, i32)> = None; // For line plot.
-        for (x, y) in pairs {
             let mut x = *x;
             // Shift data left so that `min_x` = 0, then scale so that
             // `max_x` = width.
             x = (x - min_x) * scale_x;
-            let x = x.trunc() as i32;
+            let x = rounding.apply(x) as i32;
 
             let mut y = *y;
             y = (y - min_y) * scale_y;
             y = canvas.fh() - y; // Y-axis is inverted.
-            let y = y.trunc() as i32;
+            let y = rounding.apply(y) as i32;
 
             match plot_type {
-                PlotType::Line => {
+                PlotType::Line | PlotType::Path => {
                     let pair = (x, y);
 
                     if let Some(previous) = previous {
@@ -648,6 +1257,9 @@ impl Plot {
                 PlotType::Scatter => {
                     canvas.set_pixel(x, y, true);
                 }
+                PlotType::Marker(marker) => {
+                    marker.draw(canvas, x, y);
+                }
             }
         }
     }
@@ -673,19 +1285,19 @@ impl Plot {
         } else if both_have_no_range {
             // Draw a dot in the middle to show the user we tried to do
             // something, but the values are off.
-            canvas.set_pixel(canvas.cx(), canvas.cy(), true);
+            Self::draw_point(canvas, canvas.cx(), canvas.cy(), plot_type);
         }
     }
 
     fn draw_horizontally_centered_line(canvas: &mut TextCanvas, x: &[f64], plot_type: PlotType) {
         match plot_type {
-            PlotType::Line => {
+            PlotType::Line | PlotType::Path => {
                 canvas.stroke_line(0, canvas.cy(), canvas.w(), canvas.cy());
             }
-            PlotType::Scatter => {
+            PlotType::Scatter | PlotType::Marker(_) => {
                 for &x_val in x {
                     if let Some(x) = Self::compute_screen_x(canvas, x_val, x) {
-                        canvas.set_pixel(x, canvas.cy(), true);
+                        Self::draw_point(canvas, x, canvas.cy(), plot_type);
                     }
                 }
             }
@@ -694,19 +1306,27 @@ impl Plot {
 
     fn draw_vertically_centered_line(canvas: &mut TextCanvas, y: &[f64], plot_type: PlotType) {
         match plot_type {
-            PlotType::Line => {
+            PlotType::Line | PlotType::Path => {
                 canvas.stroke_line(canvas.cx(), 0, canvas.cx(), canvas.h());
             }
-            PlotType::Scatter => {
+            PlotType::Scatter | PlotType::Marker(_) => {
                 for &y_val in y {
                     if let Some(y) = Self::compute_screen_y(canvas, y_val, y) {
-                        canvas.set_pixel(canvas.cx(), y, true);
+                        Self::draw_point(canvas, canvas.cx(), y, plot_type);
                     }
                 }
             }
         }
     }
 
+    fn draw_point(canvas: &mut TextCanvas, x: i32, y: i32, plot_type: PlotType) {
+        if let PlotType::Marker(marker) = plot_type {
+            marker.draw(canvas, x, y);
+        } else {
+            canvas.set_pixel(x, y, true);
+        }
+    }
+
     /// Plot a function.
     ///
     /// The function is scaled to take up the entire canvas, and is
@@ -732,169 +1352,877 @@ impl Plot {
     /// "
     /// );
     /// ```
+    ///
+    /// Where `f` returns a non-finite value, the line is broken. This
+    /// can be used to leave gaps at discontinuities, instead of joining
+    /// them with vertical spikes:
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let tan = |x: f64| {
+    ///     let y = x.tan();
+    ///     if y.abs() > 5.0 { f64::NAN } else { y }
+    /// };
+    ///
+    /// Plot::function(&mut canvas, -5.0, 5.0, &tan);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⢰⠀⠀⠀⠀⡄⠀⠀⠀⢠⠀
+    /// ⠀⠀⠀⢀⠇⠀⠀⠀⡰⠁⠀⠀⠀⡎⠀
+    /// ⠀⠀⡔⠁⠀⠀⢀⠖⠁⠀⠀⡠⠊⠀⠀
+    /// ⠀⡸⠀⠀⠀⢀⠇⠀⠀⠀⢰⠁⠀⠀⠀
+    /// ⠀⠁⠀⠀⠀⠈⠀⠀⠀⠀⠃⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
     pub fn function(canvas: &mut TextCanvas, from_x: f64, to_x: f64, f: &impl Fn(f64) -> f64) {
         let nb_values = canvas.screen.fwidth();
         let (x, y) = Self::compute_function(from_x, to_x, nb_values, f);
         Self::line(canvas, &x, &y);
     }
 
-    /// Compute the values of a function.
-    ///
-    /// This is mainly used internally to compute values for functions.
-    ///
-    /// However, it may also be useful in case one wants to pre-compute
-    /// values.
+    /// Plot a function, with adaptive sampling.
     ///
-    /// # Note
+    /// This is like [`function()`](Plot::function), but instead of
+    /// sampling the function uniformly, intervals are subdivided where
+    /// the function deviates from a straight line by more than a pixel
+    /// (vertically). Flat regions get few points, and sharp features
+    /// get many, which produces crisper curves for functions with
+    /// localized detail.
     ///
-    /// The return value of the function is generic. You can use
-    /// [`compute_function()`](Plot::compute_function) to compute
-    /// anything, but if the values of Y are not `f64`s, you will need
-    /// to adapt them before use.
+    /// Sampling starts uniform (like `function()`), and is then refined
+    /// until the curve is smooth to the pixel, or until `max_points`
+    /// are computed. The bounds are always computed, even if
+    /// `max_points < 2`.
     ///
-    /// This is useful for optimisation. Say you have an expensive
-    /// function that returns a `struct` with multiple fields. If only
-    /// `f64`s were allowed, you would have to re-compute the exact same
-    /// function for each field of the struct. But thanks to the generic
-    /// return type, you can compute the function _once_, and extract
-    /// the fields into separate vectors by `map()`ping the values.
+    /// Note: Features narrower than the initial sampling interval can
+    /// still be missed entirely, if no sample lands on them.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use textcanvas::{TextCanvas, charts::Plot};
-    /// # let mut canvas = TextCanvas::new(15, 5);
-    /// # let mut canvas2 = TextCanvas::new(15, 5);
-    /// #
-    /// let f = |x: f64| x.sin();
+    /// use textcanvas::{TextCanvas, charts::Plot};
     ///
-    /// // This is inefficient, because `f()` will be computed twice.
-    /// Plot::stroke_xy_axes_of_function(&mut canvas, -3.0, 7.0, &f);
-    /// Plot::function(&mut canvas, -3.0, 7.0, &f);
+    /// // Slope, with a sharp spike.
+    /// let f = |x: f64| x + 2.0 / (1.0 + ((x - 0.13) / 0.01).powi(2));
     ///
-    /// // This is better, the values are computed only once.
-    /// let (x, y) = Plot::compute_function(-3.0, 7.0, canvas2.screen.fwidth(), &f);
-    /// Plot::stroke_xy_axes(&mut canvas2, &x, &y);
-    /// Plot::line(&mut canvas2, &x, &y);
+    /// let mut canvas = TextCanvas::new(15, 5);
+    /// Plot::function_adaptive(&mut canvas, -1.0, 1.0, &f, 200);
     ///
-    /// assert_eq!(canvas.to_string(), canvas2.to_string());
-    /// ```
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⢀⡠
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⣇⡠⠤⠒⠉⠁⠀
+    /// ⠀⠀⠀⠀⣀⠤⠒⠊⠀⠀⠀⠀⠀⠀⠀
+    /// ⡠⠔⠊⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
     ///
-    /// Note that the "inefficient" solution is unlikely to cause a
-    /// noticeable performance hit. The simpler approach is most often
-    /// the better approach.
-    pub fn compute_function<T>(
+    /// // Compare with uniform sampling, which nearly misses the spike.
+    /// let mut uniform = TextCanvas::new(15, 5);
+    /// Plot::function(&mut uniform, -1.0, 1.0, &f);
+    ///
+    /// assert_eq!(
+    ///     uniform.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⡠⠒⠉
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⡤⡠⠒⠁⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⢀⡠⠊⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⢀⡠⠒⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⡠⠒⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn function_adaptive(
+        canvas: &mut TextCanvas,
         from_x: f64,
         to_x: f64,
-        nb_values: f64,
-        f: &impl Fn(f64) -> T,
-    ) -> (Vec<f64>, Vec<T>) {
-        let range = to_x - from_x;
-        // If we want 5 values in a range including bounds, we need to
-        // divide the range into 4 equal pieces:
-        //   1   2   3   4
-        // |   |   |   |   |
-        // 1   2   3   4   5
-        let step = range / (nb_values - 1.0);
-
-        // This is fine. `nb_values` will realistically never be big
-        // enough to overflow `usize`, and even then, this is just for
-        // pre-allocation.
-        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        let nb_values = nb_values.ceil() as usize;
-        let mut px: Vec<f64> = Vec::with_capacity(nb_values);
-        let mut py: Vec<T> = Vec::with_capacity(nb_values);
-
-        // Always add first value.
-        px.push(from_x);
-        py.push(f(from_x));
-
-        let mut x = from_x + step;
-        while x < to_x {
-            px.push(x);
-            py.push(f(x));
+        f: &impl Fn(f64) -> f64,
+        max_points: usize,
+    ) {
+        #[allow(clippy::cast_precision_loss)]
+        let nb_values = canvas.screen.fwidth().min(max_points as f64).max(2.0);
+        let (mut x, mut y) = Self::compute_function(from_x, to_x, nb_values, f);
+
+        while x.len() < max_points {
+            let Some((min_y, max_y)) = finite_min_max(&y) else {
+                break;
+            };
+            // If `range = 0`, any deviation is infinite.
+            let scale_y = canvas.fh() / (max_y - min_y);
+            let mut budget = max_points - x.len();
+
+            let mut refined_x = Vec::with_capacity(x.len() * 2);
+            let mut refined_y = Vec::with_capacity(y.len() * 2);
+            for i in 0..x.len() - 1 {
+                refined_x.push(x[i]);
+                refined_y.push(y[i]);
+
+                if budget == 0 {
+                    continue;
+                }
+                let middle_x = f64::midpoint(x[i], x[i + 1]);
+                // Precision is exhausted, the interval cannot be split.
+                #[allow(clippy::float_cmp)]
+                if middle_x == x[i] || middle_x == x[i + 1] {
+                    continue;
+                }
+                let middle_y = f(middle_x);
+                let deviation = (middle_y - f64::midpoint(y[i], y[i + 1])).abs() * scale_y;
+                if deviation > 1.0 {
+                    refined_x.push(middle_x);
+                    refined_y.push(middle_y);
+                    budget -= 1;
+                }
+            }
+            refined_x.push(x[x.len() - 1]);
+            refined_y.push(y[y.len() - 1]);
 
-            x += step;
+            if refined_x.len() == x.len() {
+                break;
+            }
+            (x, y) = (refined_x, refined_y);
         }
 
-        // Always add last value.
-        px.push(to_x);
-        py.push(f(to_x));
-
-        (px, py)
+        Self::line(canvas, &x, &y);
     }
-}
-
-/// Helper functions to render charts on a [`TextCanvas`].
-///
-/// Basically, this renders a [`Plot`] and makes it pretty.
-///
-/// The idea comes from <https://github.com/sunetos/TextPlots.jl>.
-pub struct Chart;
-
-impl Chart {
-    const MARGIN_TOP: i32 = 1;
-    const MARGIN_RIGHT: i32 = 2;
-    const MARGIN_BOTTOM: i32 = 2;
-    const MARGIN_LEFT: i32 = 10;
 
-    const HORIZONTAL_MARGIN: i32 = Self::MARGIN_LEFT + Self::MARGIN_RIGHT;
-    const VERTICAL_MARGIN: i32 = Self::MARGIN_TOP + Self::MARGIN_BOTTOM;
-
-    /// Render chart with a line plot.
+    /// Plot a function, leaving gaps at discontinuities.
+    ///
+    /// This is like [`function()`](Plot::function), but the line is
+    /// broken wherever two consecutive points are more than
+    /// `jump_threshold` screen pixels apart vertically. This prevents
+    /// functions like `1/x` or `tan(x)` from being joined by misleading
+    /// vertical lines through their asymptotes.
+    ///
+    /// Choose `jump_threshold` larger than the steepest legitimate
+    /// slope of the function (in pixels per column), or it will be
+    /// broken where it shouldn't. See also the section on non-finite
+    /// values in [`Plot`], to break the line manually.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use textcanvas::{charts::Chart, TextCanvas};
+    /// use textcanvas::{TextCanvas, charts::Plot};
     ///
-    /// let mut canvas = TextCanvas::new(35, 10);
+    /// let mut canvas = TextCanvas::new(15, 5);
     ///
-    /// let x: Vec<f64> = (-5..=5).map(f64::from).collect();
-    /// let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+    /// let f = |x: f64| 1.0 / x;
     ///
-    /// Chart::line(&mut canvas, &x, &y);
+    /// Plot::function_segmented(&mut canvas, -1.0, 1.0, &f, 10.0);
     ///
     /// assert_eq!(
     ///     canvas.to_string(),
     ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⠀5⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠤⠒⠉⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠤⠊⠀⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⡠⠒⠉⠀⠀⠀⠀⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⠤⠊⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⢀⡠⠔⠊⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⢀⡠⠔⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⡠⠒⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀-5⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀-5⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀5
+    /// ⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀
+    /// ⠤⠤⠤⣀⣀⣀⠀⠀⠈⠒⠒⠒⠢⠤⠤
+    /// ⠀⠀⠀⠀⠀⠀⢱⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
     /// "
     /// );
-    /// ```
-    ///
-    /// # Panics
-    ///
-    /// Panics if chart is < 13×4, because it would make plot < 1×1.
-    pub fn line(canvas: &mut TextCanvas, x: &[f64], y: &[f64]) {
-        Self::chart(canvas, x, y, PlotType::Line);
-    }
-
-    /// Render chart with a scatter plot.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use textcanvas::{charts::Chart, TextCanvas};
-    ///
-    /// let mut canvas = TextCanvas::new(35, 10);
-    ///
-    /// let x: Vec<f64> = (-5..=5).map(f64::from).collect();
-    /// let y: Vec<f64> = (-5..=5).map(f64::from).collect();
     ///
-    /// Chart::scatter(&mut canvas, &x, &y);
+    /// // Compare with the line going through the asymptote.
+    /// let mut joined = TextCanvas::new(15, 5);
+    /// Plot::function(&mut joined, -1.0, 1.0, &f);
     ///
     /// assert_eq!(
-    ///     canvas.to_string(),
+    ///     joined.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⢸⡀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⢸⢇⠀⠀⠀⠀⠀⠀
+    /// ⠒⠒⠒⠤⠤⠤⡀⡜⠀⠉⠉⠉⠑⠒⠒
+    /// ⠀⠀⠀⠀⠀⠀⢸⡇⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn function_segmented(
+        canvas: &mut TextCanvas,
+        from_x: f64,
+        to_x: f64,
+        f: &impl Fn(f64) -> f64,
+        jump_threshold: f64,
+    ) {
+        let nb_values = canvas.screen.fwidth();
+        let (x, y) = Self::compute_function(from_x, to_x, nb_values, f);
+
+        let mut previous: Option<(i32, i32)> = None;
+        for (&value_x, &value_y) in x.iter().zip(&y) {
+            let (Some(screen_x), Some(screen_y)) = (
+                Self::compute_screen_x(canvas, value_x, &x),
+                Self::compute_screen_y(canvas, value_y, &y),
+            ) else {
+                previous = None; // Break the line.
+                continue;
+            };
+
+            match previous {
+                Some((previous_x, previous_y))
+                    if f64::from((screen_y - previous_y).abs()) <= jump_threshold =>
+                {
+                    canvas.stroke_line(previous_x, previous_y, screen_x, screen_y);
+                }
+                // Jump (or first point), start a new segment.
+                _ => canvas.set_pixel(screen_x, screen_y, true),
+            }
+
+            previous = Some((screen_x, screen_y));
+        }
+    }
+
+    /// Plot a function as markers.
+    ///
+    /// The function is evaluated at `nb_points` evenly spaced values
+    /// between `from_x` and `to_x` (both included), and the points are
+    /// scaled to take up the entire canvas. Contrary to
+    /// [`function()`](Plot::function), points are not line-joined, so
+    /// this shows exactly where the function was sampled. Useful for
+    /// sampled data, where joining the points would be misleading.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::f64::consts::TAU;
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// Plot::function_scatter(&mut canvas, 0.0, TAU, 10.0, &f64::sin);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⢀⠀⠁⠐⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠄⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠂⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠐
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠁⠀⠀⠀⢀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠐⠀⡀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn function_scatter(
+        canvas: &mut TextCanvas,
+        from_x: f64,
+        to_x: f64,
+        nb_points: f64,
+        f: &impl Fn(f64) -> f64,
+    ) {
+        let (x, y) = Self::compute_function(from_x, to_x, nb_points, f);
+        Self::scatter(canvas, &x, &y);
+    }
+
+    /// Plot the area under a function.
+    ///
+    /// The function is scaled to take up the entire canvas. For each
+    /// column of the screen, a vertical line is drawn from the
+    /// baseline to the curve, so the area has no gaps, however steep
+    /// the function.
+    ///
+    /// The baseline is _Y_ = _0_. Where the function is negative, the
+    /// area goes downwards from the baseline. If _0_ is not visible,
+    /// the baseline is the closest edge of the canvas (bottom if all
+    /// values are positive, top if they are all negative).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let f = |x: f64| x * x * x;
+    ///
+    /// Plot::function_area(&mut canvas, -2.0, 2.0, &f);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣼
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣠⣾⣿
+    /// ⣤⣤⣤⣤⣤⠤⠤⠤⠤⠤⠶⠾⠿⠿⠿
+    /// ⣿⣿⠟⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⡿⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    ///
+    /// // Compare with the outline.
+    /// let mut outline = TextCanvas::new(15, 5);
+    /// Plot::function(&mut outline, -2.0, 2.0, &f);
+    ///
+    /// assert_eq!(
+    ///     outline.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⠎
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⠔⠁⠀
+    /// ⠀⠀⠀⡠⠤⠒⠒⠒⠒⠒⠉⠁⠀⠀⠀
+    /// ⠀⡠⠊⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⡰⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn function_area(canvas: &mut TextCanvas, from_x: f64, to_x: f64, f: &impl Fn(f64) -> f64) {
+        let nb_values = canvas.screen.fwidth();
+        let (x, y) = Self::compute_function(from_x, to_x, nb_values, f);
+        Self::fill_area(canvas, &x, &y);
+    }
+
+    /// Plot the area under a function, with a smooth top edge.
+    ///
+    /// The function is evaluated at `nb_points` evenly spaced values
+    /// between `from_x` and `to_x` (both included). The top edge is
+    /// then interpolated between these points with a Catmull-Rom
+    /// spline, for each column of the screen, and the area beneath it
+    /// is filled like in [`function_area()`](Plot::function_area).
+    ///
+    /// This is useful when the function is expensive to compute, or
+    /// when you want a rounded silhouette (e.g., mountains), from only
+    /// a handful of points. The spline goes through every sampled
+    /// point, but may slightly overshoot between them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let f = |x: f64| (x / 5.0).sin() + 1.5;
+    ///
+    /// Plot::function_filled_smooth(&mut canvas, 0.0, 29.0, 5.0, &f);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⢠⣶⣷⣄⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⣰⣿⣿⣿⣿⣧⡀⠀⠀⠀⠀⠀⠀⠀
+    /// ⣴⣿⣿⣿⣿⣿⣿⣷⡄⠀⠀⠀⠀⠀⠀
+    /// ⣿⣿⣿⣿⣿⣿⣿⣿⣿⡄⠀⠀⠀⠀⢀
+    /// ⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣄⣀⣠⣾⣿
+    /// "
+    /// );
+    ///
+    /// // Compare with the same 5 points, joined by straight lines.
+    /// let mut edge = TextCanvas::new(15, 5);
+    /// let (x, y) = Plot::compute_function(0.0, 29.0, 5.0, &f);
+    /// Plot::line(&mut edge, &x, &y);
+    ///
+    /// assert_eq!(
+    ///     edge.to_string(),
+    ///     "\
+    /// ⠀⠀⢠⠊⠢⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⡔⠁⠀⠀⠈⠢⡀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠊⠀⠀⠀⠀⠀⠀⠘⡄⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠈⢆⠀⠀⠀⢀⡠
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⢆⠤⠊⠁⠀
+    /// "
+    /// );
+    /// ```
+    pub fn function_filled_smooth(
+        canvas: &mut TextCanvas,
+        from_x: f64,
+        to_x: f64,
+        nb_points: f64,
+        f: &impl Fn(f64) -> f64,
+    ) {
+        let (x, y) = Self::compute_function(from_x, to_x, nb_points, f);
+        let (x, y) = Self::smooth_catmull_rom(&x, &y, canvas.screen.fwidth());
+        Self::fill_area(canvas, &x, &y);
+    }
+
+    /// Interpolate points with a uniform Catmull-Rom spline.
+    ///
+    /// `x` must be sorted. The spline is evaluated at `nb_values`
+    /// evenly spaced values, from the first to the last `x` (like
+    /// [`compute_function()`](Plot::compute_function)).
+    #[allow(clippy::many_single_char_names)]
+    fn smooth_catmull_rom(x: &[f64], y: &[f64], nb_values: f64) -> (Vec<f64>, Vec<f64>) {
+        let n = x.len().min(y.len());
+        if n < 2 {
+            return (x[..n].to_vec(), y[..n].to_vec());
+        }
+
+        let catmull_rom = |p0: f64, p1: f64, p2: f64, p3: f64, t: f64| {
+            let t2 = t * t;
+            let t3 = t2 * t;
+            0.5 * (2.0 * p1
+                + (p2 - p0) * t
+                + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+                + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+        };
+
+        Self::compute_function(x[0], x[n - 1], nb_values, &|value_x| {
+            // Segment `[x[i]; x[i + 1]]` containing `value_x`.
+            let mut i = 0;
+            while i + 2 < n && x[i + 1] <= value_x {
+                i += 1;
+            }
+
+            let length = x[i + 1] - x[i];
+            let t = if length > 0.0 {
+                ((value_x - x[i]) / length).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+
+            // Missing neighbours at the ends are extrapolated linearly.
+            let p0 = if i > 0 { y[i - 1] } else { 2.0 * y[0] - y[1] };
+            let p3 = if i + 2 < n {
+                y[i + 2]
+            } else {
+                2.0 * y[n - 1] - y[n - 2]
+            };
+
+            catmull_rom(p0, y[i], y[i + 1], p3, t)
+        })
+    }
+
+    /// Fill the area between the baseline and the values.
+    fn fill_area(canvas: &mut TextCanvas, x: &[f64], y: &[f64]) {
+        let Some((min_y, max_y)) = finite_min_max(y) else {
+            return;
+        };
+        let baseline = 0.0_f64.clamp(min_y, max_y);
+        let Some(baseline) = Self::compute_screen_y(canvas, baseline, y) else {
+            return;
+        };
+
+        for (&value_x, &value_y) in x.iter().zip(y) {
+            let (Some(screen_x), Some(screen_y)) = (
+                Self::compute_screen_x(canvas, value_x, x),
+                Self::compute_screen_y(canvas, value_y, y),
+            ) else {
+                continue;
+            };
+            canvas.stroke_line(screen_x, baseline, screen_x, screen_y);
+        }
+    }
+
+    /// Plot a parametric curve.
+    ///
+    /// `fx` and `fy` give the X and Y coordinates of the curve as a
+    /// function of `t`, with `t` going from `from_t` to `to_t`. The
+    /// resulting points are scaled to take up the entire canvas, and
+    /// are line-joined together in the order of `t` (contrary to
+    /// [`line()`](Plot::line), points are not sorted by X). This makes
+    /// it possible to plot closed or self-intersecting curves, like
+    /// circles, spirals or Lissajous figures.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::f64::consts::TAU;
+    /// use textcanvas::{TextCanvas, charts::Plot};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// Plot::parametric(&mut canvas, 0.0, TAU, &f64::cos, &f64::sin);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⢀⠔⠒⠉⠉⠉⠉⠉⠉⠑⠢⢄⡀⠀
+    /// ⡔⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠙⡄
+    /// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡕
+    /// ⠑⢄⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⡴⠁
+    /// ⠀⠀⠑⠒⠤⠤⠤⡠⠤⠤⠔⠊⠁⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn parametric(
+        canvas: &mut TextCanvas,
+        from_t: f64,
+        to_t: f64,
+        fx: &impl Fn(f64) -> f64,
+        fy: &impl Fn(f64) -> f64,
+    ) {
+        // Contrary to functions of X, the curve can go in any direction.
+        // Go for enough values to go around the canvas.
+        let nb_values = 2.0 * (canvas.screen.fwidth() + canvas.screen.fheight());
+        let (_, points) = Self::compute_function(from_t, to_t, nb_values, &|t| (fx(t), fy(t)));
+        let (x, y): (Vec<f64>, Vec<f64>) = points.into_iter().unzip();
+        Self::plot(canvas, &x, &y, PlotType::Path);
+    }
+
+    /// Compute the values of a function.
+    ///
+    /// This is mainly used internally to compute values for functions.
+    ///
+    /// However, it may also be useful in case one wants to pre-compute
+    /// values.
+    ///
+    /// # Note
+    ///
+    /// The return value of the function is generic. You can use
+    /// [`compute_function()`](Plot::compute_function) to compute
+    /// anything, but if the values of Y are not `f64`s, you will need
+    /// to adapt them before use.
+    ///
+    /// This is useful for optimisation. Say you have an expensive
+    /// function that returns a `struct` with multiple fields. If only
+    /// `f64`s were allowed, you would have to re-compute the exact same
+    /// function for each field of the struct. But thanks to the generic
+    /// return type, you can compute the function _once_, and extract
+    /// the fields into separate vectors by `map()`ping the values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use textcanvas::{TextCanvas, charts::Plot};
+    /// # let mut canvas = TextCanvas::new(15, 5);
+    /// # let mut canvas2 = TextCanvas::new(15, 5);
+    /// #
+    /// let f = |x: f64| x.sin();
+    ///
+    /// // This is inefficient, because `f()` will be computed twice.
+    /// Plot::stroke_xy_axes_of_function(&mut canvas, -3.0, 7.0, &f);
+    /// Plot::function(&mut canvas, -3.0, 7.0, &f);
+    ///
+    /// // This is better, the values are computed only once.
+    /// let (x, y) = Plot::compute_function(-3.0, 7.0, canvas2.screen.fwidth(), &f);
+    /// Plot::stroke_xy_axes(&mut canvas2, &x, &y);
+    /// Plot::line(&mut canvas2, &x, &y);
+    ///
+    /// assert_eq!(canvas.to_string(), canvas2.to_string());
+    /// ```
+    ///
+    /// Note that the "inefficient" solution is unlikely to cause a
+    /// noticeable performance hit. The simpler approach is most often
+    /// the better approach.
+    pub fn compute_function<T>(
+        from_x: f64,
+        to_x: f64,
+        nb_values: f64,
+        f: &impl Fn(f64) -> T,
+    ) -> (Vec<f64>, Vec<T>) {
+        let range = to_x - from_x;
+        // If we want 5 values in a range including bounds, we need to
+        // divide the range into 4 equal pieces:
+        //   1   2   3   4
+        // |   |   |   |   |
+        // 1   2   3   4   5
+        let step = range / (nb_values - 1.0);
+
+        // This is fine. `nb_values` will realistically never be big
+        // enough to overflow `usize`, and even then, this is just for
+        // pre-allocation.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let nb_values = nb_values.ceil() as usize;
+        let mut px: Vec<f64> = Vec::with_capacity(nb_values);
+        let mut py: Vec<T> = Vec::with_capacity(nb_values);
+
+        // Always add first value.
+        px.push(from_x);
+        py.push(f(from_x));
+
+        let mut x = from_x + step;
+        while x < to_x {
+            px.push(x);
+            py.push(f(x));
+
+            x += step;
+        }
+
+        // Always add last value.
+        px.push(to_x);
+        py.push(f(to_x));
+
+        (px, py)
+    }
+
+    /// Compute the values of a function, excluding `to_x`.
+    ///
+    /// This is the same as [`compute_function()`](Plot::compute_function),
+    /// but over the half-open range `[from_x; to_x[`. The range is
+    /// divided into `nb_values` equal pieces, and the value at the
+    /// start of each piece is computed.
+    ///
+    /// This is useful for periodic functions, where the value at
+    /// `to_x` is the same as the value at `from_x`. Stitching segments
+    /// together would otherwise duplicate the wrap point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::charts::Plot;
+    ///
+    /// let (x, _) = Plot::compute_function_exclusive(0.0, 4.0, 4.0, &|x| x);
+    ///
+    /// assert_eq!(x, vec![0.0, 1.0, 2.0, 3.0]);
+    /// ```
+    pub fn compute_function_exclusive<T>(
+        from_x: f64,
+        to_x: f64,
+        nb_values: f64,
+        f: &impl Fn(f64) -> T,
+    ) -> (Vec<f64>, Vec<T>) {
+        let range = to_x - from_x;
+        // If we want 4 values in a range excluding the upper bound, we
+        // need to divide the range into 4 equal pieces:
+        //   1   2   3   4
+        // |   |   |   |   |
+        // 1   2   3   4
+        let step = range / nb_values;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let nb_values = nb_values.ceil() as usize;
+
+        // Compute X from the index rather than accumulating steps, so
+        // that rounding errors cannot push the last value onto `to_x`.
+        #[allow(clippy::cast_precision_loss)]
+        let px: Vec<f64> = (0..nb_values).map(|i| from_x + step * i as f64).collect();
+        let py: Vec<T> = px.iter().map(|&x| f(x)).collect();
+
+        (px, py)
+    }
+
+    /// Read two numeric columns of a CSV file into X and Y values.
+    ///
+    /// `x_col` and `y_col` are zero-based column indices. The first row
+    /// is a header, and is skipped. Surrounding whitespace is trimmed.
+    ///
+    /// Values that are missing or not numeric become `NaN` (which the
+    /// plotting functions ignore), so that X and Y stay aligned.
+    ///
+    /// Note: Requires the `csv` feature.
+    ///
+    /// # Errors
+    ///
+    /// If reading from `reader` fails, or if a record is not valid CSV
+    /// (e.g., it is not valid UTF-8).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::charts::Plot;
+    ///
+    /// let data = "\
+    /// year,population,area
+    /// 2000,6.1,12
+    /// 2010,6.9,12
+    /// 2020,7.8,12
+    /// ";
+    ///
+    /// let (x, y) = Plot::from_csv(data.as_bytes(), 0, 1)?;
+    ///
+    /// assert_eq!(x, [2000.0, 2010.0, 2020.0]);
+    /// assert_eq!(y, [6.1, 6.9, 7.8]);
+    /// # Ok::<(), csv::Error>(())
+    /// ```
+    #[cfg(feature = "csv")]
+    pub fn from_csv(
+        reader: impl std::io::Read,
+        x_col: usize,
+        y_col: usize,
+    ) -> Result<(Vec<f64>, Vec<f64>), csv::Error> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(true)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        let parse = |record: &csv::StringRecord, col: usize| {
+            record
+                .get(col)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(f64::NAN)
+        };
+
+        let mut x = Vec::new();
+        let mut y = Vec::new();
+
+        for record in reader.records() {
+            let record = record?;
+            x.push(parse(&record, x_col));
+            y.push(parse(&record, y_col));
+        }
+
+        Ok((x, y))
+    }
+}
+
+/// Formatter for the numbers of chart axes.
+///
+/// The default formatter is compact: large numbers get a `K`, `M`, `B`
+/// or `T` suffix, and precision depends on the magnitude of the number.
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::charts::NumberFormat;
+///
+/// let currency = NumberFormat::new(|number| format!("${number:.2}"));
+///
+/// assert_eq!(currency.format(1.5), "$1.50");
+/// assert_eq!(NumberFormat::default().format(12_345.0), "12.3K");
+/// ```
+pub struct NumberFormat(Box<dyn Fn(f64) -> String>);
+
+impl NumberFormat {
+    /// Create new `NumberFormat` from a formatting function.
+    pub fn new(format: impl Fn(f64) -> String + 'static) -> Self {
+        Self(Box::new(format))
+    }
+
+    /// Format a number.
+    #[must_use]
+    pub fn format(&self, number: f64) -> String {
+        (self.0)(number)
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new(Chart::format_number)
+    }
+}
+
+/// Options to customize [`Chart`]s.
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::charts::{Chart, ChartOptions, NumberFormat};
+/// use textcanvas::TextCanvas;
+///
+/// let mut canvas = TextCanvas::new(35, 10);
+///
+/// let x: Vec<f64> = (0..=10).map(|i| f64::from(i) / 10.0).collect();
+/// let y = x.clone();
+///
+/// let mut options = ChartOptions::new();
+/// options.number_format(NumberFormat::new(|number| format!("{:.0}%", number * 100.0)));
+///
+/// Chart::line_with_options(&mut canvas, &x, &y, &options);
+///
+/// assert_eq!(
+///     canvas.to_string(),
+///     "\
+/// ⠀⠀⠀⠀100%⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠤⠒⠉⢸⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠤⠊⠀⠀⠀⠀⢸⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⡠⠒⠉⠀⠀⠀⠀⠀⠀⠀⢸⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⠤⠊⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⢀⡠⠔⠊⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⢀⡠⠔⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⡠⠒⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+/// ⠀⠀⠀⠀⠀⠀0%⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠀0%⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀100%
+/// "
+/// );
+/// ```
+#[derive(Default)]
+pub struct ChartOptions {
+    number_format: NumberFormat,
+}
+
+impl ChartOptions {
+    /// Create new `ChartOptions`, with default values.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Formatter for the numbers of the axes (see [`NumberFormat`]).
+    pub fn number_format(&mut self, number_format: NumberFormat) -> &mut Self {
+        self.number_format = number_format;
+        self
+    }
+}
+
+/// Helper functions to render charts on a [`TextCanvas`].
+///
+/// Basically, this renders a [`Plot`] and makes it pretty.
+///
+/// The idea comes from <https://github.com/sunetos/TextPlots.jl>.
+pub struct Chart;
+
+impl Chart {
+    const MARGIN_TOP: i32 = 1;
+    const MARGIN_RIGHT: i32 = 2;
+    const MARGIN_BOTTOM: i32 = 2;
+    const MARGIN_LEFT: i32 = 10;
+
+    const HORIZONTAL_MARGIN: i32 = Self::MARGIN_LEFT + Self::MARGIN_RIGHT;
+    const VERTICAL_MARGIN: i32 = Self::MARGIN_TOP + Self::MARGIN_BOTTOM;
+
+    /// Render chart with a line plot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{charts::Chart, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(35, 10);
+    ///
+    /// let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+    /// let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+    ///
+    /// Chart::line(&mut canvas, &x, &y);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀5⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠤⠒⠉⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠤⠊⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⡠⠒⠉⠀⠀⠀⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⠤⠊⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⢀⡠⠔⠊⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⢀⡠⠔⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⡠⠒⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀-5⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀-5⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀5
+    /// "
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if chart is < 13×4, because it would make plot < 1×1.
+    pub fn line(canvas: &mut TextCanvas, x: &[f64], y: &[f64]) {
+        Self::line_with_options(canvas, x, y, &ChartOptions::default());
+    }
+
+    /// Render chart with a line plot, with custom options.
+    ///
+    /// Same as [`line()`](Chart::line), but with [`ChartOptions`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if chart is < 13×4, because it would make plot < 1×1.
+    pub fn line_with_options(
+        canvas: &mut TextCanvas,
+        x: &[f64],
+        y: &[f64],
+        options: &ChartOptions,
+    ) {
+        Self::chart(canvas, x, y, PlotType::Line, options);
+    }
+
+    /// Render chart with a scatter plot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{charts::Chart, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(35, 10);
+    ///
+    /// let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+    /// let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+    ///
+    /// Chart::scatter(&mut canvas, &x, &y);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
     ///     "\
     /// ⠀⠀⠀⠀⠀⠀⠀5⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
     /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠄⠀⠈⢸⠀
@@ -910,172 +2238,1147 @@ impl Chart {
     /// );
     /// ```
     ///
-    /// # Panics
+    /// # Panics
+    ///
+    /// Panics if chart is < 13×4, because it would make plot < 1×1.
+    pub fn scatter(canvas: &mut TextCanvas, x: &[f64], y: &[f64]) {
+        Self::scatter_with_options(canvas, x, y, &ChartOptions::default());
+    }
+
+    /// Render chart with a scatter plot, with custom options.
+    ///
+    /// Same as [`scatter()`](Chart::scatter), but with
+    /// [`ChartOptions`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if chart is < 13×4, because it would make plot < 1×1.
+    pub fn scatter_with_options(
+        canvas: &mut TextCanvas,
+        x: &[f64],
+        y: &[f64],
+        options: &ChartOptions,
+    ) {
+        Self::chart(canvas, x, y, PlotType::Scatter, options);
+    }
+
+    fn chart(
+        canvas: &mut TextCanvas,
+        x: &[f64],
+        y: &[f64],
+        plot_type: PlotType,
+        options: &ChartOptions,
+    ) {
+        if x.is_empty() || y.is_empty() {
+            return;
+        }
+        Self::check_canvas_size(canvas);
+        Self::plot_values(canvas, x, y, plot_type);
+        Self::stroke_plot_border(canvas);
+        Self::draw_min_and_max_values(canvas, x, y, &options.number_format);
+    }
+
+    fn check_canvas_size(canvas: &TextCanvas) {
+        let width = canvas.output.width();
+        let height = canvas.output.height();
+        let min_width = Self::HORIZONTAL_MARGIN + 1;
+        let min_height = Self::VERTICAL_MARGIN + 1;
+        assert!(
+            width >= min_width && height >= min_height,
+            "Canvas size is {width}×{height}, but must be at least {min_width}×{min_height} to accommodate for plot."
+        );
+    }
+
+    fn plot_values(canvas: &mut TextCanvas, x: &[f64], y: &[f64], plot_type: PlotType) {
+        let width = canvas.output.width() - Self::HORIZONTAL_MARGIN;
+        let height = canvas.output.height() - Self::VERTICAL_MARGIN;
+
+        let mut plot = TextCanvas::new(width, height);
+        Plot::set_rounding(&mut plot, canvas.plot_rounding);
+
+        match plot_type {
+            PlotType::Line => {
+                Plot::line(&mut plot, x, y);
+            }
+            PlotType::Path => {
+                Plot::plot(&mut plot, x, y, PlotType::Path);
+            }
+            PlotType::Scatter => {
+                Plot::scatter(&mut plot, x, y);
+            }
+            PlotType::Marker(marker) => {
+                Plot::scatter_with_marker(&mut plot, x, y, marker);
+            }
+        }
+
+        let (dx, dy) = canvas.output_to_screen(Self::MARGIN_LEFT, Self::MARGIN_TOP);
+        canvas.draw_canvas(&plot, dx, dy);
+    }
+
+    fn stroke_plot_border(canvas: &mut TextCanvas) {
+        let (top, right, bottom, left) = Self::plot_border(canvas);
+
+        canvas.stroke_line(left, top, right, top);
+        canvas.stroke_line(right, top, right, bottom);
+        canvas.stroke_line(right, bottom, left, bottom);
+        canvas.stroke_line(left, bottom, left, top);
+    }
+
+    /// Screen coordinates of the plot border (top, right, bottom, left).
+    fn plot_border(canvas: &TextCanvas) -> (i32, i32, i32, i32) {
+        let top = (Self::MARGIN_TOP - 1) * 4 + 2;
+        let right = canvas.w() - (Self::MARGIN_RIGHT - 1) * 2;
+        let bottom = canvas.h() - ((Self::MARGIN_BOTTOM - 1) * 4 + 2);
+        let left = (Self::MARGIN_LEFT - 1) * 2;
+        (top, right, bottom, left)
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn draw_min_and_max_values(
+        canvas: &mut TextCanvas,
+        x: &[f64],
+        y: &[f64],
+        number_format: &NumberFormat,
+    ) {
+        let (Some((min_x, max_x)), Some((min_y, max_y))) = (finite_min_max(x), finite_min_max(y))
+        else {
+            return; // No finite values.
+        };
+        let min_x = number_format.format(min_x);
+        let max_x = number_format.format(max_x);
+        let min_y = number_format.format(min_y);
+        let max_y = number_format.format(max_y);
+
+        canvas.draw_text(
+            &min_x,
+            Self::MARGIN_LEFT - (text_width(&min_x) as i32),
+            canvas.output.height() - Self::MARGIN_TOP,
+        );
+        canvas.draw_text(
+            &max_x,
+            canvas.output.width() - Self::MARGIN_RIGHT + 2 - (text_width(&max_x) as i32),
+            canvas.output.height() - Self::MARGIN_TOP,
+        );
+        canvas.draw_text(
+            &min_y,
+            Self::MARGIN_LEFT - 2 - (text_width(&min_y) as i32),
+            canvas.output.height() - Self::MARGIN_TOP - 1,
+        );
+        canvas.draw_text(
+            &max_y,
+            Self::MARGIN_LEFT - 2 - (text_width(&max_y) as i32),
+            Self::MARGIN_TOP - 1,
+        );
+    }
+
+    fn format_number(mut number: f64) -> String {
+        let mut precision = 1;
+        let mut suffix = "";
+        if number.abs() >= 1_000_000_000_000.0 {
+            number /= 1_000_000_000_000.0;
+            suffix = "T";
+        } else if number.abs() >= 1_000_000_000.0 {
+            number /= 1_000_000_000.0;
+            suffix = "B";
+        } else if number.abs() >= 1_000_000.0 {
+            number /= 1_000_000.0;
+            suffix = "M";
+        } else if number.abs() >= 10_000.0 {
+            number /= 1000.0;
+            suffix = "K";
+        } else if (number - number.round()).abs() < 0.001 {
+            precision = 0; // Close enough to being round for display.
+            if number.abs() < 0.000_1 {
+                number = 0.0; // Prevent "-0".
+            }
+        } else if number.abs() < 1.0 {
+            precision = 4; // Sub-1 decimals matter a lot.
+        }
+
+        format!("{number:.precision$}{suffix}")
+    }
+
+    /// Render chart with a function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{charts::Chart, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(35, 10);
+    ///
+    /// let f = |x: f64| x.cos();
+    ///
+    /// Chart::function(&mut canvas, 0.0, 5.0, &f);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀1⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠉⠉⠢⢄⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠱⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠈⢆⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⠖⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠣⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡠⠃⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠑⡄⠀⠀⠀⠀⠀⠀⠀⠀⠀⡰⠁⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠢⡀⠀⠀⠀⠀⠀⢀⠔⠁⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠢⠤⡠⠤⠒⠁⠀⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀-1⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀0⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀5
+    /// "
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if chart is < 13×4, because it would make plot < 1×1.
+    pub fn function(canvas: &mut TextCanvas, from_x: f64, to_x: f64, f: &impl Fn(f64) -> f64) {
+        Self::function_with_options(canvas, from_x, to_x, f, &ChartOptions::default());
+    }
+
+    /// Render chart with a function, with custom options.
+    ///
+    /// Same as [`function()`](Chart::function), but with
+    /// [`ChartOptions`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if chart is < 13×4, because it would make plot < 1×1.
+    pub fn function_with_options(
+        canvas: &mut TextCanvas,
+        from_x: f64,
+        to_x: f64,
+        f: &impl Fn(f64) -> f64,
+        options: &ChartOptions,
+    ) {
+        let nb_values = f64::from((canvas.output.width() - (Self::HORIZONTAL_MARGIN)) * 2);
+        let (x, y) = Plot::compute_function(from_x, to_x, nb_values, f);
+        Self::line_with_options(canvas, &x, &y, options);
+    }
+
+    /// Draw an axis break marker on a chart.
+    ///
+    /// The marker is the conventional double-slash (`//`), cut across
+    /// the border of the chart, to show that part of the range of the
+    /// axis was left out.
+    ///
+    /// `position` is normalized along the axis, from `0.0` (the
+    /// origin, bottom-left corner of the chart) to `1.0` (the end of
+    /// the axis). It is clamped to this range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{charts::{Axis, Chart}, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(20, 7);
+    ///
+    /// let x: Vec<f64> = (95..=100).map(f64::from).collect();
+    /// let y: Vec<f64> = (95..=100).map(f64::from).collect();
+    ///
+    /// Chart::line(&mut canvas, &x, &y);
+    /// Chart::axis_break(&mut canvas, 0.3, Axis::Y);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀100⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⡠⠊⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⡠⠊⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⢀⡣⠄⠀⡠⠊⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠕⡎⡡⠊⠀⠀⠀⠀⠀⠀⢸⠀
+    /// ⠀⠀⠀⠀⠀⠀95⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀95⠀⠀⠀⠀⠀⠀⠀100
+    /// "
+    /// );
+    /// ```
+    pub fn axis_break(canvas: &mut TextCanvas, position: f64, axis: Axis) {
+        if !position.is_finite() {
+            return;
+        }
+        let position = position.clamp(0.0, 1.0);
+
+        let (top, right, bottom, left) = Self::plot_border(canvas);
+
+        #[allow(clippy::cast_possible_truncation)]
+        match axis {
+            Axis::X => {
+                let x = left + (position * f64::from(right - left)).round() as i32;
+                // Cut the axis between the slashes.
+                for dx in -1..=1 {
+                    canvas.set_pixel(x + dx, bottom, false);
+                }
+                canvas.stroke_line(x - 2, bottom + 2, x, bottom - 2);
+                canvas.stroke_line(x, bottom + 2, x + 2, bottom - 2);
+            }
+            Axis::Y => {
+                let y = bottom - (position * f64::from(bottom - top)).round() as i32;
+                for dy in -1..=1 {
+                    canvas.set_pixel(left, y + dy, false);
+                }
+                canvas.stroke_line(left - 2, y + 1, left + 2, y - 1);
+                canvas.stroke_line(left - 2, y + 3, left + 2, y + 1);
+            }
+        }
+    }
+
+    /// Render a heatmap of 2D data.
+    ///
+    /// Each value of the matrix is mapped to a rectangular region of
+    /// the canvas, and the region is filled with the color returned by
+    /// `colormap`. Values are normalized to `[0; 1]` (min value is 0,
+    /// max value is 1) before being passed to `colormap`.
+    ///
+    /// The data is scaled to take up the entire canvas. The first row
+    /// of data is drawn at the top. Regions are aligned on output
+    /// cells, because color is stored per output cell.
+    ///
+    /// Non-finite values (`NaN`, `±Inf`) are ignored, and their region
+    /// is left untouched. If all values are the same, they are mapped
+    /// to `0.5`.
+    ///
+    /// The context color of the canvas is not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{charts::Chart, Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(2, 1);
+    ///
+    /// let data = [vec![0.0, 10.0]];
+    ///
+    /// Chart::heatmap(&mut canvas, &data, |t| {
+    ///     if t < 0.5 {
+    ///         Color::new().blue().fix()
+    ///     } else {
+    ///         Color::new().red().fix()
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(canvas.to_string(), "\x1b[0;34m⣿\x1b[0m\x1b[0;31m⣿\x1b[0m\n");
+    /// ```
+    pub fn heatmap(canvas: &mut TextCanvas, data: &[Vec<f64>], colormap: impl Fn(f64) -> Color) {
+        let nb_rows = data.len();
+        let nb_columns = data.iter().map(Vec::len).max().unwrap_or(0);
+        if nb_rows == 0 || nb_columns == 0 {
+            return;
+        }
+
+        let values = data.iter().flatten().filter(|value| value.is_finite());
+        let Some(min) = values.clone().min_by(cmp_f64) else {
+            return; // No finite values.
+        };
+        let max = values.max_by(cmp_f64).unwrap_or(min);
+        let range = max - min;
+
+        let mut heatmap = TextCanvas::new(canvas.output.width(), canvas.output.height());
+
+        // Region boundaries, in output cells.
+        let boundary = |i: usize, nb_regions: usize, size: i32| -> i32 {
+            let i = i32::try_from(i).unwrap_or(i32::MAX);
+            let nb_regions = i32::try_from(nb_regions).unwrap_or(i32::MAX);
+            i.saturating_mul(size) / nb_regions
+        };
+
+        for (row, values) in data.iter().enumerate() {
+            let top = boundary(row, nb_rows, heatmap.output.height());
+            let bottom = boundary(row + 1, nb_rows, heatmap.output.height());
+
+            for (column, &value) in values.iter().enumerate() {
+                if !value.is_finite() {
+                    continue;
+                }
+                let left = boundary(column, nb_columns, heatmap.output.width());
+                let right = boundary(column + 1, nb_columns, heatmap.output.width());
+
+                let t = if range == 0.0 {
+                    0.5
+                } else {
+                    (value - min) / range
+                };
+
+                heatmap.set_color(&colormap(t));
+                heatmap.fill_rect(left * 2, top * 4, (right - left) * 2, (bottom - top) * 4);
+            }
+        }
+
+        canvas.merge_canvas(&heatmap, 0, 0);
+    }
+
+    /// Render contour lines of a 2D scalar field.
+    ///
+    /// `f` is sampled at every pixel of the canvas, over `x_range` and
+    /// `y_range` (`(from, to)`, `y` going up). Then, for each value in
+    /// `levels`, an iso-line is traced where `f` crosses that value,
+    /// using the marching squares algorithm.
+    ///
+    /// Like with [`Chart::heatmap()`], the field takes up the entire
+    /// canvas. Lines are drawn in the context color; to give each
+    /// level its own color, call `contour()` once per level, changing
+    /// the color in-between.
+    ///
+    /// Non-finite values (`NaN`, `±Inf`) break the lines around them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{charts::Chart, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(21, 10);
+    ///
+    /// let f = |x: f64, y: f64| x.powi(2) + y.powi(2);
+    ///
+    /// Chart::contour(&mut canvas, f, (-3.0, 3.0), (-3.0, 3.0), &[1.0, 4.0]);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⣀⣀⣀⣀⣀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⡠⠒⠉⠀⠀⠀⠀⠀⠉⠒⢄⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⡜⠀⠀⠀⡠⠒⠒⠒⢄⠀⠀⠀⢣⠀⠀⠀⠀
+    /// ⠀⠀⠀⢸⠀⠀⠀⡎⠀⠀⠀⠀⠀⢱⠀⠀⠀⡇⠀⠀⠀
+    /// ⠀⠀⠀⢸⠀⠀⠀⢇⠀⠀⠀⠀⠀⡸⠀⠀⠀⡇⠀⠀⠀
+    /// ⠀⠀⠀⠀⢣⠀⠀⠀⠑⠤⠤⠤⠊⠀⠀⠀⡜⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠑⠤⣀⠀⠀⠀⠀⠀⣀⠤⠊⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠉⠉⠉⠉⠉⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn contour(
+        canvas: &mut TextCanvas,
+        f: impl Fn(f64, f64) -> f64,
+        x_range: (f64, f64),
+        y_range: (f64, f64),
+        levels: &[f64],
+    ) {
+        let width = canvas.screen.width();
+        let height = canvas.screen.height();
+
+        let (from_x, to_x) = x_range;
+        let (from_y, to_y) = y_range;
+        let step_x = (to_x - from_x) / f64::from(width - 1);
+        let step_y = (to_y - from_y) / f64::from(height - 1);
+
+        // Sample the field at every pixel (top row is `to_y`).
+        let field: Vec<Vec<f64>> = (0..height)
+            .map(|y| {
+                let value_y = to_y - f64::from(y) * step_y;
+                (0..width)
+                    .map(|x| f(from_x + f64::from(x) * step_x, value_y))
+                    .collect()
+            })
+            .collect();
+
+        for &level in levels {
+            for y in 0..height - 1 {
+                for x in 0..width - 1 {
+                    Self::contour_cell(canvas, &field, x, y, level);
+                }
+            }
+        }
+    }
+
+    /// Trace the iso-line of `level` through one cell of the grid.
+    ///
+    /// The cell spans pixels `(x, y)` to `(x + 1, y + 1)`.
+    #[allow(clippy::cast_sign_loss)]
+    fn contour_cell(canvas: &mut TextCanvas, field: &[Vec<f64>], x: i32, y: i32, level: f64) {
+        let (ux, uy) = (x as usize, y as usize);
+        let top_left = field[uy][ux];
+        let top_right = field[uy][ux + 1];
+        let bottom_right = field[uy + 1][ux + 1];
+        let bottom_left = field[uy + 1][ux];
+
+        let corners = [top_left, top_right, bottom_right, bottom_left];
+        if corners.iter().any(|value| !value.is_finite()) {
+            return;
+        }
+
+        let case = corners
+            .iter()
+            .fold(0, |case, &value| (case << 1) | u8::from(value > level));
+
+        // Where the iso-line crosses an edge, by linear interpolation.
+        let crossing = |a: f64, b: f64| (level - a) / (b - a);
+        let top = || (f64::from(x) + crossing(top_left, top_right), f64::from(y));
+        let right = || {
+            (
+                f64::from(x + 1),
+                f64::from(y) + crossing(top_right, bottom_right),
+            )
+        };
+        let bottom = || {
+            (
+                f64::from(x) + crossing(bottom_left, bottom_right),
+                f64::from(y + 1),
+            )
+        };
+        let left = || (f64::from(x), f64::from(y) + crossing(top_left, bottom_left));
+
+        let is_center_above = (top_left + top_right + bottom_right + bottom_left) / 4.0 > level;
+
+        // Bits: top-left, top-right, bottom-right, bottom-left.
+        let segments = match case {
+            0b0001 | 0b1110 => vec![(left(), bottom())],
+            0b0010 | 0b1101 => vec![(bottom(), right())],
+            0b0011 | 0b1100 => vec![(left(), right())],
+            0b0100 | 0b1011 => vec![(top(), right())],
+            0b0110 | 0b1001 => vec![(top(), bottom())],
+            0b0111 | 0b1000 => vec![(left(), top())],
+            // Saddles: the center decides which corners are connected.
+            0b0101 if is_center_above => vec![(left(), top()), (bottom(), right())],
+            0b1010 if !is_center_above => vec![(left(), top()), (bottom(), right())],
+            0b0101 | 0b1010 => vec![(top(), right()), (left(), bottom())],
+            _ => vec![],
+        };
+
+        for ((x1, y1), (x2, y2)) in segments {
+            #[allow(clippy::cast_possible_truncation)]
+            canvas.stroke_line(
+                x1.round() as i32,
+                y1.round() as i32,
+                x2.round() as i32,
+                y2.round() as i32,
+            );
+        }
+    }
+
+    /// Render a vector field (quiver plot).
+    ///
+    /// The field is sampled on an `nx`×`ny` grid over `x_range` and
+    /// `y_range` (`(from, to)`, `y` going up), at the center of each
+    /// grid cell. `fx` and `fy` give the `x` and `y` components of the
+    /// vector at each sample, and an arrow is drawn for each vector
+    /// (see [`TextCanvas::stroke_arrow()`]).
+    ///
+    /// Arrows are scaled relative to the largest vector, so that the
+    /// largest arrow spans one grid cell, and arrows never overlap.
+    ///
+    /// Like with [`Chart::heatmap()`], the field takes up the entire
+    /// canvas. Non-finite vectors are ignored, and null vectors are
+    /// drawn as a single pixel. If `nx` or `ny` is < 1, nothing is
+    /// drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{charts::Chart, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 7);
+    ///
+    /// // Counter-clockwise rotation.
+    /// let fx = |_: f64, y: f64| -y;
+    /// let fy = |x: f64, _: f64| x;
+    ///
+    /// Chart::quiver(&mut canvas, fx, fy, (-1.0, 1.0), (-1.0, 1.0), 3, 3);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⡠⠂⠀⢀⡀⠀⠀⠰⣦⠄⠀⠀
+    /// ⢠⡷⠍⠀⠀⠈⠙⠋⠉⠁⠀⠁⠉⠢⡀
+    /// ⠀⠀⢀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⠀⠀
+    /// ⠀⠀⣼⡄⠀⠀⠀⠠⠀⠀⠀⠀⢻⠃⠀
+    /// ⠀⠀⠈⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠀⠀
+    /// ⠈⠢⣀⢀⠀⢀⣀⣠⣄⡀⠀⠀⣐⢾⠃
+    /// ⠀⠀⠐⠻⠆⠀⠀⠈⠁⠀⠠⠊⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn quiver(
+        canvas: &mut TextCanvas,
+        fx: impl Fn(f64, f64) -> f64,
+        fy: impl Fn(f64, f64) -> f64,
+        x_range: (f64, f64),
+        y_range: (f64, f64),
+        nx: i32,
+        ny: i32,
+    ) {
+        if nx < 1 || ny < 1 {
+            return;
+        }
+
+        let width = f64::from(canvas.screen.width());
+        let height = f64::from(canvas.screen.height());
+        let (from_x, to_x) = x_range;
+        let (from_y, to_y) = y_range;
+
+        // Grid cells, in pixels.
+        let cell_width = width / f64::from(nx);
+        let cell_height = height / f64::from(ny);
+
+        // Vectors, converted to pixels (`y` going down).
+        let mut samples = Vec::new();
+        for j in 0..ny {
+            for i in 0..nx {
+                let (x, y) = (f64::from(i) + 0.5, f64::from(j) + 0.5);
+                let value_x = from_x + x / f64::from(nx) * (to_x - from_x);
+                let value_y = to_y - y / f64::from(ny) * (to_y - from_y);
+
+                let dx = fx(value_x, value_y) * width / (to_x - from_x);
+                let dy = -fy(value_x, value_y) * height / (to_y - from_y);
+                if !dx.is_finite() || !dy.is_finite() {
+                    continue;
+                }
+
+                samples.push((x * cell_width, y * cell_height, dx, dy));
+            }
+        }
+
+        let max_magnitude = samples
+            .iter()
+            .map(|(_, _, dx, dy)| dx.hypot(*dy))
+            .fold(0.0, f64::max);
+        // Largest arrow spans one cell (minus one pixel, for spacing).
+        let max_length = (cell_width.min(cell_height) - 1.0).max(0.0);
+        let scale = if max_magnitude == 0.0 {
+            0.0
+        } else {
+            max_length / max_magnitude
+        };
+
+        for (cx, cy, dx, dy) in samples {
+            let (dx, dy) = (dx * scale / 2.0, dy * scale / 2.0);
+            #[allow(clippy::cast_possible_truncation)]
+            canvas.stroke_arrow(
+                (cx - dx).floor() as i32,
+                (cy - dy).floor() as i32,
+                (cx + dx).floor() as i32,
+                (cy + dy).floor() as i32,
+            );
+        }
+    }
+
+    /// Viridis-like colormap.
+    ///
+    /// Maps `t` in `[0; 1]` to a perceptually uniform RGB color going
+    /// from dark purple (0), through blue and green, to yellow (1).
+    /// Values outside of `[0; 1]` are clamped.
     ///
-    /// Panics if chart is < 13×4, because it would make plot < 1×1.
-    pub fn scatter(canvas: &mut TextCanvas, x: &[f64], y: &[f64]) {
-        Self::chart(canvas, x, y, PlotType::Scatter);
+    /// This is meant to be used with [`Chart::heatmap()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{charts::Chart, Color};
+    ///
+    /// assert_eq!(Chart::viridis(0.0), Color::new().rgb(68, 1, 84).fix());
+    /// assert_eq!(Chart::viridis(1.0), Color::new().rgb(253, 231, 37).fix());
+    /// ```
+    #[must_use]
+    pub fn viridis(t: f64) -> Color {
+        const STOPS: [(u8, u8, u8); 5] = [
+            (68, 1, 84),
+            (59, 82, 139),
+            (33, 145, 140),
+            (94, 201, 98),
+            (253, 231, 37),
+        ];
+
+        let t = t.clamp(0.0, 1.0) * 4.0;
+        // `t` is in `[0; 4]`.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let i = (t.floor() as usize).min(3);
+
+        let (r1, g1, b1) = STOPS[i];
+        let (r2, g2, b2) = STOPS[i + 1];
+        let from = Color::new().rgb(r1, g1, b1).fix();
+        let to = Color::new().rgb(r2, g2, b2).fix();
+
+        #[allow(clippy::cast_precision_loss)]
+        Color::lerp_rgb(&from, &to, t - i as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stroke_x_and_y_axes() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+
+        Plot::stroke_xy_axes(&mut canvas, &x, &y);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠤⠤⠤⠤⠤⠤⠤⡧⠤⠤⠤⠤⠤⠤⠤
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+"
+        );
+    }
+
+    #[test]
+    fn stroke_x_axis_at_top_boundary() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let y: Vec<f64> = (-5..=0).map(f64::from).collect();
+
+        Plot::stroke_x_axis(&mut canvas, &y);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"
+        );
+    }
+
+    #[test]
+    fn stroke_x_axis_at_bottom_boundary() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let y: Vec<f64> = (0..=5).map(f64::from).collect();
+
+        Plot::stroke_x_axis(&mut canvas, &y);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀
+"
+        );
+    }
+
+    #[test]
+    fn stroke_y_axis_at_left_boundary() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let x: Vec<f64> = (0..=5).map(f64::from).collect();
+
+        Plot::stroke_y_axis(&mut canvas, &x);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"
+        );
+    }
+
+    #[test]
+    fn stroke_y_axis_at_right_boundary() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let x: Vec<f64> = (-5..=0).map(f64::from).collect();
+
+        Plot::stroke_y_axis(&mut canvas, &x);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+"
+        );
+    }
+
+    #[test]
+    fn stroke_line_at_x() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+
+        Plot::stroke_line_at_x(&mut canvas, -5.0, &x);
+        Plot::stroke_line_at_x(&mut canvas, -2.5, &x);
+        Plot::stroke_line_at_x(&mut canvas, 0.0, &x);
+        Plot::stroke_line_at_x(&mut canvas, 2.5, &x);
+        Plot::stroke_line_at_x(&mut canvas, 5.0, &x);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
+⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
+⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
+⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
+⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
+"
+        );
+    }
+
+    #[test]
+    fn stroke_line_at_x_ignore_empty_values() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let x: Vec<f64> = vec![];
+
+        Plot::stroke_line_at_x(&mut canvas, 0.0, &x);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"
+        );
+    }
+
+    #[test]
+    fn stroke_line_at_y() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+
+        Plot::stroke_line_at_y(&mut canvas, -5.0, &y);
+        Plot::stroke_line_at_y(&mut canvas, -2.5, &y);
+        Plot::stroke_line_at_y(&mut canvas, 0.0, &y);
+        Plot::stroke_line_at_y(&mut canvas, 2.5, &y);
+        Plot::stroke_line_at_y(&mut canvas, 5.0, &y);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉
+⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒
+⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤
+⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀
+⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀
+"
+        );
+    }
+
+    #[test]
+    fn stroke_stats_mean_is_not_center() {
+        let mut canvas = TextCanvas::new(4, 3);
+
+        Plot::stroke_stats(&mut canvas, &[0.0, 0.0, 0.0, 8.0]);
+
+        // Max at the top, min at the bottom, and mean (2) just above.
+        assert_eq!(canvas.to_string(), "⠉⠀⠉⠀\n⠀⠀⠀⠀\n⣒⠀⣒⠀\n");
+    }
+
+    #[test]
+    fn stroke_stats_ignore_empty_values() {
+        let mut canvas = TextCanvas::new(4, 2);
+
+        Plot::stroke_stats(&mut canvas, &[]);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀⠀\n⠀⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn stroke_line_at_y_ignore_empty_values() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let y: Vec<f64> = vec![];
+
+        Plot::stroke_line_at_y(&mut canvas, 0.0, &y);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"
+        );
+    }
+
+    #[test]
+    fn shade_band_is_clipped_to_canvas() {
+        let mut canvas = TextCanvas::new(5, 3);
+
+        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+
+        Plot::shade_band(&mut canvas, 100.0, 2.5, &y, Color::new().red());
+
+        assert_eq!(canvas.to_plain_string(), "⠅⠅⠅⠅⠅\n⠀⠀⠀⠀⠀\n⠀⠀⠀⠀⠀\n");
+        assert_eq!(canvas.color_buffer[0][0], *Color::new().red());
     }
 
-    fn chart(canvas: &mut TextCanvas, x: &[f64], y: &[f64], plot_type: PlotType) {
-        if x.is_empty() || y.is_empty() {
-            return;
-        }
-        Self::check_canvas_size(canvas);
-        Self::plot_values(canvas, x, y, plot_type);
-        Self::stroke_plot_border(canvas);
-        Self::draw_min_and_max_values(canvas, x, y);
+    #[test]
+    fn shade_band_out_of_range() {
+        let mut canvas = TextCanvas::new(5, 3);
+
+        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+
+        Plot::shade_band(&mut canvas, 20.0, 100.0, &y, Color::new().red());
+        Plot::shade_band(&mut canvas, f64::NAN, 0.0, &y, Color::new().red());
+        Plot::shade_band(&mut canvas, 0.0, 1.0, &[], Color::new().red());
+
+        assert!(canvas.buffer.iter().flatten().all(|&pixel| !pixel));
     }
 
-    fn check_canvas_size(canvas: &TextCanvas) {
-        let width = canvas.output.width();
-        let height = canvas.output.height();
-        let min_width = Self::HORIZONTAL_MARGIN + 1;
-        let min_height = Self::VERTICAL_MARGIN + 1;
-        assert!(
-            width >= min_width && height >= min_height,
-            "Canvas size is {width}×{height}, but must be at least {min_width}×{min_height} to accommodate for plot."
-        );
+    #[test]
+    fn shade_band_does_not_change_context_color() {
+        let mut canvas = TextCanvas::new(5, 3);
+
+        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+
+        Plot::shade_band(&mut canvas, -5.0, 5.0, &y, Color::new().red());
+        canvas.draw_text("x", 0, 0);
+
+        assert_eq!(canvas.text_buffer[0][0], "x");
     }
 
-    fn plot_values(canvas: &mut TextCanvas, x: &[f64], y: &[f64], plot_type: PlotType) {
-        let width = canvas.output.width() - Self::HORIZONTAL_MARGIN;
-        let height = canvas.output.height() - Self::VERTICAL_MARGIN;
+    #[test]
+    fn compute_screen_x() {
+        let canvas = TextCanvas::new(15, 5);
 
-        let mut plot = TextCanvas::new(width, height);
+        let x: Vec<f64> = (-10..=10).map(f64::from).collect();
 
-        match plot_type {
-            PlotType::Line => {
-                Plot::line(&mut plot, x, y);
-            }
-            PlotType::Scatter => {
-                Plot::scatter(&mut plot, x, y);
-            }
-        }
+        assert_eq!(0, Plot::compute_screen_x(&canvas, -10.0, &x).unwrap());
+        assert_eq!(29, Plot::compute_screen_x(&canvas, 10.0, &x).unwrap());
+        assert_eq!(14, Plot::compute_screen_x(&canvas, 0.0, &x).unwrap());
+    }
 
-        canvas.draw_canvas(&plot, Self::MARGIN_LEFT * 2, Self::MARGIN_TOP * 4);
+    #[test]
+    fn compute_screen_x_input_size_1() {
+        let canvas = TextCanvas::new(15, 5);
+
+        let x: Vec<f64> = vec![3.0];
+
+        assert_eq!(15, Plot::compute_screen_x(&canvas, 0.0, &x).unwrap());
     }
 
-    fn stroke_plot_border(canvas: &mut TextCanvas) {
-        let top = (Self::MARGIN_TOP - 1) * 4 + 2;
-        let right = canvas.w() - (Self::MARGIN_RIGHT - 1) * 2;
-        let bottom = canvas.h() - ((Self::MARGIN_BOTTOM - 1) * 4 + 2);
-        let left = (Self::MARGIN_LEFT - 1) * 2;
+    #[test]
+    fn compute_screen_x_empty_input() {
+        let canvas = TextCanvas::new(15, 5);
 
-        canvas.stroke_line(left, top, right, top);
-        canvas.stroke_line(right, top, right, bottom);
-        canvas.stroke_line(right, bottom, left, bottom);
-        canvas.stroke_line(left, bottom, left, top);
+        let x: Vec<f64> = vec![];
+
+        assert!(Plot::compute_screen_x(&canvas, 0.0, &x).is_none());
     }
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
-    fn draw_min_and_max_values(canvas: &mut TextCanvas, x: &[f64], y: &[f64]) {
-        let min_x = Self::format_number(*x.iter().min_by(cmp_f64).expect("cannot be empty"));
-        let max_x = Self::format_number(*x.iter().max_by(cmp_f64).expect("cannot be empty"));
-        let min_y = Self::format_number(*y.iter().min_by(cmp_f64).expect("cannot be empty"));
-        let max_y = Self::format_number(*y.iter().max_by(cmp_f64).expect("cannot be empty"));
+    #[test]
+    fn compute_screen_y() {
+        let canvas = TextCanvas::new(15, 5);
 
-        canvas.draw_text(
-            &min_x,
-            Self::MARGIN_LEFT - (min_x.len() as i32),
-            canvas.output.height() - Self::MARGIN_TOP,
+        let y: Vec<f64> = (-10..=10).map(f64::from).collect();
+
+        assert_eq!(19, Plot::compute_screen_y(&canvas, -10.0, &y).unwrap());
+        assert_eq!(0, Plot::compute_screen_y(&canvas, 10.0, &y).unwrap());
+        assert_eq!(10, Plot::compute_screen_y(&canvas, 0.0, &y).unwrap());
+    }
+
+    #[test]
+    fn compute_screen_y_input_size_1() {
+        let canvas = TextCanvas::new(15, 5);
+
+        let y: Vec<f64> = vec![3.0];
+
+        assert_eq!(10, Plot::compute_screen_y(&canvas, 0.0, &y).unwrap());
+    }
+
+    #[test]
+    fn compute_screen_y_empty_input() {
+        let canvas = TextCanvas::new(15, 5);
+
+        let y: Vec<f64> = vec![];
+
+        assert!(Plot::compute_screen_y(&canvas, 0.0, &y).is_none());
+    }
+
+    #[test]
+    fn compute_screen_xy_ignore_non_finite_values() {
+        let canvas = TextCanvas::new(15, 5);
+
+        let values = vec![f64::NEG_INFINITY, -10.0, f64::NAN, 10.0, f64::INFINITY];
+
+        assert_eq!(0, Plot::compute_screen_x(&canvas, -10.0, &values).unwrap());
+        assert_eq!(29, Plot::compute_screen_x(&canvas, 10.0, &values).unwrap());
+        assert_eq!(19, Plot::compute_screen_y(&canvas, -10.0, &values).unwrap());
+        assert_eq!(0, Plot::compute_screen_y(&canvas, 10.0, &values).unwrap());
+    }
+
+    #[test]
+    fn compute_screen_xy_of_enormous_values_saturates() {
+        let canvas = TextCanvas::new(15, 5);
+
+        let data = [0.0, 1.0];
+
+        assert_eq!(
+            Plot::compute_screen_x(&canvas, 1e300, &data),
+            Some(i32::MAX)
         );
-        canvas.draw_text(
-            &max_x,
-            canvas.output.width() - Self::MARGIN_RIGHT + 2 - (max_x.len() as i32),
-            canvas.output.height() - Self::MARGIN_TOP,
+        assert_eq!(
+            Plot::compute_screen_x(&canvas, -1e300, &data),
+            Some(i32::MIN)
         );
-        canvas.draw_text(
-            &min_y,
-            Self::MARGIN_LEFT - 2 - (min_y.len() as i32),
-            canvas.output.height() - Self::MARGIN_TOP - 1,
+        // Y-axis is inverted.
+        assert_eq!(
+            Plot::compute_screen_y(&canvas, 1e300, &data),
+            Some(i32::MIN)
         );
-        canvas.draw_text(
-            &max_y,
-            Self::MARGIN_LEFT - 2 - (max_y.len() as i32),
-            Self::MARGIN_TOP - 1,
+        assert_eq!(
+            Plot::compute_screen_y(&canvas, -1e300, &data),
+            Some(i32::MAX)
         );
     }
 
-    fn format_number(mut number: f64) -> String {
-        let mut precision = 1;
-        let mut suffix = "";
-        if number.abs() >= 1_000_000_000_000.0 {
-            number /= 1_000_000_000_000.0;
-            suffix = "T";
-        } else if number.abs() >= 1_000_000_000.0 {
-            number /= 1_000_000_000.0;
-            suffix = "B";
-        } else if number.abs() >= 1_000_000.0 {
-            number /= 1_000_000.0;
-            suffix = "M";
-        } else if number.abs() >= 10_000.0 {
-            number /= 1000.0;
-            suffix = "K";
-        } else if (number - number.round()).abs() < 0.001 {
-            precision = 0; // Close enough to being round for display.
-            if number.abs() < 0.000_1 {
-                number = 0.0; // Prevent "-0".
-            }
-        } else if number.abs() < 1.0 {
-            precision = 4; // Sub-1 decimals matter a lot.
-        }
+    #[test]
+    fn plot_line_enormous_values_does_not_panic() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        format!("{number:.precision$}{suffix}")
+        let x: Vec<f64> = vec![0.0, 1.0, 2.0, 3.0];
+        let y: Vec<f64> = vec![0.0, 1e300, -1e300, 0.0];
+
+        Plot::line(&mut canvas, &x, &y);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⡠⠊⢆⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⡠⠊⠀⠀⠈⢆⠀⠀⠀⠀⠀⠀⠀⠀
+⠊⠀⠀⠀⠀⠀⠈⢆⠀⠀⠀⠀⠀⢀⠔
+⠀⠀⠀⠀⠀⠀⠀⠈⢆⠀⠀⢀⠔⠁⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠈⢆⠔⠁⠀⠀⠀
+"
+        );
+
+        // Range overflows to infinity.
+        let y: Vec<f64> = vec![f64::MAX, -f64::MAX, 0.0, f64::MAX];
+        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
+        Plot::stroke_line_at_y(&mut canvas, f64::MAX, &y);
     }
 
-    /// Render chart with a function.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use textcanvas::{charts::Chart, TextCanvas};
-    ///
-    /// let mut canvas = TextCanvas::new(35, 10);
-    ///
-    /// let f = |x: f64| x.cos();
-    ///
-    /// Chart::function(&mut canvas, 0.0, 5.0, &f);
-    ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⠀1⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠉⠉⠢⢄⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠱⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠈⢆⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⠖⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠣⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡠⠃⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠑⡄⠀⠀⠀⠀⠀⠀⠀⠀⠀⡰⠁⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠢⡀⠀⠀⠀⠀⠀⢀⠔⠁⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠢⠤⡠⠤⠒⠁⠀⠀⠀⠀⠀⢸⠀
-    /// ⠀⠀⠀⠀⠀⠀-1⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀0⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀5
-    /// "
-    /// );
-    /// ```
-    ///
-    /// # Panics
-    ///
-    /// Panics if chart is < 13×4, because it would make plot < 1×1.
-    pub fn function(canvas: &mut TextCanvas, from_x: f64, to_x: f64, f: &impl Fn(f64) -> f64) {
-        let nb_values = f64::from((canvas.output.width() - (Self::HORIZONTAL_MARGIN)) * 2);
-        let (x, y) = Plot::compute_function(from_x, to_x, nb_values, f);
-        Self::line(canvas, &x, &y);
+    #[test]
+    fn truncation_misaligns_axis_and_data() {
+        let mut canvas = TextCanvas::new(15, 5);
+        let values: Vec<f64> = (-5..=5).map(f64::from).collect();
+
+        Plot::scatter(&mut canvas, &values, &values);
+
+        // Screen Y of 0 is 9.5. The axis truncates it to 10, but the
+        // data is truncated to 9.
+        assert_eq!(Plot::compute_screen_y(&canvas, 0.0, &values), Some(10));
+        assert_eq!(canvas.get_pixel(14, 9), Some(true));
+        assert_eq!(canvas.get_pixel(14, 10), Some(false));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn rounding_aligns_axis_and_data() {
+        let values: Vec<f64> = (-5..=5).map(f64::from).collect();
+
+        let mut axes = TextCanvas::new(15, 5);
+        Plot::set_rounding(&mut axes, Rounding::Round);
+        Plot::stroke_xy_axes(&mut axes, &values, &values);
+
+        let mut data = TextCanvas::new(15, 5);
+        Plot::set_rounding(&mut data, Rounding::Round);
+        Plot::scatter(&mut data, &values, &values);
+
+        // Data point (0, 0) is exactly at the intersection of the axes.
+        let x = Plot::compute_screen_x(&data, 0.0, &values).unwrap();
+        let y = Plot::compute_screen_y(&data, 0.0, &values).unwrap();
+        assert_eq!((x, y), (15, 10));
+        assert_eq!(data.get_pixel(x, y), Some(true));
+        assert_eq!(axes.get_pixel(x, 0), Some(true));
+        assert_eq!(axes.get_pixel(0, y), Some(true));
+    }
 
     #[test]
-    fn stroke_x_and_y_axes() {
+    fn rounding_of_screen_coordinates() {
         let mut canvas = TextCanvas::new(15, 5);
+        let values: Vec<f64> = (-10..=10).map(f64::from).collect();
+
+        // Screen X of 0 is 14.5, screen Y of 0 is 9.5.
+        let mut screen_xy = |rounding| {
+            Plot::set_rounding(&mut canvas, rounding);
+            (
+                Plot::compute_screen_x(&canvas, 0.0, &values).unwrap(),
+                Plot::compute_screen_y(&canvas, 0.0, &values).unwrap(),
+            )
+        };
 
-        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
-        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+        assert_eq!(screen_xy(Rounding::Trunc), (14, 10));
+        assert_eq!(screen_xy(Rounding::Round), (15, 10));
+        assert_eq!(screen_xy(Rounding::Floor), (14, 9));
+    }
 
-        Plot::stroke_xy_axes(&mut canvas, &x, &y);
+    #[test]
+    fn chart_uses_rounding_of_canvas() {
+        let values: Vec<f64> = (-5..=5).map(f64::from).collect();
+
+        let mut chart = TextCanvas::new(25, 11);
+        Plot::set_rounding(&mut chart, Rounding::Round);
+        Chart::scatter(&mut chart, &values, &values);
+
+        let mut plot = TextCanvas::new(25 - Chart::HORIZONTAL_MARGIN, 11 - Chart::VERTICAL_MARGIN);
+        Plot::set_rounding(&mut plot, Rounding::Round);
+        Plot::scatter(&mut plot, &values, &values);
+
+        let mut expected = TextCanvas::new(25, 11);
+        Chart::scatter(&mut expected, &values, &values);
+        let (dx, dy) = expected.output_to_screen(Chart::MARGIN_LEFT, Chart::MARGIN_TOP);
+        expected.draw_canvas(&plot, dx, dy);
+
+        assert_eq!(chart.to_string(), expected.to_string());
+
+        let mut truncated = TextCanvas::new(25, 11);
+        Chart::scatter(&mut truncated, &values, &values);
+        assert_ne!(chart.to_string(), truncated.to_string());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn rounding_policies() {
+        assert_eq!(Rounding::default(), Rounding::Trunc);
+
+        assert_eq!(Rounding::Trunc.apply(-1.5), -1.0);
+        assert_eq!(Rounding::Round.apply(-1.5), -2.0);
+        assert_eq!(Rounding::Floor.apply(-1.5), -2.0);
+        assert_eq!(Rounding::Round.apply(1.4), 1.0);
+    }
+
+    #[test]
+    fn compute_screen_xy_of_non_finite_value() {
+        let canvas = TextCanvas::new(15, 5);
+
+        let values: Vec<f64> = (-10..=10).map(f64::from).collect();
+
+        assert!(Plot::compute_screen_x(&canvas, f64::NAN, &values).is_none());
+        assert!(Plot::compute_screen_y(&canvas, f64::INFINITY, &values).is_none());
+    }
+
+    #[test]
+    fn compute_screen_xy_only_non_finite_values() {
+        let canvas = TextCanvas::new(15, 5);
+
+        let values = vec![f64::NAN, f64::INFINITY];
+
+        assert!(Plot::compute_screen_x(&canvas, 0.0, &values).is_none());
+        assert!(Plot::compute_screen_y(&canvas, 0.0, &values).is_none());
+    }
+
+    #[test]
+    fn stroke_x_and_y_axes_of_function() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let f = |x| x;
+
+        Plot::stroke_xy_axes_of_function(&mut canvas, -5.0, 5.0, &f);
 
         assert_eq!(
             canvas.to_string(),
@@ -1090,12 +3393,12 @@ mod tests {
     }
 
     #[test]
-    fn stroke_x_axis_at_top_boundary() {
+    fn stroke_x_axis_of_function_at_top_boundary() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let y: Vec<f64> = (-5..=0).map(f64::from).collect();
+        let f = |x| x;
 
-        Plot::stroke_x_axis(&mut canvas, &y);
+        Plot::stroke_x_axis_of_function(&mut canvas, -5.0, 0.0, &f);
 
         assert_eq!(
             canvas.to_string(),
@@ -1110,12 +3413,12 @@ mod tests {
     }
 
     #[test]
-    fn stroke_x_axis_at_bottom_boundary() {
+    fn stroke_x_axis_of_function_at_bottom_boundary() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let y: Vec<f64> = (0..=5).map(f64::from).collect();
+        let f = |x| x;
 
-        Plot::stroke_x_axis(&mut canvas, &y);
+        Plot::stroke_x_axis_of_function(&mut canvas, 0.0, 5.0, &f);
 
         assert_eq!(
             canvas.to_string(),
@@ -1130,12 +3433,12 @@ mod tests {
     }
 
     #[test]
-    fn stroke_y_axis_at_left_boundary() {
+    fn stroke_y_axis_of_function_at_left_boundary() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = (0..=5).map(f64::from).collect();
+        let f = |x| x;
 
-        Plot::stroke_y_axis(&mut canvas, &x);
+        Plot::stroke_y_axis_of_function(&mut canvas, 0.0, 5.0, &f);
 
         assert_eq!(
             canvas.to_string(),
@@ -1150,12 +3453,12 @@ mod tests {
     }
 
     #[test]
-    fn stroke_y_axis_at_right_boundary() {
+    fn stroke_y_axis_of_function_at_right_boundary() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = (-5..=0).map(f64::from).collect();
+        let f = |x| x;
 
-        Plot::stroke_y_axis(&mut canvas, &x);
+        Plot::stroke_y_axis_of_function(&mut canvas, -5.0, 0.0, &f);
 
         assert_eq!(
             canvas.to_string(),
@@ -1170,16 +3473,16 @@ mod tests {
     }
 
     #[test]
-    fn stroke_line_at_x() {
+    fn stroke_line_at_x_of_function() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let f = |x| x;
 
-        Plot::stroke_line_at_x(&mut canvas, -5.0, &x);
-        Plot::stroke_line_at_x(&mut canvas, -2.5, &x);
-        Plot::stroke_line_at_x(&mut canvas, 0.0, &x);
-        Plot::stroke_line_at_x(&mut canvas, 2.5, &x);
-        Plot::stroke_line_at_x(&mut canvas, 5.0, &x);
+        Plot::stroke_line_at_x_of_function(&mut canvas, -5.0, -5.0, 5.0, &f);
+        Plot::stroke_line_at_x_of_function(&mut canvas, -2.5, -5.0, 5.0, &f);
+        Plot::stroke_line_at_x_of_function(&mut canvas, 0.0, -5.0, 5.0, &f);
+        Plot::stroke_line_at_x_of_function(&mut canvas, 2.5, -5.0, 5.0, &f);
+        Plot::stroke_line_at_x_of_function(&mut canvas, 5.0, -5.0, 5.0, &f);
 
         assert_eq!(
             canvas.to_string(),
@@ -1194,12 +3497,12 @@ mod tests {
     }
 
     #[test]
-    fn stroke_line_at_x_ignore_empty_values() {
+    fn stroke_line_at_x_of_function_value_out_of_bounds() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = vec![];
+        let f = |x| x;
 
-        Plot::stroke_line_at_x(&mut canvas, 0.0, &x);
+        Plot::stroke_line_at_x_of_function(&mut canvas, -100.0, -5.0, 5.0, &f);
 
         assert_eq!(
             canvas.to_string(),
@@ -1214,16 +3517,16 @@ mod tests {
     }
 
     #[test]
-    fn stroke_line_at_y() {
+    fn stroke_line_at_y_of_function() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let f = |x| x;
 
-        Plot::stroke_line_at_y(&mut canvas, -5.0, &y);
-        Plot::stroke_line_at_y(&mut canvas, -2.5, &y);
-        Plot::stroke_line_at_y(&mut canvas, 0.0, &y);
-        Plot::stroke_line_at_y(&mut canvas, 2.5, &y);
-        Plot::stroke_line_at_y(&mut canvas, 5.0, &y);
+        Plot::stroke_line_at_y_of_function(&mut canvas, -5.0, -5.0, 5.0, &f);
+        Plot::stroke_line_at_y_of_function(&mut canvas, -2.5, -5.0, 5.0, &f);
+        Plot::stroke_line_at_y_of_function(&mut canvas, 0.0, -5.0, 5.0, &f);
+        Plot::stroke_line_at_y_of_function(&mut canvas, 2.5, -5.0, 5.0, &f);
+        Plot::stroke_line_at_y_of_function(&mut canvas, 5.0, -5.0, 5.0, &f);
 
         assert_eq!(
             canvas.to_string(),
@@ -1238,12 +3541,12 @@ mod tests {
     }
 
     #[test]
-    fn stroke_line_at_y_ignore_empty_values() {
+    fn stroke_line_at_y_of_function_value_out_of_bounds() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let y: Vec<f64> = vec![];
+        let f = |x| x;
 
-        Plot::stroke_line_at_y(&mut canvas, 0.0, &y);
+        Plot::stroke_line_at_y_of_function(&mut canvas, -100.0, -5.0, 5.0, &f);
 
         assert_eq!(
             canvas.to_string(),
@@ -1258,97 +3561,163 @@ mod tests {
     }
 
     #[test]
-    fn compute_screen_x() {
+    fn compute_screen_x_of_function() {
         let canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = (-10..=10).map(f64::from).collect();
+        let f = |x| x;
 
-        assert_eq!(0, Plot::compute_screen_x(&canvas, -10.0, &x).unwrap());
-        assert_eq!(29, Plot::compute_screen_x(&canvas, 10.0, &x).unwrap());
-        assert_eq!(14, Plot::compute_screen_x(&canvas, 0.0, &x).unwrap());
+        assert_eq!(
+            0,
+            Plot::compute_screen_x_of_function(&canvas, -10.0, -10.0, 10.0, &f).unwrap()
+        );
+        assert_eq!(
+            14,
+            Plot::compute_screen_x_of_function(&canvas, 0.0, -10.0, 10.0, &f).unwrap()
+        );
+        assert_eq!(
+            29,
+            Plot::compute_screen_x_of_function(&canvas, 10.0, -10.0, 10.0, &f).unwrap()
+        );
     }
 
     #[test]
-    fn compute_screen_x_input_size_1() {
+    fn compute_screen_x_of_function_range_0() {
         let canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = vec![3.0];
+        let f = |x| x;
 
-        assert_eq!(15, Plot::compute_screen_x(&canvas, 0.0, &x).unwrap());
+        assert_eq!(
+            15,
+            Plot::compute_screen_x_of_function(&canvas, -10.0, 0.0, 0.0, &f).unwrap()
+        );
+        assert_eq!(
+            15,
+            Plot::compute_screen_x_of_function(&canvas, 0.0, 0.0, 0.0, &f).unwrap()
+        );
+        assert_eq!(
+            15,
+            Plot::compute_screen_x_of_function(&canvas, 10.0, 0.0, 0.0, &f).unwrap()
+        );
     }
 
     #[test]
-    fn compute_screen_x_empty_input() {
-        let canvas = TextCanvas::new(15, 5);
+    fn compute_screen_x_of_function_canvas_size_1x1() {
+        let canvas = TextCanvas::new(1, 1);
 
-        let x: Vec<f64> = vec![];
+        let f = |x| x;
 
-        assert!(Plot::compute_screen_x(&canvas, 0.0, &x).is_none());
+        assert_eq!(
+            0,
+            Plot::compute_screen_x_of_function(&canvas, -10.0, -10.0, 10.0, &f).unwrap()
+        );
+        assert_eq!(
+            0,
+            Plot::compute_screen_x_of_function(&canvas, 0.0, -10.0, 10.0, &f).unwrap()
+        );
+        assert_eq!(
+            1,
+            Plot::compute_screen_x_of_function(&canvas, 10.0, -10.0, 10.0, &f).unwrap()
+        );
     }
 
     #[test]
-    fn compute_screen_y() {
+    fn compute_screen_y_of_function() {
         let canvas = TextCanvas::new(15, 5);
 
-        let y: Vec<f64> = (-10..=10).map(f64::from).collect();
+        let f = |x| x;
 
-        assert_eq!(19, Plot::compute_screen_y(&canvas, -10.0, &y).unwrap());
-        assert_eq!(0, Plot::compute_screen_y(&canvas, 10.0, &y).unwrap());
-        assert_eq!(10, Plot::compute_screen_y(&canvas, 0.0, &y).unwrap());
+        assert_eq!(
+            19,
+            Plot::compute_screen_y_of_function(&canvas, -10.0, -10.0, 10.0, &f).unwrap()
+        );
+        assert_eq!(
+            10,
+            Plot::compute_screen_y_of_function(&canvas, 0.0, -10.0, 10.0, &f).unwrap()
+        );
+        assert_eq!(
+            0,
+            Plot::compute_screen_y_of_function(&canvas, 10.0, -10.0, 10.0, &f).unwrap()
+        );
     }
 
     #[test]
-    fn compute_screen_y_input_size_1() {
+    fn compute_screen_y_of_function_range_0() {
         let canvas = TextCanvas::new(15, 5);
 
-        let y: Vec<f64> = vec![3.0];
+        let f = |x| x;
 
-        assert_eq!(10, Plot::compute_screen_y(&canvas, 0.0, &y).unwrap());
+        assert_eq!(
+            10,
+            Plot::compute_screen_y_of_function(&canvas, -10.0, 0.0, 0.0, &f).unwrap()
+        );
+        assert_eq!(
+            10,
+            Plot::compute_screen_y_of_function(&canvas, 0.0, 0.0, 0.0, &f).unwrap()
+        );
+        assert_eq!(
+            10,
+            Plot::compute_screen_y_of_function(&canvas, 10.0, 0.0, 0.0, &f).unwrap()
+        );
     }
 
     #[test]
-    fn compute_screen_y_empty_input() {
-        let canvas = TextCanvas::new(15, 5);
+    fn compute_screen_y_of_function_canvas_size_1x1() {
+        let canvas = TextCanvas::new(1, 1);
 
-        let y: Vec<f64> = vec![];
+        let f = |x| x;
 
-        assert!(Plot::compute_screen_y(&canvas, 0.0, &y).is_none());
+        assert_eq!(
+            3,
+            Plot::compute_screen_y_of_function(&canvas, -10.0, -10.0, 10.0, &f).unwrap()
+        );
+        assert_eq!(
+            2,
+            Plot::compute_screen_y_of_function(&canvas, 0.0, -10.0, 10.0, &f).unwrap()
+        );
+        assert_eq!(
+            0,
+            Plot::compute_screen_y_of_function(&canvas, 10.0, -10.0, 10.0, &f).unwrap()
+        );
     }
 
     #[test]
-    fn stroke_x_and_y_axes_of_function() {
+    fn plot_line() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
 
-        Plot::stroke_xy_axes_of_function(&mut canvas, -5.0, 5.0, &f);
+        Plot::stroke_xy_axes(&mut canvas, &x, &y);
+        Plot::line(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠤⠤⠤⠤⠤⠤⠤⡧⠤⠤⠤⠤⠤⠤⠤
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⢀⠤⠒⠉
+⠀⠀⠀⠀⠀⠀⠀⡇⢀⠤⠊⠁⠀⠀⠀
+⠤⠤⠤⠤⠤⢤⠤⡯⠥⠤⠤⠤⠤⠤⠤
+⠀⠀⢀⠤⠊⠁⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⡠⠊⠁⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn stroke_x_axis_of_function_at_top_boundary() {
+    fn plot_line_with_empty_x() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = vec![];
+        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
 
-        Plot::stroke_x_axis_of_function(&mut canvas, -5.0, 0.0, &f);
+        Plot::stroke_xy_axes(&mut canvas, &x, &y);
+        Plot::line(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
@@ -1356,103 +3725,147 @@ mod tests {
     }
 
     #[test]
-    fn stroke_x_axis_of_function_at_bottom_boundary() {
+    fn plot_line_with_empty_y() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let y: Vec<f64> = vec![];
 
-        Plot::stroke_x_axis_of_function(&mut canvas, 0.0, 5.0, &f);
+        Plot::stroke_xy_axes(&mut canvas, &x, &y);
+        Plot::line(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn stroke_y_axis_of_function_at_left_boundary() {
+    fn plot_line_sorts_elements_by_x_before_plotting() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = vec![-5.0, 5.0, -2.5];
+        let y: Vec<f64> = vec![5.0, 2.5, -2.5];
 
-        Plot::stroke_y_axis_of_function(&mut canvas, 0.0, 5.0, &f);
+        Plot::stroke_xy_axes(&mut canvas, &x, &y);
+        Plot::line(&mut canvas, &x, &y);
 
+        // Not sorted, it would look like this:
+        // ⠉⠑⠒⠒⠤⠤⢄⣇⡀⠀⠀⠀⠀⠀⠀
+        // ⠀⠀⠀⠀⠀⠀⠀⡇⠈⠉⠉⠒⠒⢢⡤
+        // ⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⣀⠤⠊⠁⠀
+        // ⠒⠒⠒⠒⠒⠒⢒⡷⠖⠚⠒⠒⠒⠒⠒
+        // ⠀⠀⠀⢀⠤⠒⠁⡇⠀⠀⠀⠀⠀⠀⠀
         assert_eq!(
             canvas.to_string(),
             "\
-⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⢣⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠈⢆⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⢀⡠
+⠀⠘⡄⠀⠀⠀⠀⡇⠀⠀⣀⠤⠊⠁⠀
+⠒⠒⠳⡒⠒⠒⢒⡷⠖⠛⠒⠒⠒⠒⠒
+⠀⠀⠀⢣⠤⠒⠁⡇⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn stroke_y_axis_of_function_at_right_boundary() {
+    fn plot_line_unsorted_keeps_order_of_points() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = vec![-5.0, 5.0, -2.5];
+        let y: Vec<f64> = vec![5.0, 2.5, -2.5];
 
-        Plot::stroke_y_axis_of_function(&mut canvas, -5.0, 0.0, &f);
+        Plot::stroke_xy_axes(&mut canvas, &x, &y);
+        Plot::line_unsorted(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+⠉⠑⠒⠒⠤⠤⢄⣇⡀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠈⠉⠉⠒⠒⢢⡤
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⣀⠤⠊⠁⠀
+⠒⠒⠒⠒⠒⠒⢒⡷⠖⠚⠒⠒⠒⠒⠒
+⠀⠀⠀⢀⠤⠒⠁⡇⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn stroke_line_at_x_of_function() {
+    fn plot_sequence_same_as_line_with_indices() {
+        let y = [0.0, 3.0, 1.0, 2.0];
+
+        let mut canvas = TextCanvas::new(12, 3);
+        Plot::sequence(&mut canvas, &y, false);
+
+        let mut expected = TextCanvas::new(12, 3);
+        Plot::line(&mut expected, &[0.0, 1.0, 2.0, 3.0], &y);
+
+        assert_eq!(canvas.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn plot_sequence_inclusive_end_leaves_one_step() {
+        let y = [0.0, 1.0];
+
+        let mut canvas = TextCanvas::new(6, 1);
+        Plot::sequence(&mut canvas, &y, true);
+
+        // X goes from 0 to 2, so the line stops at mid-width.
+        assert_eq!(canvas.to_string(), "⡠⠔⠊⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn plot_sequence_empty() {
+        let mut canvas = TextCanvas::new(6, 1);
+
+        Plot::sequence(&mut canvas, &[], true);
+        Plot::sequence(&mut canvas, &[], false);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn plot_line_with_single_value() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = vec![0.0];
+        let y: Vec<f64> = vec![0.0];
 
-        Plot::stroke_line_at_x_of_function(&mut canvas, -5.0, -5.0, 5.0, &f);
-        Plot::stroke_line_at_x_of_function(&mut canvas, -2.5, -5.0, 5.0, &f);
-        Plot::stroke_line_at_x_of_function(&mut canvas, 0.0, -5.0, 5.0, &f);
-        Plot::stroke_line_at_x_of_function(&mut canvas, 2.5, -5.0, 5.0, &f);
-        Plot::stroke_line_at_x_of_function(&mut canvas, 5.0, -5.0, 5.0, &f);
+        Plot::line(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
-⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
-⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
-⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
-⡇⠀⠀⢸⠀⠀⠀⡇⠀⠀⢸⠀⠀⠀⢸
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠠⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn stroke_line_at_x_of_function_value_out_of_bounds() {
+    fn plot_line_with_range_xy_zero() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
+        let y: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
 
-        Plot::stroke_line_at_x_of_function(&mut canvas, -100.0, -5.0, 5.0, &f);
+        Plot::line(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠠⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
@@ -1460,43 +3873,41 @@ mod tests {
     }
 
     #[test]
-    fn stroke_line_at_y_of_function() {
+    fn plot_line_with_range_x_zero() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
+        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
 
-        Plot::stroke_line_at_y_of_function(&mut canvas, -5.0, -5.0, 5.0, &f);
-        Plot::stroke_line_at_y_of_function(&mut canvas, -2.5, -5.0, 5.0, &f);
-        Plot::stroke_line_at_y_of_function(&mut canvas, 0.0, -5.0, 5.0, &f);
-        Plot::stroke_line_at_y_of_function(&mut canvas, 2.5, -5.0, 5.0, &f);
-        Plot::stroke_line_at_y_of_function(&mut canvas, 5.0, -5.0, 5.0, &f);
+        Plot::line(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉
-⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒⠒
-⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤
-⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀
-⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn stroke_line_at_y_of_function_value_out_of_bounds() {
+    fn plot_line_with_range_y_zero() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let y: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
 
-        Plot::stroke_line_at_y_of_function(&mut canvas, -100.0, -5.0, 5.0, &f);
+        Plot::line(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
@@ -1504,156 +3915,131 @@ mod tests {
     }
 
     #[test]
-    fn compute_screen_x_of_function() {
-        let canvas = TextCanvas::new(15, 5);
+    fn plot_line_with_x_and_y_of_different_lengths_more_x() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = (-10..=10).map(f64::from).collect();
+        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
 
+        Plot::stroke_xy_axes(&mut canvas, &x, &y);
+        Plot::line(&mut canvas, &x, &y);
+
+        // The scale is correct. At X = 0, Y = 5. To see values on the
+        // right, you'd have to increase the range of Y (up to 15, to
+        // match X).
         assert_eq!(
-            0,
-            Plot::compute_screen_x_of_function(&canvas, -10.0, -10.0, 10.0, &f).unwrap()
-        );
-        assert_eq!(
-            14,
-            Plot::compute_screen_x_of_function(&canvas, 0.0, -10.0, 10.0, &f).unwrap()
-        );
-        assert_eq!(
-            29,
-            Plot::compute_screen_x_of_function(&canvas, 10.0, -10.0, 10.0, &f).unwrap()
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⢀⠔⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⡠⠊⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠤⠤⢤⠴⠥⠤⠤⡧⠤⠤⠤⠤⠤⠤⠤
+⠀⡠⠊⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⡰⠁⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+"
         );
     }
 
     #[test]
-    fn compute_screen_x_of_function_range_0() {
-        let canvas = TextCanvas::new(15, 5);
+    fn plot_line_with_x_and_y_of_different_lengths_more_y() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |x| x;
+        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let y: Vec<f64> = (-10..=10).map(f64::from).collect();
 
+        Plot::stroke_xy_axes(&mut canvas, &x, &y);
+        Plot::line(&mut canvas, &x, &y);
+
+        // The scale is correct. Y range is [-10;10], (0;10) is just
+        // not rendered because X stops when Y = 0. If you'd continue
+        // to the right, Y would reach 10 at X = 15.
         assert_eq!(
-            15,
-            Plot::compute_screen_x_of_function(&canvas, -10.0, 0.0, 0.0, &f).unwrap()
-        );
-        assert_eq!(
-            15,
-            Plot::compute_screen_x_of_function(&canvas, 0.0, 0.0, 0.0, &f).unwrap()
-        );
-        assert_eq!(
-            15,
-            Plot::compute_screen_x_of_function(&canvas, 10.0, 0.0, 0.0, &f).unwrap()
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠤⠤⠤⠤⠤⠤⠤⡧⠤⠤⠤⣤⡤⠤⠶
+⠀⠀⠀⠀⠀⣀⡠⡧⠒⠊⠉⠀⠀⠀⠀
+⡠⠤⠒⠊⠉⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+"
         );
     }
 
     #[test]
-    fn compute_screen_x_of_function_canvas_size_1x1() {
-        let canvas = TextCanvas::new(1, 1);
+    fn plot_line_breaks_at_non_finite_values() {
+        let mut canvas = TextCanvas::new(5, 1);
 
-        let f = |x| x;
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let y = [0.0, 0.0, f64::NAN, 1.0, 1.0];
 
-        assert_eq!(
-            0,
-            Plot::compute_screen_x_of_function(&canvas, -10.0, -10.0, 10.0, &f).unwrap()
-        );
-        assert_eq!(
-            0,
-            Plot::compute_screen_x_of_function(&canvas, 0.0, -10.0, 10.0, &f).unwrap()
-        );
-        assert_eq!(
-            1,
-            Plot::compute_screen_x_of_function(&canvas, 10.0, -10.0, 10.0, &f).unwrap()
-        );
+        Plot::line(&mut canvas, &x, &y);
+
+        assert_eq!(canvas.to_string(), "⣀⡀⠀⠉⠉\n");
     }
 
     #[test]
-    fn compute_screen_y_of_function() {
-        let canvas = TextCanvas::new(15, 5);
+    fn plot_line_non_finite_values_do_not_affect_scale() {
+        let mut canvas = TextCanvas::new(5, 1);
 
-        let f = |x| x;
+        let x = [0.0, 1.0, f64::INFINITY, 2.0];
+        let y = [0.0, 1.0, 1.0, f64::NEG_INFINITY];
 
-        assert_eq!(
-            19,
-            Plot::compute_screen_y_of_function(&canvas, -10.0, -10.0, 10.0, &f).unwrap()
-        );
-        assert_eq!(
-            10,
-            Plot::compute_screen_y_of_function(&canvas, 0.0, -10.0, 10.0, &f).unwrap()
-        );
-        assert_eq!(
-            0,
-            Plot::compute_screen_y_of_function(&canvas, 10.0, -10.0, 10.0, &f).unwrap()
-        );
+        Plot::line(&mut canvas, &x, &y);
+
+        assert_eq!(canvas.to_string(), "⡠⠒⠁⠀⠀\n");
     }
 
     #[test]
-    fn compute_screen_y_of_function_range_0() {
-        let canvas = TextCanvas::new(15, 5);
+    fn plot_line_with_only_non_finite_values() {
+        let mut canvas = TextCanvas::new(5, 1);
 
-        let f = |x| x;
+        Plot::line(&mut canvas, &[f64::NAN, 1.0], &[f64::NAN, f64::INFINITY]);
 
-        assert_eq!(
-            10,
-            Plot::compute_screen_y_of_function(&canvas, -10.0, 0.0, 0.0, &f).unwrap()
-        );
-        assert_eq!(
-            10,
-            Plot::compute_screen_y_of_function(&canvas, 0.0, 0.0, 0.0, &f).unwrap()
-        );
-        assert_eq!(
-            10,
-            Plot::compute_screen_y_of_function(&canvas, 10.0, 0.0, 0.0, &f).unwrap()
-        );
+        assert_eq!(canvas.to_string(), "⠀⠀⠀⠀⠀\n");
     }
 
     #[test]
-    fn compute_screen_y_of_function_canvas_size_1x1() {
-        let canvas = TextCanvas::new(1, 1);
+    fn plot_scatter_skips_non_finite_values() {
+        let mut canvas = TextCanvas::new(5, 1);
 
-        let f = |x| x;
+        let x = [0.0, 1.0, 2.0];
+        let y = [0.0, f64::NAN, 1.0];
 
-        assert_eq!(
-            3,
-            Plot::compute_screen_y_of_function(&canvas, -10.0, -10.0, 10.0, &f).unwrap()
-        );
-        assert_eq!(
-            2,
-            Plot::compute_screen_y_of_function(&canvas, 0.0, -10.0, 10.0, &f).unwrap()
-        );
-        assert_eq!(
-            0,
-            Plot::compute_screen_y_of_function(&canvas, 10.0, -10.0, 10.0, &f).unwrap()
-        );
+        Plot::scatter(&mut canvas, &x, &y);
+
+        assert_eq!(canvas.to_string(), "⡀⠀⠀⠀⠈\n");
     }
 
     #[test]
-    fn plot_line() {
+    fn plot_scatter() {
         let mut canvas = TextCanvas::new(15, 5);
 
         let x: Vec<f64> = (-5..=5).map(f64::from).collect();
         let y: Vec<f64> = (-5..=5).map(f64::from).collect();
 
         Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⢀⠤⠒⠉
-⠀⠀⠀⠀⠀⠀⠀⡇⢀⠤⠊⠁⠀⠀⠀
-⠤⠤⠤⠤⠤⢤⠤⡯⠥⠤⠤⠤⠤⠤⠤
-⠀⠀⢀⠤⠊⠁⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⡠⠊⠁⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⢀⠀⠂⠈
+⠀⠀⠀⠀⠀⠀⠀⡇⢀⠀⠂⠀⠀⠀⠀
+⠤⠤⠤⠤⠤⢤⠤⡧⠤⠤⠤⠤⠤⠤⠤
+⠀⠀⢀⠀⠂⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⡀⠂⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn plot_line_with_empty_x() {
+    fn plot_scatter_with_empty_x() {
         let mut canvas = TextCanvas::new(15, 5);
 
         let x: Vec<f64> = vec![];
         let y: Vec<f64> = (-5..=5).map(f64::from).collect();
 
         Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
@@ -1668,14 +4054,14 @@ mod tests {
     }
 
     #[test]
-    fn plot_line_with_empty_y() {
+    fn plot_scatter_with_empty_y() {
         let mut canvas = TextCanvas::new(15, 5);
 
         let x: Vec<f64> = (-5..=5).map(f64::from).collect();
         let y: Vec<f64> = vec![];
 
         Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
@@ -1690,62 +4076,89 @@ mod tests {
     }
 
     #[test]
-    fn plot_line_sorts_elements_by_x_before_plotting() {
+    fn plot_scatter_with_single_value() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = vec![-5.0, 5.0, -2.5];
-        let y: Vec<f64> = vec![5.0, 2.5, -2.5];
+        let x: Vec<f64> = vec![0.0];
+        let y: Vec<f64> = vec![0.0];
 
-        Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
 
-        // Not sorted, it would look like this:
-        // ⠉⠑⠒⠒⠤⠤⢄⣇⡀⠀⠀⠀⠀⠀⠀
-        // ⠀⠀⠀⠀⠀⠀⠀⡇⠈⠉⠉⠒⠒⢢⡤
-        // ⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⣀⠤⠊⠁⠀
-        // ⠒⠒⠒⠒⠒⠒⢒⡷⠖⠚⠒⠒⠒⠒⠒
-        // ⠀⠀⠀⢀⠤⠒⠁⡇⠀⠀⠀⠀⠀⠀⠀
         assert_eq!(
             canvas.to_string(),
             "\
-⢣⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠈⢆⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⢀⡠
-⠀⠘⡄⠀⠀⠀⠀⡇⠀⠀⣀⠤⠊⠁⠀
-⠒⠒⠳⡒⠒⠒⢒⡷⠖⠛⠒⠒⠒⠒⠒
-⠀⠀⠀⢣⠤⠒⠁⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠠⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn plot_line_with_single_value() {
+    fn plot_scatter_with_marker_dot_is_scatter() {
+        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let y: Vec<f64> = x.iter().map(|x| x * x).collect();
+
         let mut canvas = TextCanvas::new(15, 5);
+        Plot::scatter_with_marker(&mut canvas, &x, &y, Marker::Dot);
 
-        let x: Vec<f64> = vec![0.0];
-        let y: Vec<f64> = vec![0.0];
+        let mut scatter = TextCanvas::new(15, 5);
+        Plot::scatter(&mut scatter, &x, &y);
+
+        assert_eq!(canvas, scatter);
+    }
+
+    #[test]
+    fn plot_scatter_with_marker_shapes() {
+        let shapes = [
+            (Marker::Dot, "⠀⠀⠀\n⠀⠈⠀\n"),
+            (Marker::Plus, "⠀⢀⠀\n⠀⠙⠁\n"),
+            (Marker::Cross, "⠀⡀⡀\n⠀⠊⠂\n"),
+            (Marker::Square, "⠀⣀⡀\n⠀⠓⠃\n"),
+        ];
+
+        for (marker, expected) in shapes {
+            let mut canvas = TextCanvas::new(3, 2);
+
+            Plot::scatter_with_marker(&mut canvas, &[0.0], &[0.0], marker);
+
+            assert_eq!(canvas.to_string(), expected, "{marker:?}");
+        }
+    }
+
+    #[test]
+    fn plot_scatter_with_marker_with_range_y_zero() {
+        let mut canvas = TextCanvas::new(5, 2);
+
+        Plot::scatter_with_marker(&mut canvas, &[0.0, 1.0], &[0.0, 0.0], Marker::Plus);
 
-        Plot::line(&mut canvas, &x, &y);
+        assert_eq!(canvas.to_string(), "⡀⠀⠀⠀⢀\n⠋⠀⠀⠀⠙\n");
+    }
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠠⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-"
+    #[test]
+    fn plot_line_with_markers() {
+        let mut canvas = TextCanvas::new(5, 2);
+
+        Plot::line_with_markers(
+            &mut canvas,
+            &[0.0, 1.0, 2.0],
+            &[0.0, 1.0, 0.0],
+            Marker::Cross,
         );
+
+        assert_eq!(canvas.to_string(), "⠀⡰⠱⡀⠀\n⡰⠁⠀⠈⢆\n");
     }
 
     #[test]
-    fn plot_line_with_range_xy_zero() {
+    fn plot_scatter_with_range_xy_zero() {
         let mut canvas = TextCanvas::new(15, 5);
 
         let x: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
         let y: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
 
-        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
@@ -1760,41 +4173,41 @@ mod tests {
     }
 
     #[test]
-    fn plot_line_with_range_x_zero() {
+    fn plot_scatter_with_range_x_zero() {
         let mut canvas = TextCanvas::new(15, 5);
 
         let x: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
         let y: Vec<f64> = (-5..=5).map(f64::from).collect();
 
-        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠨⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠨⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠨⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠨⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⢨⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn plot_line_with_range_y_zero() {
+    fn plot_scatter_with_range_y_zero() {
         let mut canvas = TextCanvas::new(15, 5);
 
         let x: Vec<f64> = (-5..=5).map(f64::from).collect();
         let y: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
 
-        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
 
         assert_eq!(
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤
+⠄⠄⠠⠀⠄⠠⠀⠄⠠⠀⠄⠠⠀⠄⠠
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
@@ -1802,14 +4215,14 @@ mod tests {
     }
 
     #[test]
-    fn plot_line_with_x_and_y_of_different_lengths_more_x() {
+    fn plot_scatter_with_x_and_y_of_different_lengths_more_x() {
         let mut canvas = TextCanvas::new(15, 5);
 
         let x: Vec<f64> = (-10..=10).map(f64::from).collect();
         let y: Vec<f64> = (-5..=5).map(f64::from).collect();
 
         Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
 
         // The scale is correct. At X = 0, Y = 5. To see values on the
         // right, you'd have to increase the range of Y (up to 15, to
@@ -1817,24 +4230,24 @@ mod tests {
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⢀⠔⡇⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⡠⠊⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠤⠤⢤⠴⠥⠤⠤⡧⠤⠤⠤⠤⠤⠤⠤
-⠀⡠⠊⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⡰⠁⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⢀⠐⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⡀⠂⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠤⠤⢤⠴⠤⠤⠤⡧⠤⠤⠤⠤⠤⠤⠤
+⠀⡀⠂⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⡐⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn plot_line_with_x_and_y_of_different_lengths_more_y() {
+    fn plot_scatter_with_x_and_y_of_different_lengths_more_y() {
         let mut canvas = TextCanvas::new(15, 5);
 
         let x: Vec<f64> = (-5..=5).map(f64::from).collect();
         let y: Vec<f64> = (-10..=10).map(f64::from).collect();
 
         Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::line(&mut canvas, &x, &y);
+        Plot::scatter(&mut canvas, &x, &y);
 
         // The scale is correct. Y range is [-10;10], (0;10) is just
         // not rendered because X stops when Y = 0. If you'd continue
@@ -1844,94 +4257,86 @@ mod tests {
             "\
 ⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠤⠤⠤⠤⠤⠤⠤⡧⠤⠤⠤⣤⡤⠤⠶
-⠀⠀⠀⠀⠀⣀⡠⡧⠒⠊⠉⠀⠀⠀⠀
-⡠⠤⠒⠊⠉⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠤⠤⠤⠤⠤⠤⠤⡧⠤⠤⠤⢤⠤⠤⠴
+⠀⠀⠀⠀⠀⢀⠀⡇⠐⠀⠁⠀⠀⠀⠀
+⡀⠄⠐⠀⠁⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn plot_scatter() {
+    fn plot_function() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
-        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let f = |x| x * x;
 
-        Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::scatter(&mut canvas, &x, &y);
+        Plot::stroke_xy_axes_of_function(&mut canvas, -10.0, 10.0, &f);
+        Plot::function(&mut canvas, -10.0, 10.0, &f);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⢀⠀⠂⠈
-⠀⠀⠀⠀⠀⠀⠀⡇⢀⠀⠂⠀⠀⠀⠀
-⠤⠤⠤⠤⠤⢤⠤⡧⠤⠤⠤⠤⠤⠤⠤
-⠀⠀⢀⠀⠂⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⡀⠂⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠱⡀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⡜
+⠀⢣⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⡜⠀
+⠀⠀⠣⡀⠀⠀⠀⡇⠀⠀⠀⠀⡔⠁⠀
+⠀⠀⠀⠑⡄⠀⠀⡇⠀⠀⢀⠎⠀⠀⠀
+⣀⣀⣀⣀⣈⣒⣤⣇⣤⣒⣁⣀⣀⣀⣀
 "
         );
     }
 
     #[test]
-    fn plot_scatter_with_empty_x() {
+    fn plot_function_explosive_values() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = vec![];
-        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
-
-        Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::scatter(&mut canvas, &x, &y);
+        Plot::function(&mut canvas, -10.0, 10.0, &|x: f64| x.powi(10));
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+⢱⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡎
+⢸⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇
+⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀
+⠀⠘⠤⠤⠤⠤⠤⣀⠤⠤⠤⠤⠤⠃⠀
 "
         );
     }
 
     #[test]
-    fn plot_scatter_with_empty_y() {
+    fn plot_function_with_single_value() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
-        let y: Vec<f64> = vec![];
+        let f = |_| 0.0;
 
-        Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::scatter(&mut canvas, &x, &y);
+        Plot::function(&mut canvas, 0.0, 0.0, &f);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠠⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn plot_scatter_with_single_value() {
+    fn plot_function_with_range_zero() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = vec![0.0];
-        let y: Vec<f64> = vec![0.0];
+        let f = |_| 0.0;
 
-        Plot::scatter(&mut canvas, &x, &y);
+        Plot::function(&mut canvas, -10.0, 10.0, &f);
 
         assert_eq!(
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠠⠀⠀⠀⠀⠀⠀⠀
+⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
@@ -1939,153 +4344,311 @@ mod tests {
     }
 
     #[test]
-    fn plot_scatter_with_range_xy_zero() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn plot_function_segmented_breaks_at_jumps() {
+        let mut canvas = TextCanvas::new(4, 1);
 
-        let x: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
-        let y: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
+        let f = |x: f64| if x < 0.0 { 0.0 } else { 1.0 };
 
-        Plot::scatter(&mut canvas, &x, &y);
+        Plot::function_segmented(&mut canvas, -1.0, 1.0, &f, 1.0);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠠⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-"
-        );
+        assert_eq!(canvas.to_string(), "⣀⣀⠉⠉\n");
     }
 
     #[test]
-    fn plot_scatter_with_range_x_zero() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn plot_function_segmented_joins_below_threshold() {
+        let mut canvas = TextCanvas::new(4, 1);
 
-        let x: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
-        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let f = |x: f64| if x < 0.0 { 0.0 } else { 1.0 };
 
-        Plot::scatter(&mut canvas, &x, &y);
+        Plot::function_segmented(&mut canvas, -1.0, 1.0, &f, 3.0);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⠀⠨⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠨⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠨⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠨⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⢨⠀⠀⠀⠀⠀⠀⠀
-"
-        );
+        assert_eq!(canvas.to_string(), "⣀⣠⠋⠉\n");
     }
 
     #[test]
-    fn plot_scatter_with_range_y_zero() {
+    fn plot_function_segmented_breaks_at_non_finite_values() {
+        let mut canvas = TextCanvas::new(4, 1);
+
+        let f = |x: f64| if x.abs() < 0.5 { f64::NAN } else { 0.0 };
+
+        Plot::function_segmented(&mut canvas, -1.0, 1.0, &f, 100.0);
+
+        assert_eq!(canvas.to_string(), "⠤⠀⠀⠤\n");
+    }
+
+    #[test]
+    fn plot_function_adaptive_smooth_function_is_like_uniform() {
         let mut canvas = TextCanvas::new(15, 5);
+        let mut uniform = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
-        let y: Vec<f64> = (-5..=5).map(|_| 0.0).collect();
+        Plot::function_adaptive(&mut canvas, -1.0, 1.0, &|x| x, 200);
+        Plot::function(&mut uniform, -1.0, 1.0, &|x| x);
 
-        Plot::scatter(&mut canvas, &x, &y);
+        assert_eq!(canvas.to_string(), uniform.to_string());
+    }
+
+    #[test]
+    fn plot_function_adaptive_respects_max_points() {
+        let f = |x: f64| x + 2.0 / (1.0 + ((x - 0.13) / 0.01).powi(2));
+        let mut canvas = TextCanvas::new(15, 5);
+        let mut uniform = TextCanvas::new(15, 5);
+
+        // No room for refinement.
+        Plot::function_adaptive(&mut canvas, -1.0, 1.0, &f, 30);
+        Plot::function(&mut uniform, -1.0, 1.0, &f);
+
+        assert_eq!(canvas.to_string(), uniform.to_string());
+    }
+
+    #[test]
+    fn plot_function_adaptive_with_too_few_points() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        Plot::function_adaptive(&mut canvas, -1.0, 1.0, &|x| x, 0);
+
+        assert_eq!(canvas.to_string(), "⡠⠔⠊\n");
+    }
+
+    #[test]
+    fn plot_function_scatter() {
+        let mut canvas = TextCanvas::new(5, 2);
+
+        Plot::function_scatter(&mut canvas, 0.0, 4.0, 5.0, &|x| x);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⡀⠂⠈\n⡀⠂⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn plot_function_scatter_is_not_joined() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        Plot::function_scatter(&mut canvas, -1.0, 1.0, 3.0, &|x| x * x);
 
         assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠄⠄⠠⠀⠄⠠⠀⠄⠠⠀⠄⠠⠀⠄⠠
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-"
+            canvas
+                .buffer
+                .iter()
+                .flatten()
+                .filter(|&&pixel| pixel)
+                .count(),
+            3
         );
     }
 
     #[test]
-    fn plot_scatter_with_x_and_y_of_different_lengths_more_x() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn plot_bars_from_baseline_outside_of_values() {
+        let mut canvas = TextCanvas::new(3, 2);
 
-        let x: Vec<f64> = (-10..=10).map(f64::from).collect();
-        let y: Vec<f64> = (-5..=5).map(f64::from).collect();
+        Plot::bars_from(&mut canvas, &[0.0, 1.0, 2.0], &[1.0, 2.0, 3.0], 0.0);
+
+        assert_eq!(canvas.to_string(), "⠀⡀⢸\n⡆⡇⢸\n");
+    }
+
+    #[test]
+    fn plot_bars_from_non_zero_baseline() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        Plot::bars_from(&mut canvas, &[0.0, 1.0, 2.0], &[1.0, 2.0, 3.0], 2.0);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⢸\n⡇⠁⠈\n");
+    }
+
+    #[test]
+    fn plot_bars_from_with_empty_values() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        Plot::bars_from(&mut canvas, &[], &[1.0], 0.0);
+        Plot::bars_from(&mut canvas, &[1.0], &[], 0.0);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn plot_bars_ranged_signed_heights() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        Plot::bars_ranged(&mut canvas, &[0.0, 1.0, 2.0], &[1.0, -1.0]);
+
+        assert_eq!(canvas.to_string(), "⣿⡇⠀\n⠉⣿⣿\n");
+    }
+
+    #[test]
+    fn plot_bars_ranged_skips_non_finite_edges() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        Plot::bars_ranged(&mut canvas, &[0.0, 1.0, f64::NAN, 3.0], &[1.0, 2.0, 2.0]);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n⣿⠀⠀\n");
+    }
+
+    #[test]
+    fn plot_bars_ranged_with_empty_values() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        Plot::bars_ranged(&mut canvas, &[0.0], &[1.0]);
+        Plot::bars_ranged(&mut canvas, &[0.0, 1.0], &[]);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n⠀⠀⠀\n");
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn plot_from_csv() {
+        let data = "x,foo,y\n1,a,10\n2,b,20\n3,c,30\n";
+
+        let (x, y) = Plot::from_csv(data.as_bytes(), 0, 2).unwrap();
+
+        assert_eq!(x, [1.0, 2.0, 3.0]);
+        assert_eq!(y, [10.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn plot_from_csv_missing_or_invalid_values_are_nan() {
+        let data = "x,y\n1, 10\n2,n/a\n3\n";
+
+        let (x, y) = Plot::from_csv(data.as_bytes(), 0, 1).unwrap();
+
+        assert_eq!(x, [1.0, 2.0, 3.0]);
+        assert_eq!(y[..1], [10.0]);
+        assert!(y[1].is_nan());
+        assert!(y[2].is_nan());
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn plot_from_csv_header_only() {
+        let (x, y) = Plot::from_csv(&b"x,y\n"[..], 0, 1).unwrap();
+
+        assert!(x.is_empty());
+        assert!(y.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn plot_from_csv_invalid_record_is_error() {
+        let data = b"x,y\n1,10\n2,\xff\n3,30\n";
+
+        let result = Plot::from_csv(&data[..], 0, 1);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn plot_sparkline_ramp() {
+        let values: Vec<f64> = (0..8).map(f64::from).collect();
+
+        assert_eq!(Plot::sparkline(&values), "⣀⣤⣶⣿");
+    }
+
+    #[test]
+    fn plot_sparkline_odd_number_of_values() {
+        assert_eq!(Plot::sparkline(&[0.0, 3.0, 1.0]), "⣸⡄");
+    }
+
+    #[test]
+    fn plot_sparkline_constant_values() {
+        assert_eq!(Plot::sparkline(&[5.0, 5.0]), "⣶");
+    }
+
+    #[test]
+    fn plot_sparkline_ignores_non_finite_values() {
+        assert_eq!(Plot::sparkline(&[0.0, f64::NAN, 1.0, 0.0]), "⡀⣇");
+        assert_eq!(Plot::sparkline(&[f64::NAN, f64::INFINITY, f64::NAN]), "⠀⠀");
+    }
+
+    #[test]
+    fn plot_sparkline_empty() {
+        assert_eq!(Plot::sparkline(&[]), "");
+    }
+
+    #[test]
+    fn plot_function_area_positive_values_fill_from_bottom() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::scatter(&mut canvas, &x, &y);
+        Plot::function_area(&mut canvas, 1.0, 2.0, &|x| x);
 
-        // The scale is correct. At X = 0, Y = 5. To see values on the
-        // right, you'd have to increase the range of Y (up to 15, to
-        // match X).
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⢀⠐⡇⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⡀⠂⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠤⠤⢤⠴⠤⠤⠤⡧⠤⠤⠤⠤⠤⠤⠤
-⠀⡀⠂⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⡐⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⣤⣾
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⣤⣾⣿⣿⣿
+⠀⠀⠀⠀⠀⠀⢀⣤⣾⣿⣿⣿⣿⣿⣿
+⠀⠀⠀⢀⣤⣾⣿⣿⣿⣿⣿⣿⣿⣿⣿
+⣀⣤⣾⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
 "
         );
     }
 
     #[test]
-    fn plot_scatter_with_x_and_y_of_different_lengths_more_y() {
+    fn plot_function_area_negative_values_fill_from_top() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
-        let y: Vec<f64> = (-10..=10).map(f64::from).collect();
-
-        Plot::stroke_xy_axes(&mut canvas, &x, &y);
-        Plot::scatter(&mut canvas, &x, &y);
+        Plot::function_area(&mut canvas, 1.0, 2.0, &|x| -x);
 
-        // The scale is correct. Y range is [-10;10], (0;10) is just
-        // not rendered because X stops when Y = 0. If you'd continue
-        // to the right, Y would reach 10 at X = 15.
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
-⠤⠤⠤⠤⠤⠤⠤⡧⠤⠤⠤⢤⠤⠤⠴
-⠀⠀⠀⠀⠀⢀⠀⡇⠐⠀⠁⠀⠀⠀⠀
-⡀⠄⠐⠀⠁⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀
+⠙⠿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
+⠀⠀⠈⠙⠿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
+⠀⠀⠀⠀⠀⠈⠙⠿⣿⣿⣿⣿⣿⣿⣿
+⠀⠀⠀⠀⠀⠀⠀⠀⠈⠙⠿⣿⣿⣿⣿
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠙⠿⣿
 "
         );
     }
 
     #[test]
-    fn plot_function() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn plot_function_filled_smooth_with_range_zero() {
+        let mut canvas = TextCanvas::new(3, 2);
 
-        let f = |x| x * x;
+        Plot::function_filled_smooth(&mut canvas, -1.0, 1.0, 3.0, &|_| 1.0);
 
-        Plot::stroke_xy_axes_of_function(&mut canvas, -10.0, 10.0, &f);
-        Plot::function(&mut canvas, -10.0, 10.0, &f);
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n⠉⠉⠉\n");
+    }
+
+    #[test]
+    fn smooth_catmull_rom_goes_through_points() {
+        let x = [0.0, 1.0, 2.0, 3.0];
+        let y = [0.0, 2.0, -1.0, 5.0];
 
+        let (px, py) = Plot::smooth_catmull_rom(&x, &y, 7.0);
+
+        assert_eq!(px, [0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0]);
+        assert_eq!((py[0], py[2], py[4], py[6]), (0.0, 2.0, -1.0, 5.0));
+    }
+
+    #[test]
+    fn smooth_catmull_rom_keeps_straight_lines_straight() {
+        let x = [0.0, 1.0, 2.0, 3.0];
+        let y = [0.0, 2.0, 4.0, 6.0];
+
+        let (_, py) = Plot::smooth_catmull_rom(&x, &y, 7.0);
+
+        assert_eq!(py, [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn smooth_catmull_rom_with_less_than_two_points() {
         assert_eq!(
-            canvas.to_string(),
-            "\
-⠱⡀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⡜
-⠀⢣⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⡜⠀
-⠀⠀⠣⡀⠀⠀⠀⡇⠀⠀⠀⠀⡔⠁⠀
-⠀⠀⠀⠑⡄⠀⠀⡇⠀⠀⢀⠎⠀⠀⠀
-⣀⣀⣀⣀⣈⣒⣤⣇⣤⣒⣁⣀⣀⣀⣀
-"
+            Plot::smooth_catmull_rom(&[1.0], &[2.0], 5.0),
+            (vec![1.0], vec![2.0])
         );
+        assert_eq!(Plot::smooth_catmull_rom(&[], &[], 5.0), (vec![], vec![]));
     }
 
     #[test]
-    fn plot_function_with_single_value() {
+    fn plot_function_area_with_range_zero() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |_| 0.0;
-
-        Plot::function(&mut canvas, 0.0, 0.0, &f);
+        Plot::function_area(&mut canvas, -10.0, 10.0, &|_| 5.0);
 
         assert_eq!(
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠠⠀⠀⠀⠀⠀⠀⠀
+⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
@@ -2093,25 +4656,77 @@ mod tests {
     }
 
     #[test]
-    fn plot_function_with_range_zero() {
+    fn plot_parametric_keeps_order_of_t() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        let f = |_| 0.0;
+        // Zig-zag: X goes back and forth, which sorting by X would
+        // turn into a single line.
+        let fx = |t: f64| if t <= 1.0 { t } else { 2.0 - t };
+        let fy = |t: f64| t;
 
-        Plot::function(&mut canvas, -10.0, 10.0, &f);
+        Plot::parametric(&mut canvas, 0.0, 2.0, &fx, &fy);
 
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤⠤
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠉⠑⠒⠤⢄⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠉⠙⠒⠦⢤⣀⡀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⣀⡭⠽⠒
+⠀⠀⠀⠀⢀⣀⠤⠴⠒⠋⠉⠀⠀⠀⠀
+⡤⠔⠒⠉⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"
+        );
+    }
+
+    #[test]
+    fn plot_parametric_with_range_zero() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        Plot::parametric(&mut canvas, 0.0, 1.0, &|_| 0.0, &|t| t);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
+    #[test]
+    fn compute_function_exclusive_excludes_upper_bound() {
+        let (x, y) = Plot::compute_function_exclusive(-5.0, 5.0, 5.0, &|x| -x);
+
+        assert_eq!(x, vec![-5.0, -3.0, -1.0, 1.0, 3.0]);
+        assert_eq!(y, vec![5.0, 3.0, 1.0, -1.0, -3.0]);
+        assert!(*x.last().unwrap() < 5.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn compute_function_exclusive_stitches_without_duplicates() {
+        let tau = std::f64::consts::TAU;
+
+        let (x, _) = Plot::compute_function_exclusive(0.0, tau, 100.0, &f64::sin);
+        let (x2, _) = Plot::compute_function_exclusive(tau, 2.0 * tau, 100.0, &f64::sin);
+
+        assert_eq!(x.len(), 100);
+        assert!(*x.last().unwrap() < tau);
+        assert!((x.last().unwrap() + tau / 100.0 - tau).abs() < 1e-12);
+        assert_eq!(x2[0], tau);
+    }
+
+    #[test]
+    fn compute_function_exclusive_with_no_values() {
+        let (x, y) = Plot::compute_function_exclusive(0.0, 1.0, 0.0, &|x| x);
+
+        assert!(x.is_empty());
+        assert!(y.is_empty());
+    }
+
     #[test]
     fn compute_function_works_with_structs() {
         #[derive(Debug, PartialEq)]
@@ -2160,6 +4775,58 @@ mod tests {
         assert_eq!(y_bar, vec![5.0, 2.5, -0.0, -2.5, -5.0]);
     }
 
+    #[test]
+    fn chart_axis_break_x() {
+        let mut canvas = TextCanvas::new(20, 7);
+
+        let x: Vec<f64> = (95..=100).map(f64::from).collect();
+
+        Chart::line(&mut canvas, &x, &x);
+        Chart::axis_break(&mut canvas, 0.5, Axis::X);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀100⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⡠⠊⢸⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⡠⠊⠀⠀⢸⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⡠⠊⠀⠀⠀⠀⢸⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⡠⠊⠀⠀⡀⡀⠀⠀⢸⠀
+⠀⠀⠀⠀⠀⠀95⠀⠓⠒⠒⠒⡲⡱⠓⠒⠒⠚⠀
+⠀⠀⠀⠀⠀⠀⠀⠀95⠀⠀⠀⠀⠀⠀⠀100
+"
+        );
+    }
+
+    #[test]
+    fn chart_axis_break_non_finite_position() {
+        let x: Vec<f64> = (95..=100).map(f64::from).collect();
+
+        let mut canvas = TextCanvas::new(20, 7);
+        Chart::line(&mut canvas, &x, &x);
+        Chart::axis_break(&mut canvas, f64::NAN, Axis::X);
+
+        let mut expected = TextCanvas::new(20, 7);
+        Chart::line(&mut expected, &x, &x);
+
+        assert_eq!(canvas, expected);
+    }
+
+    #[test]
+    fn chart_axis_break_position_is_clamped() {
+        let x: Vec<f64> = (95..=100).map(f64::from).collect();
+
+        let mut canvas = TextCanvas::new(20, 7);
+        Chart::line(&mut canvas, &x, &x);
+        Chart::axis_break(&mut canvas, 2.0, Axis::Y);
+
+        let mut expected = TextCanvas::new(20, 7);
+        Chart::line(&mut expected, &x, &x);
+        Chart::axis_break(&mut expected, 1.0, Axis::Y);
+
+        assert_eq!(canvas, expected);
+    }
+
     #[test]
     fn chart_x_squared() {
         let mut canvas = TextCanvas::new(71, 19);
@@ -2319,6 +4986,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn chart_default_options_match_plain_chart() {
+        let x: Vec<f64> = (-5..=5).map(f64::from).collect();
+        let y: Vec<f64> = x.iter().map(|x| x * 1000.0).collect();
+
+        let mut canvas = TextCanvas::new(35, 10);
+        Chart::line(&mut canvas, &x, &y);
+
+        let mut with_options = TextCanvas::new(35, 10);
+        Chart::line_with_options(&mut with_options, &x, &y, &ChartOptions::new());
+
+        assert_eq!(with_options.to_string(), canvas.to_string());
+    }
+
+    #[test]
+    fn chart_custom_number_format_is_used_for_all_labels() {
+        let mut canvas = TextCanvas::new(35, 10);
+
+        let x = [1.0, 2.0];
+        let y = [3.0, 4.0];
+
+        let mut options = ChartOptions::new();
+        options.number_format(NumberFormat::new(|number| format!("<{number}>")));
+
+        Chart::scatter_with_options(&mut canvas, &x, &y, &options);
+
+        let text = canvas.text_to_string();
+        for label in ["<1>", "<2>", "<3>", "<4>"] {
+            assert!(text.contains(label), "Missing {label} in:\n{text}");
+        }
+    }
+
+    #[test]
+    fn chart_function_with_options() {
+        let mut canvas = TextCanvas::new(35, 10);
+
+        let mut options = ChartOptions::new();
+        options.number_format(NumberFormat::new(|number| format!("${number:.2}")));
+
+        Chart::function_with_options(&mut canvas, 0.0, 1.0, &|x| x, &options);
+
+        let text = canvas.text_to_string();
+        assert!(text.contains("$0.00"));
+        assert!(text.contains("$1.00"));
+    }
+
     #[test]
     fn chart_empty() {
         let mut canvas = TextCanvas::new(35, 10);
@@ -2386,6 +5099,274 @@ mod tests {
         Chart::line(&mut canvas, &x, &y);
     }
 
+    #[test]
+    fn chart_heatmap() {
+        let mut canvas = TextCanvas::new(4, 2);
+
+        let data = [vec![0.0, 1.0], vec![2.0, 3.0]];
+
+        Chart::heatmap(&mut canvas, &data, |t| {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let level = (t * 255.0).round() as u8;
+            Color::new().rgb(level, level, level).fix()
+        });
+
+        let gray = |level| Color::new().rgb(level, level, level).fix();
+        assert_eq!(
+            canvas.color_buffer,
+            [
+                [gray(0), gray(0), gray(85), gray(85)],
+                [gray(170), gray(170), gray(255), gray(255)],
+            ]
+        );
+        assert!(canvas.buffer.iter().flatten().all(|&pixel| pixel));
+    }
+
+    #[test]
+    fn chart_heatmap_uneven_regions() {
+        let mut canvas = TextCanvas::new(5, 1);
+
+        let data = [vec![0.0, 1.0, 2.0]];
+
+        Chart::heatmap(&mut canvas, &data, |t| {
+            if t < 0.25 {
+                Color::new().red().fix()
+            } else if t < 0.75 {
+                Color::new().green().fix()
+            } else {
+                Color::new().blue().fix()
+            }
+        });
+
+        let (red, green, blue) = (
+            Color::new().red().fix(),
+            Color::new().green().fix(),
+            Color::new().blue().fix(),
+        );
+        assert_eq!(
+            canvas.color_buffer,
+            [[red, green.clone(), green, blue.clone(), blue]]
+        );
+    }
+
+    #[test]
+    fn chart_heatmap_constant_values() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        let data = [vec![7.0, 7.0]];
+
+        Chart::heatmap(&mut canvas, &data, |t| {
+            assert!((t - 0.5).abs() < f64::EPSILON);
+            Color::new().red().fix()
+        });
+
+        assert_eq!(
+            canvas.color_buffer,
+            [[Color::new().red().fix(), Color::new().red().fix()]]
+        );
+    }
+
+    #[test]
+    fn chart_heatmap_ignores_non_finite_values() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        let data = [vec![0.0, f64::NAN, 1.0]];
+
+        Chart::heatmap(&mut canvas, &data, |_| Color::new().red().fix());
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[0;31m⣿\x1b[0m⠀\x1b[0;31m⣿\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn chart_contour_straight_line() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        Chart::contour(&mut canvas, |x, _| x, (0.0, 5.0), (0.0, 1.0), &[2.8]);
+
+        assert_eq!(canvas.to_string(), "⠀⢸⠀\n");
+    }
+
+    #[test]
+    fn chart_contour_y_goes_up() {
+        let mut canvas = TextCanvas::new(1, 2);
+
+        // Only the top half is above the level.
+        Chart::contour(&mut canvas, |_, y| y, (0.0, 1.0), (0.0, 7.0), &[3.8]);
+
+        assert_eq!(canvas.to_string(), "⣀\n⠀\n");
+    }
+
+    #[test]
+    fn chart_contour_multiple_levels() {
+        let mut canvas = TextCanvas::new(5, 1);
+
+        Chart::contour(&mut canvas, |x, _| x, (0.0, 9.0), (0.0, 1.0), &[2.5, 6.5]);
+
+        assert_eq!(canvas.to_string(), "⠀⢸⠀⢸⠀\n");
+    }
+
+    #[test]
+    fn chart_contour_level_out_of_range() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        Chart::contour(&mut canvas, |x, y| x + y, (0.0, 1.0), (0.0, 1.0), &[10.0]);
+        Chart::contour(&mut canvas, |x, y| x + y, (0.0, 1.0), (0.0, 1.0), &[]);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn chart_contour_ignores_non_finite_values() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        Chart::contour(&mut canvas, |_, _| f64::NAN, (0.0, 1.0), (0.0, 1.0), &[0.0]);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn chart_quiver_uniform_field() {
+        let mut canvas = TextCanvas::new(4, 1);
+
+        Chart::quiver(
+            &mut canvas,
+            |_, _| 1.0,
+            |_, _| 0.0,
+            (0.0, 1.0),
+            (0.0, 1.0),
+            1,
+            1,
+        );
+
+        assert_eq!(canvas.to_string(), "⠀⠤⡦⠀\n");
+    }
+
+    #[test]
+    fn chart_quiver_y_goes_up() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        Chart::quiver(
+            &mut canvas,
+            |_, _| 0.0,
+            |_, _| 1.0,
+            (0.0, 1.0),
+            (0.0, 1.0),
+            1,
+            1,
+        );
+
+        // Tip is at the top.
+        assert_eq!(canvas.to_string(), "⢀⣄\n⠀⠃\n");
+    }
+
+    #[test]
+    fn chart_quiver_arrows_are_scaled_to_largest() {
+        let mut canvas = TextCanvas::new(8, 1);
+
+        // Right half is twice as strong as left half.
+        let fx = |x: f64, _: f64| if x < 0.5 { 1.0 } else { 2.0 };
+        Chart::quiver(&mut canvas, fx, |_, _| 0.0, (0.0, 1.0), (0.0, 1.0), 2, 1);
+
+        assert_eq!(canvas.to_string(), "⠀⠠⠄⠀⠀⠤⡦⠀\n");
+    }
+
+    #[test]
+    fn chart_quiver_null_field() {
+        let mut canvas = TextCanvas::new(4, 1);
+
+        Chart::quiver(
+            &mut canvas,
+            |_, _| 0.0,
+            |_, _| 0.0,
+            (0.0, 1.0),
+            (0.0, 1.0),
+            2,
+            1,
+        );
+
+        assert_eq!(canvas.to_string(), "⠀⠄⠀⠄\n");
+    }
+
+    #[test]
+    fn chart_quiver_ignores_non_finite_values() {
+        let mut canvas = TextCanvas::new(4, 1);
+
+        Chart::quiver(
+            &mut canvas,
+            |_, _| f64::NAN,
+            |_, _| 0.0,
+            (0.0, 1.0),
+            (0.0, 1.0),
+            2,
+            1,
+        );
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn chart_quiver_empty_grid() {
+        let mut canvas = TextCanvas::new(4, 1);
+
+        Chart::quiver(
+            &mut canvas,
+            |_, _| 1.0,
+            |_, _| 1.0,
+            (0.0, 1.0),
+            (0.0, 1.0),
+            0,
+            1,
+        );
+        Chart::quiver(
+            &mut canvas,
+            |_, _| 1.0,
+            |_, _| 1.0,
+            (0.0, 1.0),
+            (0.0, 1.0),
+            1,
+            0,
+        );
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn chart_heatmap_non_finite_region_is_left_untouched() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_pixel(0, 0, true);
+
+        let data = [vec![f64::INFINITY, 1.0]];
+
+        Chart::heatmap(&mut canvas, &data, |_| Color::new().red().fix());
+
+        assert_eq!(canvas.to_string(), "⠁\x1b[0;31m⣿\x1b[0m\n");
+    }
+
+    #[test]
+    fn chart_heatmap_empty() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        Chart::heatmap(&mut canvas, &[], |_| Color::new().red().fix());
+        Chart::heatmap(&mut canvas, &[vec![]], |_| Color::new().red().fix());
+        Chart::heatmap(&mut canvas, &[vec![f64::NAN]], |_| Color::new().red().fix());
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n");
+        assert!(!canvas.is_colorized());
+    }
+
+    #[test]
+    fn chart_viridis() {
+        assert_eq!(Chart::viridis(0.0), Color::new().rgb(68, 1, 84).fix());
+        assert_eq!(Chart::viridis(0.5), Color::new().rgb(33, 145, 140).fix());
+        assert_eq!(Chart::viridis(1.0), Color::new().rgb(253, 231, 37).fix());
+        // Clamped.
+        assert_eq!(Chart::viridis(-1.0), Chart::viridis(0.0));
+        assert_eq!(Chart::viridis(2.0), Chart::viridis(1.0));
+    }
+
     #[test]
     #[allow(clippy::cognitive_complexity)]
     fn chart_pretty_number() {
@@ -1,7 +1,10 @@
 use std::env;
 use std::fmt::{self, Formatter, Write};
+use std::ops;
 use std::sync::LazyLock;
 
+use crate::maths::Interpolation;
+
 /// `true` if `NO_COLOR` is set and is non-empty.
 #[cfg(not(tarpaulin_include))]
 #[allow(unreachable_code)]
@@ -79,7 +82,8 @@ enum ColorMode {
 ///
 /// <div class="warning">
 ///
-/// RGB (24-bit) colors do not work on every terminal.
+/// RGB (24-bit) colors do not work on every terminal. See
+/// [`auto_downgrade()`](Color::auto_downgrade) for a fallback.
 ///
 /// </div>
 ///
@@ -94,7 +98,8 @@ enum ColorMode {
 ///
 /// Mixing foreground and background colors of different modes doesn't
 /// work. For example, if you have an 8-bit background, use an 8-bit
-/// foreground as well.
+/// foreground as well. Use [`is_consistent()`](Color::is_consistent)
+/// to check for this.
 ///
 /// </div>
 ///
@@ -116,6 +121,7 @@ enum ColorMode {
 ///
 /// - <https://en.wikipedia.org/wiki/ANSI_escape_code#Colors>
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[allow(clippy::struct_field_names)]
 pub struct Color {
     mode: ColorMode,
     color_rgb: Option<(u8, u8, u8)>,
@@ -217,7 +223,78 @@ impl Color {
         self.to_string().replace(PLACEHOLDER, string)
     }
 
-    fn is_empty(&self) -> bool {
+    /// Opening escape sequence of the color, without the reset.
+    ///
+    /// [`format()`](Color::format) wraps a string between this prefix
+    /// and [`reset()`](Color::reset). Emitting the prefix once for a
+    /// run of characters of the same color, and resetting at the end,
+    /// makes for much shorter output than wrapping each character.
+    ///
+    /// An empty color (or color disabled with `NO_COLOR`) has no
+    /// prefix. `format()` does not reset it either, but writing a
+    /// reset anyway is harmless.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// let red = Color::new().red().fix();
+    ///
+    /// assert_eq!(red.open(), "\x1b[0;31m");
+    /// assert_eq!(format!("{}foo{}", red.open(), Color::reset()), red.format("foo"));
+    /// assert_eq!(Color::new().open(), "");
+    /// ```
+    #[must_use]
+    pub fn open(&self) -> String {
+        let formatted = self.to_string();
+        match formatted.split_once(PLACEHOLDER) {
+            Some((prefix, _)) => prefix.to_owned(),
+            None => formatted,
+        }
+    }
+
+    /// Escape sequence that resets all colors and attributes.
+    ///
+    /// See [`open()`](Color::open).
+    #[must_use]
+    pub fn reset() -> &'static str {
+        RESET
+    }
+
+    /// Whether all the colors set are of the same mode.
+    ///
+    /// Only the colors of the last mode set (RGB, 4-bit, or 8-bit) are
+    /// displayed, the others are silently ignored. This returns `false`
+    /// if colors of different modes were mixed (e.g., an RGB foreground
+    /// with an 8-bit background), which is useful to catch mistakes in
+    /// tests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert!(Color::new().rgb(255, 0, 0).bg_rgb(0, 0, 255).is_consistent());
+    /// assert!(Color::new().red().bg_blue().is_consistent());
+    ///
+    /// // RGB foreground, 4-bit background.
+    /// assert!(!Color::new().rgb(255, 0, 0).bg_blue().is_consistent());
+    /// ```
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        let has_rgb = self.color_rgb.is_some() || self.bg_color_rgb.is_some();
+        let has_4bit = self.color_4bit.is_some() || self.bg_color_4bit.is_some();
+        let has_8bit = self.color_8bit.is_some() || self.bg_color_8bit.is_some();
+
+        [has_rgb, has_4bit, has_8bit]
+            .iter()
+            .filter(|&&has_mode| has_mode)
+            .count()
+            <= 1
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
         matches!(self.mode, ColorMode::NoColor) && !self.has_display_attributes()
     }
 
@@ -225,6 +302,38 @@ impl Color {
         !matches!(self.mode, ColorMode::NoColor)
     }
 
+    pub(crate) fn has_background(&self) -> bool {
+        self.bg_color_rgb.is_some() || self.bg_color_4bit.is_some() || self.bg_color_8bit.is_some()
+    }
+
+    /// Copy of `self`, with the background of `other`.
+    ///
+    /// Foreground and display attributes are taken from `self`. If the
+    /// two colors are of different modes, they cannot be mixed, and
+    /// the foreground is dropped in favour of the background.
+    pub(crate) fn with_background_of(&self, other: &Self) -> Self {
+        if !other.has_colors() {
+            return self.clone();
+        }
+
+        let mut color = if self.mode == other.mode {
+            self.clone()
+        } else {
+            Self {
+                is_bold: self.is_bold,
+                is_italic: self.is_italic,
+                is_underlined: self.is_underlined,
+                ..Self::new()
+            }
+        };
+
+        color.mode = other.mode.clone();
+        color.bg_color_rgb = other.bg_color_rgb;
+        color.bg_color_4bit = other.bg_color_4bit;
+        color.bg_color_8bit = other.bg_color_8bit;
+        color
+    }
+
     // Display Attributes.
 
     pub fn bold(&mut self) -> &mut Self {
@@ -296,6 +405,387 @@ impl Color {
         self.bg_rgb(r, g, b)
     }
 
+    /// Create RGB color from a `0xRRGGBB` integer.
+    ///
+    /// Bits above the lower 24 are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::from_u32(0x1f_2c3b).format("hello, world"),
+    ///     "\x1b[0;38;2;31;44;59mhello, world\x1b[0m",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_u32(rgb: u32) -> Self {
+        let [_, red, green, blue] = rgb.to_be_bytes();
+        Self::new().rgb(red, green, blue).fix()
+    }
+
+    /// Foreground RGB color as a `#rrggbb` hex string.
+    ///
+    /// Returns `None` if the color is not in RGB mode, or if it has no
+    /// RGB foreground.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(Color::from_u32(0x1f_2c3b).to_hex(), Some(String::from("#1f2c3b")));
+    /// assert_eq!(Color::new().red().to_hex(), None);
+    /// ```
+    #[must_use]
+    pub fn to_hex(&self) -> Option<String> {
+        if self.mode != ColorMode::ColorRGB {
+            return None;
+        }
+        let (red, green, blue) = self.color_rgb?;
+        Some(format!("#{red:02x}{green:02x}{blue:02x}"))
+    }
+
+    /// Darken RGB color by moving each channel towards 0.
+    ///
+    /// `amount` is clamped to `[0; 1]`; 0 leaves the color untouched,
+    /// 1 makes it black. Both foreground and background are affected.
+    ///
+    /// Colors that are not in RGB mode are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::new().rgb(200, 100, 50).darken(0.5),
+    ///     Color::new().rgb(100, 50, 25).fix(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn darken(&self, amount: f64) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        self.map_rgb(|channel| f64::from(channel) * (1.0 - amount))
+    }
+
+    /// Lighten RGB color by moving each channel towards 255.
+    ///
+    /// `amount` is clamped to `[0; 1]`; 0 leaves the color untouched,
+    /// 1 makes it white. Both foreground and background are affected.
+    ///
+    /// Colors that are not in RGB mode are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::new().rgb(55, 155, 255).lighten(0.5),
+    ///     Color::new().rgb(155, 205, 255).fix(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn lighten(&self, amount: f64) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        self.map_rgb(|channel| {
+            let channel = f64::from(channel);
+            channel + (255.0 - channel) * amount
+        })
+    }
+
+    /// Linearly interpolate between two RGB colors.
+    ///
+    /// Foreground and background are interpolated independently, if
+    /// both colors have them. Display attributes are taken from `from`.
+    ///
+    /// If either color is not in RGB mode, there is nothing to
+    /// interpolate, and the closest of the two is returned (`from` if
+    /// `t < 0.5`, `to` otherwise).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// let black = Color::new().rgb(0, 0, 0).fix();
+    /// let white = Color::new().rgb(255, 255, 255).fix();
+    ///
+    /// assert_eq!(Color::lerp_rgb(&black, &white, 0.0), black);
+    /// assert_eq!(Color::lerp_rgb(&black, &white, 0.5), Color::new().rgb(128, 128, 128).fix());
+    /// assert_eq!(Color::lerp_rgb(&black, &white, 1.0), white);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `from` - Start
+    /// - `to` - End
+    /// - `t` - Time, clamped to `[0; 1]`
+    #[must_use]
+    pub fn lerp_rgb(from: &Self, to: &Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+
+        if from.mode != ColorMode::ColorRGB || to.mode != ColorMode::ColorRGB {
+            return if t < 0.5 { from.clone() } else { to.clone() };
+        }
+
+        // The value is in `[0; 255]`, since `t` is in `[0; 1]`.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let lerp_channel = |a: u8, b: u8| {
+            Interpolation::lerp(f64::from(a), f64::from(b), t)
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+        let lerp_color = |a: Option<(u8, u8, u8)>, b: Option<(u8, u8, u8)>| match (a, b) {
+            (Some(a), Some(b)) => Some((
+                lerp_channel(a.0, b.0),
+                lerp_channel(a.1, b.1),
+                lerp_channel(a.2, b.2),
+            )),
+            (a, b) => {
+                if t < 0.5 {
+                    a
+                } else {
+                    b
+                }
+            }
+        };
+
+        Self {
+            color_rgb: lerp_color(from.color_rgb, to.color_rgb),
+            bg_color_rgb: lerp_color(from.bg_color_rgb, to.bg_color_rgb),
+            ..from.clone()
+        }
+    }
+
+    /// Create RGB color from HSV values.
+    ///
+    /// # Arguments
+    ///
+    /// - `hue` - Hue in degrees (wraps around, 360° = 0°).
+    /// - `saturation` - Saturation, clamped to `[0; 1]`.
+    /// - `value` - Value (brightness), clamped to `[0; 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(Color::from_hsv(120.0, 1.0, 1.0), Color::new().rgb(0, 255, 0).fix());
+    /// ```
+    #[must_use]
+    pub fn from_hsv(hue: f64, saturation: f64, value: f64) -> Self {
+        let (red, green, blue) = Self::hsv_to_rgb(hue, saturation, value);
+        Self::new().rgb(red, green, blue).fix()
+    }
+
+    /// Foreground RGB color as HSV values.
+    ///
+    /// Hue is in degrees `[0; 360)`, saturation and value are in
+    /// `[0; 1]`.
+    ///
+    /// Returns `None` if the color is not in RGB mode, or if it has no
+    /// RGB foreground.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(Color::new().rgb(0, 0, 255).to_hsv(), Some((240.0, 1.0, 1.0)));
+    /// ```
+    #[must_use]
+    pub fn to_hsv(&self) -> Option<(f64, f64, f64)> {
+        if self.mode != ColorMode::ColorRGB {
+            return None;
+        }
+        self.color_rgb.map(Self::rgb_to_hsv)
+    }
+
+    /// Relative luminance of the RGB foreground.
+    ///
+    /// Luminance is computed as defined by WCAG, from `0.0` (black) to
+    /// `1.0` (white). It is the perceived brightness of the color,
+    /// which is useful to pick readable text colors.
+    ///
+    /// Returns `None` if the color is not in RGB mode, or if it has no
+    /// RGB foreground.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(Color::new().rgb(0, 0, 0).luminance(), Some(0.0));
+    /// assert_eq!(Color::new().rgb(255, 255, 255).luminance(), Some(1.0));
+    /// // Green looks brighter than blue.
+    /// assert!(
+    ///     Color::new().rgb(0, 255, 0).luminance() > Color::new().rgb(0, 0, 255).luminance()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn luminance(&self) -> Option<f64> {
+        if self.mode != ColorMode::ColorRGB {
+            return None;
+        }
+        self.color_rgb.map(Self::rgb_to_luminance)
+    }
+
+    /// Relative luminance of the RGB background.
+    ///
+    /// Same as [`luminance()`](Color::luminance), for the background.
+    pub(crate) fn bg_luminance(&self) -> Option<f64> {
+        if self.mode != ColorMode::ColorRGB {
+            return None;
+        }
+        self.bg_color_rgb.map(Self::rgb_to_luminance)
+    }
+
+    fn rgb_to_luminance((red, green, blue): (u8, u8, u8)) -> f64 {
+        // sRGB to linear RGB.
+        let linearize = |channel: u8| {
+            let channel = f64::from(channel) / 255.0;
+            if channel <= 0.040_45 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linearize(red) + 0.7152 * linearize(green) + 0.0722 * linearize(blue)
+    }
+
+    /// Complementary color (hue rotated by 180°).
+    ///
+    /// Both foreground and background are affected. Colors that are
+    /// not in RGB mode are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::new().rgb(255, 0, 0).complementary(),
+    ///     Color::new().rgb(0, 255, 255).fix(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn complementary(&self) -> Self {
+        self.rotate_hue(180.0)
+    }
+
+    /// Analogous colors (hue rotated by `-degrees` and `+degrees`).
+    ///
+    /// Both foreground and background are affected. Colors that are
+    /// not in RGB mode are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// let (left, right) = Color::new().rgb(0, 255, 0).analogous(120.0);
+    ///
+    /// assert_eq!(left, Color::new().rgb(255, 0, 0).fix());
+    /// assert_eq!(right, Color::new().rgb(0, 0, 255).fix());
+    /// ```
+    #[must_use]
+    pub fn analogous(&self, degrees: f64) -> (Self, Self) {
+        (self.rotate_hue(-degrees), self.rotate_hue(degrees))
+    }
+
+    fn rotate_hue(&self, degrees: f64) -> Self {
+        if self.mode != ColorMode::ColorRGB {
+            return self.clone();
+        }
+
+        let rotate = |rgb: (u8, u8, u8)| {
+            let (hue, saturation, value) = Self::rgb_to_hsv(rgb);
+            Self::hsv_to_rgb(hue + degrees, saturation, value)
+        };
+
+        Self {
+            color_rgb: self.color_rgb.map(rotate),
+            bg_color_rgb: self.bg_color_rgb.map(rotate),
+            ..self.clone()
+        }
+    }
+
+    fn rgb_to_hsv((red, green, blue): (u8, u8, u8)) -> (f64, f64, f64) {
+        let (r, g, b) = (
+            f64::from(red) / 255.0,
+            f64::from(green) / 255.0,
+            f64::from(blue) / 255.0,
+        );
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        #[allow(clippy::float_cmp)] // `max` is one of `r`, `g`, or `b`.
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+        (hue, saturation, max)
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[allow(clippy::many_single_char_names)]
+    fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> (u8, u8, u8) {
+        let hue = hue.rem_euclid(360.0);
+        let saturation = saturation.clamp(0.0, 1.0);
+        let value = value.clamp(0.0, 1.0);
+
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - ((hue / 60.0).rem_euclid(2.0) - 1.0).abs());
+        let m = value - chroma;
+
+        let (r, g, b) = match hue {
+            h if h < 60.0 => (chroma, x, 0.0),
+            h if h < 120.0 => (x, chroma, 0.0),
+            h if h < 180.0 => (0.0, chroma, x),
+            h if h < 240.0 => (0.0, x, chroma),
+            h if h < 300.0 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        // Values are in `[0; 1]`, so they fit in `[0; 255]`.
+        let to_u8 = |channel: f64| ((channel + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+
+        (to_u8(r), to_u8(g), to_u8(b))
+    }
+
+    /// Apply `f` to every channel of the foreground and background.
+    fn map_rgb(&self, f: impl Fn(u8) -> f64) -> Self {
+        if self.mode != ColorMode::ColorRGB {
+            return self.clone();
+        }
+
+        // The value is clamped to `[0; 255]` beforehand.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let map_channel = |channel: u8| f(channel).round().clamp(0.0, 255.0) as u8;
+        let map_color = |(red, green, blue): (u8, u8, u8)| {
+            (map_channel(red), map_channel(green), map_channel(blue))
+        };
+
+        Self {
+            color_rgb: self.color_rgb.map(map_color),
+            bg_color_rgb: self.bg_color_rgb.map(map_color),
+            ..self.clone()
+        }
+    }
+
     fn hex_to_rgb(mut hex_color: &str) -> (u8, u8, u8) {
         if hex_color.starts_with('#') {
             hex_color = &hex_color[1..];
@@ -438,6 +928,197 @@ impl Color {
         colors
     }
 
+    /// Convert RGB colors to their nearest 8-bit Xterm equivalent.
+    ///
+    /// Both foreground and background are converted, and display
+    /// attributes are preserved. Colors that are not in RGB mode are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::new().rgb(255, 0, 0).to_8bit(),
+    ///     Color::new().x_red_1().fix(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_8bit(&self) -> Self {
+        if self.mode != ColorMode::ColorRGB {
+            return self.clone();
+        }
+
+        Self {
+            mode: ColorMode::Color8bit,
+            color_rgb: None,
+            bg_color_rgb: None,
+            color_8bit: self.color_rgb.map(Self::rgb_to_8bit),
+            bg_color_8bit: self.bg_color_rgb.map(Self::rgb_to_8bit),
+            ..self.clone()
+        }
+    }
+
+    /// Find the closest match in the 6×6×6 color cube and in the
+    /// grayscale ramp, and keep the closest of the two.
+    fn rgb_to_8bit((red, green, blue): (u8, u8, u8)) -> u8 {
+        const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+        let nearest_level = |channel: u8| -> u8 {
+            (0..6u8)
+                .min_by_key(|&i| {
+                    (i32::from(CUBE_LEVELS[usize::from(i)]) - i32::from(channel)).abs()
+                })
+                .expect("there are 6 levels")
+        };
+
+        // Color cube: 16..=231.
+        let (r, g, b) = (
+            nearest_level(red),
+            nearest_level(green),
+            nearest_level(blue),
+        );
+        let cube_index = 16 + 36 * r + 6 * g + b;
+        let cube_color = (
+            CUBE_LEVELS[usize::from(r)],
+            CUBE_LEVELS[usize::from(g)],
+            CUBE_LEVELS[usize::from(b)],
+        );
+
+        // Grayscale ramp: 232..=255, from 8 to 238 in steps of 10.
+        let mean = (u16::from(red) + u16::from(green) + u16::from(blue)) / 3;
+        let gray_step = u8::try_from((mean.saturating_sub(8) + 5) / 10)
+            .unwrap_or(u8::MAX)
+            .min(23);
+        let gray_index = 232 + gray_step;
+        let gray_level = 8 + 10 * gray_step;
+        let gray_color = (gray_level, gray_level, gray_level);
+
+        let rgb = (red, green, blue);
+        if Self::rgb_distance(rgb, gray_color) < Self::rgb_distance(rgb, cube_color) {
+            gray_index
+        } else {
+            cube_index
+        }
+    }
+
+    /// Convert RGB colors to their nearest 4-bit equivalent.
+    ///
+    /// Both foreground and background are converted, and display
+    /// attributes are preserved. Colors that are not in RGB mode are
+    /// returned unchanged.
+    ///
+    /// If `prefer_bright` is `true`, the nearest of all 16 colors is
+    /// used. If it is `false`, bright variants are never used, and the
+    /// nearest of the 8 normal colors is used instead. This is useful
+    /// for terminals that render bright colors poorly.
+    ///
+    /// Note: The actual colors depend on the terminal's theme. This
+    /// compares against the default Xterm palette.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::new().rgb(250, 10, 10).to_4bit(true),
+    ///     Color::new().bright_red().fix(),
+    /// );
+    /// assert_eq!(
+    ///     Color::new().rgb(250, 10, 10).to_4bit(false),
+    ///     Color::new().red().fix(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_4bit(&self, prefer_bright: bool) -> Self {
+        if self.mode != ColorMode::ColorRGB {
+            return self.clone();
+        }
+
+        Self {
+            mode: ColorMode::Color4bit,
+            color_rgb: None,
+            bg_color_rgb: None,
+            color_4bit: self
+                .color_rgb
+                .map(|rgb| Self::rgb_to_4bit(rgb, prefer_bright)),
+            bg_color_4bit: self
+                .bg_color_rgb
+                .map(|rgb| Self::rgb_to_4bit(rgb, prefer_bright) + 10),
+            ..self.clone()
+        }
+    }
+
+    /// Find the closest foreground code, in the normal colors, and in
+    /// the bright colors if `prefer_bright` is `true`.
+    fn rgb_to_4bit(rgb: (u8, u8, u8), prefer_bright: bool) -> u8 {
+        // Default Xterm palette.
+        const NORMAL: [(u8, (u8, u8, u8)); 8] = [
+            (30, (0, 0, 0)),
+            (31, (205, 0, 0)),
+            (32, (0, 205, 0)),
+            (33, (205, 205, 0)),
+            (34, (0, 0, 238)),
+            (35, (205, 0, 205)),
+            (36, (0, 205, 205)),
+            (37, (229, 229, 229)),
+        ];
+        const BRIGHT: [(u8, (u8, u8, u8)); 8] = [
+            (90, (127, 127, 127)),
+            (91, (255, 0, 0)),
+            (92, (0, 255, 0)),
+            (93, (255, 255, 0)),
+            (94, (92, 92, 255)),
+            (95, (255, 0, 255)),
+            (96, (0, 255, 255)),
+            (97, (255, 255, 255)),
+        ];
+
+        let bright: &[_] = if prefer_bright { &BRIGHT } else { &[] };
+
+        NORMAL
+            .iter()
+            .chain(bright)
+            .min_by_key(|(_, color)| Self::rgb_distance(rgb, *color))
+            .map(|(code, _)| *code)
+            .expect("palette is not empty")
+    }
+
+    /// Squared Euclidean distance between two RGB colors.
+    fn rgb_distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> i32 {
+        let (dr, dg, db) = (
+            i32::from(r1) - i32::from(r2),
+            i32::from(g1) - i32::from(g2),
+            i32::from(b1) - i32::from(b2),
+        );
+        dr * dr + dg * dg + db * db
+    }
+
+    /// Whether the terminal (probably) supports RGB colors.
+    ///
+    /// This is a best-effort guess, based on the `COLORTERM` env
+    /// variable being set to `truecolor` or `24bit`. There is no
+    /// reliable way to know for sure.
+    #[must_use]
+    pub fn supports_truecolor() -> bool {
+        env::var_os("COLORTERM").is_some_and(|value| value == "truecolor" || value == "24bit")
+    }
+
+    /// Downgrade RGB colors to 8-bit if RGB is not supported.
+    ///
+    /// If [`supports_truecolor()`](Color::supports_truecolor) is
+    /// `true`, the color is returned unchanged. Otherwise, it is
+    /// converted with [`to_8bit()`](Color::to_8bit).
+    #[must_use]
+    pub fn auto_downgrade(&self) -> Self {
+        if Self::supports_truecolor() {
+            return self.clone();
+        }
+        self.to_8bit()
+    }
+
     color_8bit!(x_black, 0);
     color_8bit!(x_maroon, 1);
     color_8bit!(x_green, 2);
@@ -983,13 +1664,89 @@ impl fmt::Display for Color {
     }
 }
 
-impl From<&mut Color> for String {
-    fn from(color: &mut Color) -> Self {
-        color.to_string()
-    }
-}
+/// Add RGB colors, channel by channel.
+///
+/// Channels saturate at 255. Foreground and background are added
+/// independently; if only one side has a foreground (or background),
+/// it is kept as is. Display attributes are taken from the left-hand
+/// side.
+///
+/// If either color is not in RGB mode, they cannot be added, and the
+/// left-hand side is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::Color;
+///
+/// let red = Color::new().rgb(200, 0, 0).fix();
+/// let blue = Color::new().rgb(100, 0, 255).fix();
+///
+/// assert_eq!(red + blue, Color::new().rgb(255, 0, 255).fix());
+/// ```
+impl ops::Add for Color {
+    type Output = Self;
 
-impl From<&Color> for String {
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.mode != ColorMode::ColorRGB || rhs.mode != ColorMode::ColorRGB {
+            return self;
+        }
+
+        let add_color = |a: Option<(u8, u8, u8)>, b: Option<(u8, u8, u8)>| match (a, b) {
+            (Some(a), Some(b)) => Some((
+                a.0.saturating_add(b.0),
+                a.1.saturating_add(b.1),
+                a.2.saturating_add(b.2),
+            )),
+            (a, b) => a.or(b),
+        };
+
+        Self {
+            color_rgb: add_color(self.color_rgb, rhs.color_rgb),
+            bg_color_rgb: add_color(self.bg_color_rgb, rhs.bg_color_rgb),
+            ..self
+        }
+    }
+}
+
+/// Scale the brightness of an RGB color.
+///
+/// Every channel of the foreground and background is multiplied by
+/// the factor, and clamped to `[0; 255]`.
+///
+/// Colors that are not in RGB mode are returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::Color;
+///
+/// let color = Color::new().rgb(100, 150, 200).fix();
+///
+/// assert_eq!(color.clone() * 0.5, Color::new().rgb(50, 75, 100).fix());
+/// assert_eq!(color * 2.0, Color::new().rgb(200, 255, 255).fix());
+/// ```
+impl ops::Mul<f64> for Color {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.map_rgb(|channel| f64::from(channel) * rhs)
+    }
+}
+
+impl From<u32> for Color {
+    fn from(rgb: u32) -> Self {
+        Self::from_u32(rgb)
+    }
+}
+
+impl From<&mut Color> for String {
+    fn from(color: &mut Color) -> Self {
+        color.to_string()
+    }
+}
+
+impl From<&Color> for String {
     fn from(color: &Color) -> Self {
         color.to_string()
     }
@@ -1064,11 +1821,110 @@ mod tests {
 
     // Special Cases.
 
+    #[test]
+    fn open_and_reset_equal_format() {
+        for color in [
+            Color::new().red().fix(),
+            Color::new().bold().italic().fix(),
+            Color::new().x_orange_1().bg_x_grey_3().fix(),
+            Color::new()
+                .rgb(31, 44, 59)
+                .bg_rgb(1, 2, 3)
+                .underline()
+                .fix(),
+        ] {
+            assert_eq!(
+                format!("{}hello, world{}", color.open(), Color::reset()),
+                color.format("hello, world")
+            );
+        }
+    }
+
+    #[test]
+    fn open_empty_color() {
+        assert_eq!(Color::new().open(), "");
+        assert_eq!(Color::reset(), "\x1b[0m");
+    }
+
     #[test]
     fn no_color() {
         assert_eq!(Color::new().format("hello, world"), "hello, world");
     }
 
+    #[test]
+    fn luminance() {
+        assert_eq!(Color::new().rgb(0, 0, 0).luminance(), Some(0.0));
+        assert_eq!(Color::new().rgb(255, 255, 255).luminance(), Some(1.0));
+        assert!((Color::new().rgb(128, 128, 128).luminance().unwrap() - 0.2159).abs() < 1e-4);
+        assert!((Color::new().rgb(255, 0, 0).luminance().unwrap() - 0.2126).abs() < 1e-9);
+    }
+
+    #[test]
+    fn luminance_not_rgb() {
+        assert_eq!(Color::new().luminance(), None);
+        assert_eq!(Color::new().red().luminance(), None);
+        assert_eq!(Color::new().bg_rgb(0, 0, 0).luminance(), None);
+    }
+
+    #[test]
+    fn bg_luminance() {
+        assert_eq!(Color::new().bg_rgb(255, 255, 255).bg_luminance(), Some(1.0));
+        assert_eq!(Color::new().rgb(255, 255, 255).bg_luminance(), None);
+        assert_eq!(Color::new().bg_blue().bg_luminance(), None);
+    }
+
+    #[test]
+    fn is_consistent() {
+        assert!(Color::new().is_consistent());
+        assert!(Color::new().bold().is_consistent());
+        assert!(Color::new().rgb(1, 2, 3).bg_rgb(4, 5, 6).is_consistent());
+        assert!(Color::new().red().bg_blue().is_consistent());
+        assert!(Color::new().x_navy().bg_x_navy().is_consistent());
+    }
+
+    #[test]
+    fn is_consistent_mixed_modes() {
+        assert!(!Color::new().rgb(1, 2, 3).bg_x_navy().is_consistent());
+        assert!(!Color::new().x_navy().bg_rgb(1, 2, 3).is_consistent());
+        assert!(!Color::new().red().bg_x_navy().is_consistent());
+        // Foreground overridden by another mode.
+        assert!(!Color::new().rgb(1, 2, 3).red().is_consistent());
+    }
+
+    #[test]
+    fn with_background_of() {
+        assert_eq!(
+            Color::new()
+                .bold()
+                .red()
+                .with_background_of(Color::new().bg_blue()),
+            Color::new().bold().red().bg_blue().fix()
+        );
+        assert_eq!(
+            Color::new().with_background_of(Color::new().bg_x_aqua()),
+            Color::new().bg_x_aqua().fix()
+        );
+    }
+
+    #[test]
+    fn with_background_of_no_color() {
+        assert_eq!(
+            Color::new().red().with_background_of(&Color::new()),
+            Color::new().red().fix()
+        );
+    }
+
+    #[test]
+    fn with_background_of_different_modes() {
+        assert_eq!(
+            Color::new()
+                .bold()
+                .red()
+                .with_background_of(Color::new().bg_rgb(1, 2, 3)),
+            Color::new().bold().bg_rgb(1, 2, 3).fix()
+        );
+    }
+
     // Display Attributes
 
     #[test]
@@ -1220,6 +2076,231 @@ mod tests {
         );
     }
 
+    #[test]
+    fn color_rgb_from_u32() {
+        assert_eq!(
+            Color::from_u32(0x1f_2c3b).format("hello, world"),
+            "\x1b[0;38;2;31;44;59mhello, world\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn color_rgb_from_u32_ignores_upper_bits() {
+        assert_eq!(Color::from_u32(0xff1f_2c3b), Color::from_u32(0x1f_2c3b));
+    }
+
+    #[test]
+    fn color_from_u32_trait() {
+        assert_eq!(Color::from(0x1f_2c3b), Color::from_u32(0x1f_2c3b));
+    }
+
+    #[test]
+    fn color_rgb_to_hex() {
+        assert_eq!(
+            Color::from_u32(0x1f_2c3b).to_hex(),
+            Some(String::from("#1f2c3b"))
+        );
+    }
+
+    #[test]
+    fn color_rgb_to_hex_round_trip() {
+        let hex = Color::new().rbg_from_hex("#00ff7f").to_hex().unwrap();
+
+        assert_eq!(hex, "#00ff7f");
+        assert_eq!(Color::new().rbg_from_hex(&hex).to_hex(), Some(hex));
+    }
+
+    #[test]
+    fn color_to_hex_not_rgb() {
+        assert_eq!(Color::new().to_hex(), None);
+        assert_eq!(Color::new().red().to_hex(), None);
+        assert_eq!(Color::new().x_aqua().to_hex(), None);
+    }
+
+    #[test]
+    fn color_to_hex_background_only() {
+        assert_eq!(Color::new().bg_rgb(1, 2, 3).to_hex(), None);
+    }
+
+    #[test]
+    fn color_rgb_darken() {
+        let color = Color::new().rgb(200, 100, 50).fix();
+
+        assert_eq!(color.darken(0.0), color);
+        assert_eq!(color.darken(0.5), Color::new().rgb(100, 50, 25).fix());
+        assert_eq!(color.darken(1.0), Color::new().rgb(0, 0, 0).fix());
+    }
+
+    #[test]
+    fn color_rgb_lighten() {
+        let color = Color::new().rgb(55, 155, 255).fix();
+
+        assert_eq!(color.lighten(0.0), color);
+        assert_eq!(color.lighten(0.5), Color::new().rgb(155, 205, 255).fix());
+        assert_eq!(color.lighten(1.0), Color::new().rgb(255, 255, 255).fix());
+    }
+
+    #[test]
+    fn color_rgb_darken_lighten_amount_is_clamped() {
+        let color = Color::new().rgb(200, 100, 50).fix();
+
+        assert_eq!(color.darken(-1.0), color);
+        assert_eq!(color.darken(2.0), Color::new().rgb(0, 0, 0).fix());
+        assert_eq!(color.lighten(-1.0), color);
+        assert_eq!(color.lighten(2.0), Color::new().rgb(255, 255, 255).fix());
+    }
+
+    #[test]
+    fn color_rgb_darken_affects_background_and_keeps_attributes() {
+        assert_eq!(
+            Color::new()
+                .bold()
+                .rgb(200, 100, 50)
+                .bg_rgb(10, 20, 30)
+                .darken(0.5),
+            Color::new().bold().rgb(100, 50, 25).bg_rgb(5, 10, 15).fix()
+        );
+    }
+
+    #[test]
+    fn color_darken_lighten_not_rgb_is_unchanged() {
+        assert_eq!(Color::new().red().darken(0.5), Color::new().red().fix());
+        assert_eq!(
+            Color::new().x_aqua().lighten(0.5),
+            Color::new().x_aqua().fix()
+        );
+    }
+
+    #[test]
+    fn color_lerp_rgb() {
+        let from = Color::new().rgb(0, 100, 200).fix();
+        let to = Color::new().rgb(200, 100, 0).fix();
+
+        assert_eq!(Color::lerp_rgb(&from, &to, 0.0), from);
+        assert_eq!(
+            Color::lerp_rgb(&from, &to, 0.25),
+            Color::new().rgb(50, 100, 150).fix()
+        );
+        assert_eq!(Color::lerp_rgb(&from, &to, 1.0), to);
+    }
+
+    #[test]
+    fn color_lerp_rgb_t_is_clamped() {
+        let from = Color::new().rgb(0, 100, 200).fix();
+        let to = Color::new().rgb(200, 100, 0).fix();
+
+        assert_eq!(Color::lerp_rgb(&from, &to, -1.0), from);
+        assert_eq!(Color::lerp_rgb(&from, &to, 2.0), to);
+    }
+
+    #[test]
+    fn color_lerp_rgb_with_background() {
+        let from = Color::new().rgb(0, 0, 0).bg_rgb(100, 100, 100).fix();
+        let to = Color::new().rgb(100, 100, 100).fix();
+
+        // Only `from` has a background, snap to closest.
+        assert_eq!(
+            Color::lerp_rgb(&from, &to, 0.2),
+            Color::new().rgb(20, 20, 20).bg_rgb(100, 100, 100).fix()
+        );
+        assert_eq!(
+            Color::lerp_rgb(&from, &to, 0.8),
+            Color::new().rgb(80, 80, 80).fix()
+        );
+    }
+
+    #[test]
+    fn color_lerp_rgb_not_rgb_snaps_to_closest() {
+        let from = Color::new().red().fix();
+        let to = Color::new().rgb(0, 0, 255).fix();
+
+        assert_eq!(Color::lerp_rgb(&from, &to, 0.4), from);
+        assert_eq!(Color::lerp_rgb(&from, &to, 0.5), to);
+    }
+
+    #[test]
+    fn color_hsv_round_trip() {
+        for rgb in [
+            (255, 0, 0),
+            (12, 200, 99),
+            (31, 44, 59),
+            (0, 0, 0),
+            (255, 255, 255),
+        ] {
+            let (hue, saturation, value) = Color::rgb_to_hsv(rgb);
+
+            assert_eq!(Color::hsv_to_rgb(hue, saturation, value), rgb);
+        }
+    }
+
+    #[test]
+    fn color_from_hsv() {
+        assert_eq!(
+            Color::from_hsv(0.0, 1.0, 1.0),
+            Color::new().rgb(255, 0, 0).fix()
+        );
+        assert_eq!(
+            Color::from_hsv(360.0, 1.0, 1.0),
+            Color::new().rgb(255, 0, 0).fix()
+        );
+        assert_eq!(
+            Color::from_hsv(-120.0, 1.0, 1.0),
+            Color::new().rgb(0, 0, 255).fix()
+        );
+        assert_eq!(
+            Color::from_hsv(0.0, 0.0, 0.5),
+            Color::new().rgb(128, 128, 128).fix()
+        );
+    }
+
+    #[test]
+    fn color_to_hsv() {
+        assert_eq!(Color::new().rgb(255, 0, 0).to_hsv(), Some((0.0, 1.0, 1.0)));
+        assert_eq!(Color::new().rgb(0, 0, 0).to_hsv(), Some((0.0, 0.0, 0.0)));
+        assert_eq!(Color::new().red().to_hsv(), None);
+        assert_eq!(Color::new().bg_rgb(255, 0, 0).to_hsv(), None);
+    }
+
+    #[test]
+    fn color_complementary_of_red_is_cyan() {
+        let (hue, saturation, value) = Color::new()
+            .rgb(255, 0, 0)
+            .complementary()
+            .to_hsv()
+            .unwrap();
+
+        assert!((hue - 180.0).abs() < 1.0);
+        assert!((saturation - 1.0).abs() < 0.01);
+        assert!((value - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn color_complementary_affects_background() {
+        assert_eq!(
+            Color::new()
+                .rgb(255, 0, 0)
+                .bg_rgb(0, 0, 255)
+                .complementary(),
+            Color::new().rgb(0, 255, 255).bg_rgb(255, 255, 0).fix()
+        );
+    }
+
+    #[test]
+    fn color_analogous() {
+        let (left, right) = Color::new().rgb(255, 0, 0).analogous(30.0);
+
+        assert_eq!(left, Color::new().rgb(255, 0, 128).fix());
+        assert_eq!(right, Color::new().rgb(255, 128, 0).fix());
+    }
+
+    #[test]
+    fn color_complementary_analogous_not_rgb_is_unchanged() {
+        let red = Color::new().red().fix();
+
+        assert_eq!(red.complementary(), red);
+        assert_eq!(red.analogous(30.0), (red.clone(), red));
+    }
+
     // 4-bit.
 
     #[test]
@@ -1289,4 +2370,185 @@ mod tests {
             "\x1b[0;38;5;108m\x1b[48;5;42mhello, world\x1b[0m"
         );
     }
+
+    #[test]
+    fn color_rgb_to_4bit_near_white() {
+        assert_eq!(
+            Color::new().rgb(250, 250, 250).to_4bit(true),
+            Color::new().bright_white().fix()
+        );
+        assert_eq!(
+            Color::new().rgb(250, 250, 250).to_4bit(false),
+            Color::new().white().fix()
+        );
+    }
+
+    #[test]
+    fn color_rgb_to_4bit_normal_colors() {
+        assert_eq!(
+            Color::new().rgb(200, 10, 10).to_4bit(true),
+            Color::new().red().fix()
+        );
+        assert_eq!(
+            Color::new().rgb(0, 0, 0).to_4bit(true),
+            Color::new().gray().fix()
+        );
+        assert_eq!(
+            Color::new().rgb(120, 130, 125).to_4bit(true),
+            Color::new().bright_gray().fix()
+        );
+    }
+
+    #[test]
+    fn color_rgb_to_4bit_with_bg_and_attributes() {
+        assert_eq!(
+            Color::new()
+                .bold()
+                .rgb(255, 0, 0)
+                .bg_rgb(0, 0, 240)
+                .to_4bit(true)
+                .format("hello, world"),
+            "\x1b[1;91;44mhello, world\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn color_to_4bit_not_rgb_is_unchanged() {
+        assert_eq!(Color::new().red().to_4bit(true), Color::new().red().fix());
+        assert_eq!(
+            Color::new().x_aqua().to_4bit(false),
+            Color::new().x_aqua().fix()
+        );
+        assert_eq!(Color::new().to_4bit(true), Color::new());
+    }
+
+    #[test]
+    fn color_rgb_to_8bit() {
+        assert_eq!(
+            Color::new().rgb(255, 0, 0).to_8bit(),
+            Color::new().x_red_1().fix()
+        );
+        assert_eq!(
+            Color::new().rgb(0, 0, 0).to_8bit(),
+            Color::new().x_grey_0().fix()
+        );
+        assert_eq!(
+            Color::new().rgb(255, 255, 255).to_8bit(),
+            Color::new().x_grey_100().fix()
+        );
+    }
+
+    #[test]
+    fn color_rgb_to_8bit_prefers_grayscale_ramp() {
+        assert_eq!(
+            Color::new().rgb(128, 128, 128).to_8bit(),
+            Color::new().x_grey_50().fix()
+        );
+    }
+
+    #[test]
+    fn color_rgb_to_8bit_with_bg_and_attributes() {
+        assert_eq!(
+            Color::new()
+                .bold()
+                .rgb(255, 0, 0)
+                .bg_rgb(128, 128, 128)
+                .to_8bit()
+                .format("hello, world"),
+            "\x1b[1;38;5;196m\x1b[48;5;244mhello, world\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn color_to_8bit_not_rgb_is_unchanged() {
+        assert_eq!(Color::new().red().to_8bit(), Color::new().red().fix());
+        assert_eq!(Color::new().x_aqua().to_8bit(), Color::new().x_aqua().fix());
+        assert_eq!(Color::new().to_8bit(), Color::new());
+    }
+
+    // Arithmetic.
+
+    #[test]
+    fn add_saturates_at_255() {
+        assert_eq!(
+            Color::new().rgb(200, 100, 255).fix() + Color::new().rgb(100, 100, 1).fix(),
+            Color::new().rgb(255, 200, 255).fix()
+        );
+    }
+
+    #[test]
+    fn add_foreground_and_background_independently() {
+        let a = Color::new().rgb(10, 20, 30).bg_rgb(250, 0, 0).fix();
+        let b = Color::new().bg_rgb(10, 5, 0).fix();
+
+        assert_eq!(a + b, Color::new().rgb(10, 20, 30).bg_rgb(255, 5, 0).fix());
+    }
+
+    #[test]
+    fn add_keeps_attributes_of_left_hand_side() {
+        let a = Color::new().bold().rgb(1, 2, 3).fix();
+        let b = Color::new().italic().rgb(1, 2, 3).fix();
+
+        assert_eq!(a + b, Color::new().bold().rgb(2, 4, 6).fix());
+    }
+
+    #[test]
+    fn add_not_rgb_is_unchanged() {
+        assert_eq!(
+            Color::new().red().fix() + Color::new().rgb(1, 2, 3).fix(),
+            Color::new().red().fix()
+        );
+        assert_eq!(
+            Color::new().rgb(1, 2, 3).fix() + Color::new().red().fix(),
+            Color::new().rgb(1, 2, 3).fix()
+        );
+    }
+
+    #[test]
+    fn mul_saturates_at_255() {
+        assert_eq!(
+            Color::new().rgb(100, 200, 0).bg_rgb(128, 1, 2).fix() * 3.0,
+            Color::new().rgb(255, 255, 0).bg_rgb(255, 3, 6).fix()
+        );
+    }
+
+    #[test]
+    fn mul_negative_clamps_to_0() {
+        assert_eq!(
+            Color::new().rgb(100, 200, 50).fix() * -1.0,
+            Color::new().rgb(0, 0, 0).fix()
+        );
+    }
+
+    #[test]
+    fn mul_not_rgb_is_unchanged() {
+        assert_eq!(Color::new().red().fix() * 0.5, Color::new().red().fix());
+    }
+
+    // Capabilities.
+
+    #[test]
+    fn supports_truecolor_and_auto_downgrade() {
+        // This is fine, as long as this is the only test that modifies
+        // `COLORTERM`.
+        let rgb = Color::new().rgb(255, 0, 0).fix();
+
+        env::remove_var("COLORTERM");
+        assert!(!Color::supports_truecolor());
+        assert_eq!(rgb.auto_downgrade(), Color::new().x_red_1().fix());
+
+        env::set_var("COLORTERM", "256color");
+        assert!(!Color::supports_truecolor());
+        assert_eq!(rgb.auto_downgrade(), Color::new().x_red_1().fix());
+
+        env::set_var("COLORTERM", "truecolor");
+        assert!(Color::supports_truecolor());
+        assert_eq!(rgb.auto_downgrade(), rgb);
+
+        env::set_var("COLORTERM", "24bit");
+        assert!(Color::supports_truecolor());
+        assert_eq!(rgb.auto_downgrade(), rgb);
+
+        env::remove_var("COLORTERM");
+    }
 }
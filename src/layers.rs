@@ -0,0 +1,266 @@
+use std::fmt;
+
+use crate::{BlendMode, TextCanvas};
+
+struct Layer {
+    name: String,
+    canvas: TextCanvas,
+    mode: BlendMode,
+}
+
+/// Stack of named layers, composited into a single canvas.
+///
+/// Each layer is a [`TextCanvas`] of the same size, that you draw into
+/// like any other canvas. When rendered, layers are blended one onto
+/// the other, from bottom to top (in the order they were added), each
+/// with its own [`BlendMode`] (see
+/// [`blend_canvas()`](TextCanvas::blend_canvas)).
+///
+/// This is useful for complex scenes (e.g., background, data,
+/// annotations), where each part can be redrawn independently, without
+/// having to merge canvases manually.
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::layers::LayerStack;
+/// use textcanvas::BlendMode;
+///
+/// let mut stack = LayerStack::new(15, 5);
+///
+/// let background = stack.add_layer("background", BlendMode::Or);
+/// background.fill();
+///
+/// let data = stack.add_layer("data", BlendMode::Subtract);
+/// data.fill_circle(data.cx(), data.cy(), 9);
+///
+/// let annotations = stack.add_layer("annotations", BlendMode::Or);
+/// annotations.draw_text("hole", 1, 2);
+///
+/// assert_eq!(
+///     stack.to_string(),
+///     "\
+/// ⣿⣿⣿⣿⡿⠛⠉⠉⠉⠙⠻⣿⣿⣿⣿
+/// ⣿⣿⣿⠏⠀⠀⠀⠀⠀⠀⠀⠈⢿⣿⣿
+/// ⣿hole⠀⠀⠀⠀⠀⠀⠀⢸⣿⣿
+/// ⣿⣿⣿⡄⠀⠀⠀⠀⠀⠀⠀⠀⣼⣿⣿
+/// ⣿⣿⣿⣿⣦⣀⠀⠀⠀⢀⣠⣾⣿⣿⣿
+/// "
+/// );
+/// ```
+pub struct LayerStack {
+    width: i32,
+    height: i32,
+    layers: Vec<Layer>,
+}
+
+impl LayerStack {
+    /// Create new, empty `LayerStack`.
+    ///
+    /// `width` and `height` are the size of the layers (see
+    /// [`TextCanvas::new()`]).
+    ///
+    /// # Panics
+    ///
+    /// Under the same conditions as [`TextCanvas::new()`].
+    #[must_use]
+    pub fn new(width: i32, height: i32) -> Self {
+        // Fail early, rather than on the first layer.
+        let _ = TextCanvas::new(width, height);
+        Self {
+            width,
+            height,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Add a new, blank layer on top of the stack.
+    ///
+    /// The layer is blended onto the layers below it with `mode`.
+    ///
+    /// # Panics
+    ///
+    /// If a layer with the same name already exists.
+    pub fn add_layer(&mut self, name: &str, mode: BlendMode) -> &mut TextCanvas {
+        assert!(
+            self.position(name).is_none(),
+            "Layer '{name}' already exists."
+        );
+
+        self.layers.push(Layer {
+            name: name.to_owned(),
+            canvas: TextCanvas::new(self.width, self.height),
+            mode,
+        });
+        &mut self.layers.last_mut().expect("layer was just added").canvas
+    }
+
+    /// Remove a layer from the stack.
+    ///
+    /// Returns the canvas of the layer, or `None` if there is no layer
+    /// with this name.
+    pub fn remove_layer(&mut self, name: &str) -> Option<TextCanvas> {
+        let position = self.position(name)?;
+        Some(self.layers.remove(position).canvas)
+    }
+
+    /// Get a layer by name.
+    #[must_use]
+    pub fn layer(&self, name: &str) -> Option<&TextCanvas> {
+        let position = self.position(name)?;
+        Some(&self.layers[position].canvas)
+    }
+
+    /// Get a layer by name, to draw into it.
+    pub fn layer_mut(&mut self, name: &str) -> Option<&mut TextCanvas> {
+        let position = self.position(name)?;
+        Some(&mut self.layers[position].canvas)
+    }
+
+    /// Change the blend mode of a layer.
+    ///
+    /// Does nothing if there is no layer with this name.
+    pub fn set_blend_mode(&mut self, name: &str, mode: BlendMode) {
+        if let Some(position) = self.position(name) {
+            self.layers[position].mode = mode;
+        }
+    }
+
+    /// Names of the layers, from bottom to top.
+    #[must_use]
+    pub fn layer_names(&self) -> Vec<&str> {
+        self.layers
+            .iter()
+            .map(|layer| layer.name.as_str())
+            .collect()
+    }
+
+    /// Composite all layers into a single canvas.
+    ///
+    /// Layers are blended from bottom to top, starting from a blank
+    /// canvas.
+    #[must_use]
+    pub fn composite(&self) -> TextCanvas {
+        let mut canvas = TextCanvas::new(self.width, self.height);
+        for layer in &self.layers {
+            canvas.blend_canvas(&layer.canvas, 0, 0, layer.mode);
+        }
+        canvas
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.layers.iter().position(|layer| layer.name == name)
+    }
+}
+
+impl fmt::Display for LayerStack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.composite())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    #[test]
+    fn empty_stack_is_blank() {
+        let stack = LayerStack::new(3, 1);
+
+        assert_eq!(stack.to_string(), "⠀⠀⠀\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_zero_panics() {
+        let _ = LayerStack::new(0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Layer 'foo' already exists.")]
+    fn duplicate_layer_name_panics() {
+        let mut stack = LayerStack::new(3, 1);
+
+        stack.add_layer("foo", BlendMode::Or);
+        stack.add_layer("foo", BlendMode::Or);
+    }
+
+    #[test]
+    fn three_layers_z_order() {
+        let mut stack = LayerStack::new(3, 1);
+
+        let bottom = stack.add_layer("bottom", BlendMode::Or);
+        bottom.set_color(Color::new().red());
+        bottom.fill_rect(0, 0, 6, 4);
+
+        let middle = stack.add_layer("middle", BlendMode::Or);
+        middle.set_color(Color::new().green());
+        middle.fill_rect(2, 0, 4, 4);
+
+        let top = stack.add_layer("top", BlendMode::Or);
+        top.set_color(Color::new().blue());
+        top.fill_rect(4, 0, 2, 4);
+
+        assert_eq!(stack.layer_names(), ["bottom", "middle", "top"]);
+        assert_eq!(
+            stack.to_string(),
+            "\x1b[0;31m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;34m⣿\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn text_of_upper_layer_is_on_top() {
+        let mut stack = LayerStack::new(3, 1);
+
+        stack
+            .add_layer("bottom", BlendMode::Or)
+            .draw_text("abc", 0, 0);
+        stack.add_layer("middle", BlendMode::Or).fill();
+        stack.add_layer("top", BlendMode::Or).draw_text("x", 1, 0);
+
+        assert_eq!(stack.to_string(), "axc\n");
+    }
+
+    #[test]
+    fn blend_modes_apply_to_layers_below() {
+        let mut stack = LayerStack::new(2, 1);
+
+        stack.add_layer("bottom", BlendMode::Or).fill();
+        stack
+            .add_layer("eraser", BlendMode::Subtract)
+            .fill_rect(0, 0, 2, 4);
+
+        assert_eq!(stack.to_string(), "⠀⣿\n");
+
+        stack.set_blend_mode("eraser", BlendMode::Or);
+
+        assert_eq!(stack.to_string(), "⣿⣿\n");
+    }
+
+    #[test]
+    fn layer_mut_draws_into_layer() {
+        let mut stack = LayerStack::new(2, 1);
+        stack.add_layer("foo", BlendMode::Or);
+
+        stack.layer_mut("foo").unwrap().fill();
+
+        assert_eq!(stack.layer("foo").unwrap().to_string(), "⣿⣿\n");
+        assert!(stack.layer("bar").is_none());
+        assert!(stack.layer_mut("bar").is_none());
+    }
+
+    #[test]
+    fn remove_layer() {
+        let mut stack = LayerStack::new(2, 1);
+        stack.add_layer("foo", BlendMode::Or).fill();
+        stack.add_layer("bar", BlendMode::Or);
+
+        let foo = stack.remove_layer("foo");
+
+        assert_eq!(foo.unwrap().to_string(), "⣿⣿\n");
+        assert_eq!(stack.layer_names(), ["bar"]);
+        assert_eq!(stack.to_string(), "⠀⠀\n");
+        assert!(stack.remove_layer("foo").is_none());
+    }
+}
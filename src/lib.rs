@@ -97,9 +97,13 @@
 //!
 //! [^1]: <https://github.com/asciimoo/drawille>
 
+pub mod anim;
 pub mod charts;
 pub mod color;
+pub mod layers;
 pub mod maths;
+pub mod palette;
+pub mod resampling;
 pub mod textcanvas;
 pub mod utils;
 
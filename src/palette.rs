@@ -0,0 +1,93 @@
+use crate::Color;
+
+/// Ready-made sets of colors.
+///
+/// Useful to give each series of a chart its own color, without having
+/// to hand-pick them.
+pub struct Palette;
+
+impl Palette {
+    const CATEGORICAL_SATURATION: f64 = 0.65;
+    const CATEGORICAL_VALUE: f64 = 0.95;
+
+    /// Generate `n` visually distinct colors.
+    ///
+    /// Colors are evenly spaced around the hue wheel, starting from
+    /// red, with the same saturation and brightness. The result is
+    /// deterministic: the same `n` always gives the same colors, so
+    /// series keep their colors from one render to the next.
+    ///
+    /// Colors are RGB (see [`Color::auto_downgrade()`] for terminals
+    /// that don't support it).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::palette::Palette;
+    /// use textcanvas::Color;
+    ///
+    /// let colors = Palette::default_categorical(3);
+    ///
+    /// assert_eq!(
+    ///     colors,
+    ///     vec![
+    ///         Color::new().rgb(242, 85, 85).fix(),
+    ///         Color::new().rgb(85, 242, 85).fix(),
+    ///         Color::new().rgb(85, 85, 242).fix(),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn default_categorical(n: usize) -> Vec<Color> {
+        #[allow(clippy::cast_precision_loss)]
+        let slice = 360.0 / n as f64;
+
+        (0..n)
+            .map(|i| {
+                #[allow(clippy::cast_precision_loss)]
+                let hue = i as f64 * slice;
+                Color::from_hsv(hue, Self::CATEGORICAL_SATURATION, Self::CATEGORICAL_VALUE)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_categorical_size() {
+        assert!(Palette::default_categorical(0).is_empty());
+        assert_eq!(Palette::default_categorical(1).len(), 1);
+        assert_eq!(Palette::default_categorical(7).len(), 7);
+    }
+
+    #[test]
+    fn default_categorical_colors_are_distinct() {
+        for n in 1..=24 {
+            let colors = Palette::default_categorical(n);
+
+            for (i, a) in colors.iter().enumerate() {
+                for b in &colors[i + 1..] {
+                    assert_ne!(a, b, "Colors should be distinct (n = {n}).");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn default_categorical_is_deterministic() {
+        assert_eq!(
+            Palette::default_categorical(5),
+            Palette::default_categorical(5)
+        );
+    }
+
+    #[test]
+    fn default_categorical_first_color_is_red() {
+        let colors = Palette::default_categorical(4);
+
+        assert_eq!(colors[0].to_hex(), Some(String::from("#f25555")));
+    }
+}
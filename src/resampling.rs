@@ -0,0 +1,465 @@
+use crate::charts::finite_min_max;
+use crate::TextCanvas;
+
+/// Algorithm used to reduce the number of points.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DownsamplingMethod {
+    /// Average the points of each bucket.
+    ///
+    /// Smooth, but peaks are flattened.
+    Mean,
+    /// Keep the lowest and highest points of each bucket.
+    ///
+    /// Preserves the envelope of the data (peaks are kept), at the
+    /// cost of jaggedness.
+    MinMax,
+    /// Largest-Triangle-Three-Buckets.
+    ///
+    /// Keep the point of each bucket that forms the largest triangle
+    /// with its neighbours. This preserves the visual shape of the data
+    /// best. First and last points are always kept.
+    Lttb,
+}
+
+/// Helper functions to prepare data for plotting.
+///
+/// Plotting a million points onto a canvas a few hundred pixels wide is
+/// slow, and most points end up on the same pixels anyway. Downsampling
+/// reduces the data to roughly the resolution of the canvas first.
+///
+/// Normalization rescales series of different scales to a common range,
+/// so they can be overlaid.
+///
+/// Data is expected to be sorted by `x`. If `x` and `y` are not the
+/// same length, the extra values are ignored.
+pub struct Resampling;
+
+impl Resampling {
+    /// Downsample data to at most `max_nb_points` points.
+    ///
+    /// If there are already at most `max_nb_points` points, the data is
+    /// returned as-is.
+    ///
+    /// [`MinMax`](DownsamplingMethod::MinMax) needs at least 2 points,
+    /// and [`Lttb`](DownsamplingMethod::Lttb) needs at least 3. Below
+    /// that, [`Mean`](DownsamplingMethod::Mean) is used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::resampling::{DownsamplingMethod, Resampling};
+    ///
+    /// let x: Vec<f64> = (0..6).map(f64::from).collect();
+    /// let y = vec![1.0, 3.0, 5.0, 7.0, 9.0, 11.0];
+    ///
+    /// let (x, y) = Resampling::downsample(&x, &y, 3, DownsamplingMethod::Mean);
+    ///
+    /// assert_eq!(x, [0.5, 2.5, 4.5]);
+    /// assert_eq!(y, [2.0, 6.0, 10.0]);
+    /// ```
+    #[must_use]
+    pub fn downsample(
+        x: &[f64],
+        y: &[f64],
+        max_nb_points: usize,
+        method: DownsamplingMethod,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let nb_points = x.len().min(y.len());
+        let (x, y) = (&x[..nb_points], &y[..nb_points]);
+
+        if nb_points <= max_nb_points {
+            return (x.to_vec(), y.to_vec());
+        }
+
+        match method {
+            DownsamplingMethod::MinMax if max_nb_points >= 2 => Self::min_max(x, y, max_nb_points),
+            DownsamplingMethod::Lttb if max_nb_points >= 3 => Self::lttb(x, y, max_nb_points),
+            _ => Self::mean(x, y, max_nb_points),
+        }
+    }
+
+    /// Downsample data to the resolution of a canvas.
+    ///
+    /// This is the same as [`downsample()`](Resampling::downsample),
+    /// with the number of points set to twice the width of the screen.
+    /// Since lines join consecutive points, this is enough to draw any
+    /// detail the canvas can show.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::resampling::{DownsamplingMethod, Resampling};
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let canvas = TextCanvas::new(15, 5);
+    ///
+    /// let x: Vec<f64> = (0..10_000).map(f64::from).collect();
+    /// let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+    ///
+    /// let (x, y) = Resampling::downsample_for_canvas(&x, &y, &canvas, DownsamplingMethod::Lttb);
+    ///
+    /// assert_eq!(x.len(), 60);
+    /// assert_eq!(y.len(), 60);
+    /// ```
+    #[must_use]
+    pub fn downsample_for_canvas(
+        x: &[f64],
+        y: &[f64],
+        canvas: &TextCanvas,
+        method: DownsamplingMethod,
+    ) -> (Vec<f64>, Vec<f64>) {
+        Self::downsample(x, y, canvas.screen.uwidth() * 2, method)
+    }
+
+    /// Rescale values to `[0, 1]`, by min/max.
+    ///
+    /// The lowest value maps to _0_, and the highest to _1_. This is
+    /// the same as [`normalize_range()`](Resampling::normalize_range)
+    /// with a range of `0.0..=1.0`.
+    ///
+    /// If all values are the same (i.e., zero range), they all map to
+    /// _0.5_. Non-finite values (`NaN`, `±Inf`) are ignored when
+    /// computing the min and max, and stay non-finite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::resampling::Resampling;
+    ///
+    /// let y = [10.0, 15.0, 20.0, 30.0];
+    ///
+    /// assert_eq!(Resampling::normalize(&y), [0.0, 0.25, 0.5, 1.0]);
+    /// assert_eq!(Resampling::normalize(&[3.0, 3.0]), [0.5, 0.5]);
+    /// ```
+    #[must_use]
+    pub fn normalize(y: &[f64]) -> Vec<f64> {
+        Self::normalize_range(y, 0.0, 1.0)
+    }
+
+    /// Rescale values to `[lo, hi]`, by min/max.
+    ///
+    /// The lowest value maps to `lo`, and the highest to `hi`. If all
+    /// values are the same (i.e., zero range), they all map to the
+    /// middle of `lo` and `hi`.
+    ///
+    /// Non-finite values (`NaN`, `±Inf`) are ignored when computing the
+    /// min and max, and stay non-finite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::resampling::Resampling;
+    ///
+    /// let y = [10.0, 15.0, 20.0, 30.0];
+    ///
+    /// assert_eq!(Resampling::normalize_range(&y, -1.0, 1.0), [-1.0, -0.5, 0.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn normalize_range(y: &[f64], lo: f64, hi: f64) -> Vec<f64> {
+        let Some((min, max)) = finite_min_max(y) else {
+            return y.to_vec();
+        };
+        let range = max - min;
+
+        y.iter()
+            .map(|&value| {
+                if !value.is_finite() {
+                    value
+                } else if range == 0.0 {
+                    f64::midpoint(lo, hi)
+                } else {
+                    lo + (value - min) / range * (hi - lo)
+                }
+            })
+            .collect()
+    }
+
+    /// Bounds of the `i`-th bucket, out of `nb_buckets` over `len`.
+    fn bucket(i: usize, nb_buckets: usize, len: usize) -> std::ops::Range<usize> {
+        (i * len / nb_buckets)..((i + 1) * len / nb_buckets)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn mean(x: &[f64], y: &[f64], nb_buckets: usize) -> (Vec<f64>, Vec<f64>) {
+        let mut res_x = Vec::with_capacity(nb_buckets);
+        let mut res_y = Vec::with_capacity(nb_buckets);
+
+        for i in 0..nb_buckets {
+            let bucket = Self::bucket(i, nb_buckets, x.len());
+            let len = bucket.len() as f64;
+            res_x.push(x[bucket.clone()].iter().sum::<f64>() / len);
+            res_y.push(y[bucket].iter().sum::<f64>() / len);
+        }
+
+        (res_x, res_y)
+    }
+
+    fn min_max(x: &[f64], y: &[f64], max_nb_points: usize) -> (Vec<f64>, Vec<f64>) {
+        let nb_buckets = max_nb_points / 2;
+        let mut res_x = Vec::with_capacity(nb_buckets * 2);
+        let mut res_y = Vec::with_capacity(nb_buckets * 2);
+
+        for i in 0..nb_buckets {
+            let bucket = Self::bucket(i, nb_buckets, x.len());
+            let (mut min, mut max) = (bucket.start, bucket.start);
+            for j in bucket {
+                if y[j] < y[min] {
+                    min = j;
+                }
+                if y[j] > y[max] {
+                    max = j;
+                }
+            }
+
+            // Keep the order of the data.
+            let (first, second) = if min <= max { (min, max) } else { (max, min) };
+            res_x.push(x[first]);
+            res_y.push(y[first]);
+            if second != first {
+                res_x.push(x[second]);
+                res_y.push(y[second]);
+            }
+        }
+
+        (res_x, res_y)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn lttb(x: &[f64], y: &[f64], max_nb_points: usize) -> (Vec<f64>, Vec<f64>) {
+        let last = x.len() - 1;
+        // First and last points are kept, the rest is bucketed.
+        let nb_buckets = max_nb_points - 2;
+
+        let mut res_x = Vec::with_capacity(max_nb_points);
+        let mut res_y = Vec::with_capacity(max_nb_points);
+
+        res_x.push(x[0]);
+        res_y.push(y[0]);
+
+        let mut a = 0;
+        for i in 0..nb_buckets {
+            let bucket = Self::bucket(i, nb_buckets, last - 1);
+            let bucket = bucket.start + 1..bucket.end + 1;
+
+            // Third point of the triangle is the average of the next
+            // bucket (or the last point, for the last bucket).
+            let next = Self::bucket(i + 1, nb_buckets, last - 1);
+            let next = next.start + 1..(next.end + 1).min(last);
+            let (avg_x, avg_y) = if next.is_empty() {
+                (x[last], y[last])
+            } else {
+                let len = next.len() as f64;
+                (
+                    x[next.clone()].iter().sum::<f64>() / len,
+                    y[next].iter().sum::<f64>() / len,
+                )
+            };
+
+            let mut max_area = -1.0;
+            let mut selected = bucket.start;
+            for j in bucket {
+                let area = ((x[a] - avg_x) * (y[j] - y[a]) - (x[a] - x[j]) * (avg_y - y[a])).abs();
+                if area > max_area {
+                    max_area = area;
+                    selected = j;
+                }
+            }
+
+            res_x.push(x[selected]);
+            res_y.push(y[selected]);
+            a = selected;
+        }
+
+        res_x.push(x[last]);
+        res_y.push(y[last]);
+
+        (res_x, res_y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp(nb_points: i32) -> (Vec<f64>, Vec<f64>) {
+        let x: Vec<f64> = (0..nb_points).map(f64::from).collect();
+        let y = x.clone();
+        (x, y)
+    }
+
+    #[test]
+    fn normalize_ramp() {
+        let (_, y) = ramp(5);
+
+        assert_eq!(Resampling::normalize(&y), [0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(
+            Resampling::normalize_range(&y, 10.0, 20.0),
+            [10.0, 12.5, 15.0, 17.5, 20.0]
+        );
+    }
+
+    #[test]
+    fn normalize_constant() {
+        assert_eq!(Resampling::normalize(&[7.0, 7.0, 7.0]), [0.5, 0.5, 0.5]);
+        assert_eq!(Resampling::normalize_range(&[7.0], -2.0, 4.0), [1.0]);
+    }
+
+    #[test]
+    fn normalize_keeps_non_finite_values() {
+        let y = Resampling::normalize(&[f64::NAN, 2.0, f64::INFINITY, 4.0]);
+
+        assert!(y[0].is_nan());
+        assert_eq!(y[1..], [0.0, f64::INFINITY, 1.0]);
+    }
+
+    #[test]
+    fn normalize_empty() {
+        assert!(Resampling::normalize(&[]).is_empty());
+        assert!(Resampling::normalize(&[f64::NAN])[0].is_nan());
+    }
+
+    #[test]
+    fn downsample_small_data_is_unchanged() {
+        let (x, y) = ramp(5);
+
+        for method in [
+            DownsamplingMethod::Mean,
+            DownsamplingMethod::MinMax,
+            DownsamplingMethod::Lttb,
+        ] {
+            assert_eq!(
+                Resampling::downsample(&x, &y, 5, method),
+                (x.clone(), y.clone())
+            );
+            assert_eq!(
+                Resampling::downsample(&x, &y, 10, method),
+                (x.clone(), y.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn downsample_different_lengths() {
+        let (x, _) = ramp(5);
+
+        let (x, y) = Resampling::downsample(&x, &[1.0, 2.0], 10, DownsamplingMethod::Mean);
+
+        assert_eq!(x, [0.0, 1.0]);
+        assert_eq!(y, [1.0, 2.0]);
+    }
+
+    #[test]
+    fn downsample_empty() {
+        let (x, y) = Resampling::downsample(&[], &[], 0, DownsamplingMethod::Lttb);
+
+        assert!(x.is_empty());
+        assert!(y.is_empty());
+    }
+
+    #[test]
+    fn downsample_mean() {
+        let (x, y) = ramp(9);
+
+        let (x, y) = Resampling::downsample(&x, &y, 3, DownsamplingMethod::Mean);
+
+        assert_eq!(x, [1.0, 4.0, 7.0]);
+        assert_eq!(y, [1.0, 4.0, 7.0]);
+    }
+
+    #[test]
+    fn downsample_mean_uneven_buckets() {
+        let (x, y) = ramp(5);
+
+        let (x, y) = Resampling::downsample(&x, &y, 2, DownsamplingMethod::Mean);
+
+        // Buckets: [0, 1], [2, 3, 4].
+        assert_eq!(x, [0.5, 3.0]);
+        assert_eq!(y, [0.5, 3.0]);
+    }
+
+    #[test]
+    fn downsample_min_max_keeps_peaks() {
+        let x: Vec<f64> = (0..8).map(f64::from).collect();
+        let y = [0.0, 9.0, 1.0, 2.0, 2.0, 1.0, -9.0, 0.0];
+
+        let (x, y) = Resampling::downsample(&x, &y, 4, DownsamplingMethod::MinMax);
+
+        assert_eq!(x, [0.0, 1.0, 4.0, 6.0]);
+        assert_eq!(y, [0.0, 9.0, 2.0, -9.0]);
+    }
+
+    #[test]
+    fn downsample_min_max_keeps_order() {
+        let x: Vec<f64> = (0..4).map(f64::from).collect();
+        let y = [5.0, 1.0, 0.0, 3.0];
+
+        let (x, y) = Resampling::downsample(&x, &y, 2, DownsamplingMethod::MinMax);
+
+        // Max (0) comes before min (2).
+        assert_eq!(x, [0.0, 2.0]);
+        assert_eq!(y, [5.0, 0.0]);
+    }
+
+    #[test]
+    fn downsample_lttb_keeps_first_and_last() {
+        let (x, y) = ramp(100);
+
+        let (x, y) = Resampling::downsample(&x, &y, 10, DownsamplingMethod::Lttb);
+
+        assert_eq!(x.len(), 10);
+        assert_eq!((x[0], y[0]), (0.0, 0.0));
+        assert_eq!((x[9], y[9]), (99.0, 99.0));
+    }
+
+    #[test]
+    fn downsample_lttb_keeps_spike() {
+        let x: Vec<f64> = (0..10).map(f64::from).collect();
+        let mut y = vec![0.0; 10];
+        y[4] = 10.0;
+
+        let (x, y) = Resampling::downsample(&x, &y, 4, DownsamplingMethod::Lttb);
+
+        assert_eq!(x.len(), 4);
+        assert!(x.contains(&4.0));
+        assert!(y.contains(&10.0));
+    }
+
+    #[test]
+    fn downsample_falls_back_to_mean() {
+        let (x, y) = ramp(9);
+
+        let mean = Resampling::downsample(&x, &y, 1, DownsamplingMethod::Mean);
+
+        assert_eq!(mean, (vec![4.0], vec![4.0]));
+        assert_eq!(
+            Resampling::downsample(&x, &y, 1, DownsamplingMethod::MinMax),
+            mean
+        );
+        assert_eq!(
+            Resampling::downsample(&x, &y, 1, DownsamplingMethod::Lttb),
+            mean
+        );
+    }
+
+    #[test]
+    fn downsample_for_canvas_tracks_canvas_width() {
+        let (x, y) = ramp(10_000);
+
+        for width in [1, 7, 15, 80] {
+            let canvas = TextCanvas::new(width, 5);
+
+            for method in [
+                DownsamplingMethod::Mean,
+                DownsamplingMethod::MinMax,
+                DownsamplingMethod::Lttb,
+            ] {
+                let (x, y) = Resampling::downsample_for_canvas(&x, &y, &canvas, method);
+
+                let expected = canvas.screen.uwidth() * 2;
+                assert_eq!(x.len(), y.len());
+                assert!(x.len() <= expected);
+                assert!(x.len() >= expected / 2, "{method:?}: {}", x.len());
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
+use std::borrow::Cow;
 use std::cmp;
 use std::env;
 use std::error::Error;
-use std::fmt;
+use std::fmt::{self, Write as _};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::OnceLock;
 
 use crate::Color;
 
@@ -61,10 +64,121 @@ impl fmt::Display for TextCanvasError {
 
 impl Error for TextCanvasError {}
 
+/// Direction of a color gradient.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GradientDir {
+    /// From left to right.
+    Horizontal,
+    /// From top to bottom.
+    Vertical,
+}
+
+/// Horizontal alignment of text, relative to an anchor column.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Align {
+    /// Text starts at the anchor.
+    Left,
+    /// Text is centered on the anchor.
+    Center,
+    /// Text ends at the anchor.
+    Right,
+}
+
+/// Style of a border made of box-drawing characters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BorderStyle {
+    /// `┌─┐│└┘`
+    Single,
+    /// `╔═╗║╚╝`
+    Double,
+    /// `╭─╮│╰╯`
+    Rounded,
+    /// `+-+|++`
+    Ascii,
+}
+
+impl BorderStyle {
+    /// Top-left, top-right, bottom-left, bottom-right, horizontal,
+    /// vertical.
+    fn chars(self) -> (char, char, char, char, char, char) {
+        match self {
+            Self::Single => ('┌', '┐', '└', '┘', '─', '│'),
+            Self::Double => ('╔', '╗', '╚', '╝', '═', '║'),
+            Self::Rounded => ('╭', '╮', '╰', '╯', '─', '│'),
+            Self::Ascii => ('+', '+', '+', '+', '-', '|'),
+        }
+    }
+}
+
+/// How an n-gon fits its radius.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NgonFit {
+    /// The polygon is inscribed in the circle (vertices on the
+    /// circle).
+    Inscribe,
+    /// The polygon circumscribes the circle (sides tangent to the
+    /// circle).
+    Circumscribe,
+}
+
+/// How segments of a thick polyline meet at corners.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LineJoin {
+    /// Outer edges are extended until they meet, for a sharp corner.
+    /// Very sharp corners fall back to [`Bevel`](LineJoin::Bevel).
+    Miter,
+    /// A disk is filled at each corner.
+    Round,
+    /// Outer edges are connected by a straight line.
+    Bevel,
+}
+
+/// How pixels of two canvases are combined.
+///
+/// `a` is the pixel of the canvas drawn onto, `b` the pixel of the
+/// canvas being drawn.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlendMode {
+    /// `a || b`
+    Or,
+    /// `a && b`
+    And,
+    /// `a ^ b`
+    Xor,
+    /// `a && !b`
+    Subtract,
+}
+
+impl BlendMode {
+    fn blend(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::Or => a || b,
+            Self::And => a && b,
+            Self::Xor => a ^ b,
+            Self::Subtract => a && !b,
+        }
+    }
+}
+
+/// Rectangular region of the screen, in screen pixels.
+#[derive(Debug, Clone, Copy)]
+struct Region {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+}
+
+impl Region {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+}
+
 /// Grid-like area with a width and a height.
 ///
 /// This is an abstract way to define the renderable buffers.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct Surface {
     width: i32,
     height: i32,
@@ -102,6 +216,12 @@ impl Surface {
     }
 }
 
+impl fmt::Display for Surface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}×{}", self.width, self.height)
+    }
+}
+
 #[derive(Debug)]
 pub struct IterPixelBuffer<T> {
     width: T,
@@ -202,6 +322,65 @@ impl Iterator for IterPixelBufferByBlocksLRTB<'_> {
     }
 }
 
+/// Number of output cells a string occupies when drawn as text.
+///
+/// Each `char` takes up one cell (this is how
+/// [`draw_text()`](TextCanvas::draw_text) draws them). For multiline
+/// text, this is the width of the longest line.
+///
+/// Use this rather than `str::len()`, which counts bytes, and is wrong
+/// for anything but ASCII.
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::text_width;
+///
+/// assert_eq!(text_width("foo"), 3);
+/// assert_eq!(text_width("µs"), 2);
+/// assert_eq!(text_width("foo\nbarbaz"), 6);
+/// ```
+#[must_use]
+pub fn text_width(text: &str) -> usize {
+    text.lines()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Rounding policy, for mapping data to screen pixels.
+///
+/// Data rarely falls exactly on a pixel. By default, screen coordinates
+/// are truncated ([`Trunc`](Rounding::Trunc)), which biases them toward
+/// the origin. This can shift a plot by up to a pixel relative to an
+/// axis drawn at the same value.
+///
+/// See [`Plot::set_rounding()`](crate::charts::Plot::set_rounding).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Rounding {
+    /// Round toward zero (default).
+    ///
+    /// Note: For Y, data is truncated _after_ the Y-axis is inverted,
+    /// but lines at a given Y value (like axes) are truncated _before_.
+    /// That is why they can be off by a pixel.
+    #[default]
+    Trunc,
+    /// Round to the nearest pixel (half away from zero).
+    Round,
+    /// Round toward negative infinity.
+    Floor,
+}
+
+impl Rounding {
+    pub(crate) fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Trunc => value.trunc(),
+            Self::Round => value.round(),
+            Self::Floor => value.floor(),
+        }
+    }
+}
+
 /// Draw to the terminal like an HTML Canvas.
 ///
 /// # Examples
@@ -273,8 +452,26 @@ pub struct TextCanvas {
     /// Inverted drawing mode. In inverted mode, functions which usually
     /// turn pixels _on_, will turn them _off_, and vice-versa.
     pub is_inverted: bool,
+    /// Last render, re-used by `to_string()` until the canvas is
+    /// mutated. Only used if enabled with
+    /// [`set_render_cache()`](TextCanvas::set_render_cache).
+    render_cache: OnceLock<String>,
+    is_render_cache_enabled: bool,
 
     color: Color,
+    default_pixel_color: Color,
+    background: Color,
+    /// Colors of entire rows and columns of output cells, which take
+    /// precedence over the context color when coloring cells. Empty
+    /// until the first call to `set_row_color()` or `set_col_color()`.
+    row_colors: Vec<Color>,
+    col_colors: Vec<Color>,
+    is_color_disabled: bool,
+    /// Drawing is restricted to this region while a [`CanvasView`]
+    /// draws.
+    clip: Option<Region>,
+    /// Rounding policy of [`Plot`](crate::charts::Plot) functions.
+    pub(crate) plot_rounding: Rounding,
 }
 
 impl TextCanvas {
@@ -308,7 +505,16 @@ impl TextCanvas {
             color_buffer: Vec::new(),
             text_buffer: Vec::new(),
             is_inverted: false,
+            render_cache: OnceLock::new(),
+            is_render_cache_enabled: false,
             color: Color::new(),
+            default_pixel_color: Color::new(),
+            background: Color::new(),
+            row_colors: Vec::new(),
+            col_colors: Vec::new(),
+            is_color_disabled: false,
+            clip: None,
+            plot_rounding: Rounding::Trunc,
         };
 
         canvas.init_buffer();
@@ -322,11 +528,19 @@ impl TextCanvas {
     }
 
     fn check_output_bounds(&self, x: i32, y: i32) -> bool {
-        x >= 0 && x < self.output.width() && y >= 0 && y < self.output.height()
+        x >= 0
+            && x < self.output.width()
+            && y >= 0
+            && y < self.output.height()
+            && self.clip.is_none_or(|clip| clip.contains(x * 2, y * 4))
     }
 
     fn check_screen_bounds(&self, x: i32, y: i32) -> bool {
-        x >= 0 && x < self.screen.width() && y >= 0 && y < self.screen.height()
+        x >= 0
+            && x < self.screen.width()
+            && y >= 0
+            && y < self.screen.height()
+            && self.clip.is_none_or(|clip| clip.contains(x, y))
     }
 
     fn init_buffer(&mut self) {
@@ -345,6 +559,72 @@ impl TextCanvas {
         Ok(Self::new(width, height))
     }
 
+    /// Create new `TextCanvas` of given width, and height matching an
+    /// aspect ratio.
+    ///
+    /// `aspect` is the ratio of width over height, as it should _look_
+    /// on screen (e.g., `1.0` for a square, `16.0 / 9.0` for a wide
+    /// canvas).
+    ///
+    /// Terminal characters are about twice as high as they are wide.
+    /// Braille dots compensate for this: with 2 dots in width and 4 in
+    /// height, each dot is roughly square on screen. So the aspect
+    /// ratio is applied to the screen (in dots), not to the output (in
+    /// characters). This is why a square canvas has about half as many
+    /// rows as it has columns.
+    ///
+    /// Height is rounded to the nearest character, so the resulting
+    /// aspect ratio is only approximate. Height is at least 1.
+    ///
+    /// # Panics
+    ///
+    /// If `aspect` is not a finite, positive number, and under the
+    /// same conditions as [`TextCanvas::new()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let canvas = TextCanvas::for_aspect(40, 1.0);
+    ///
+    /// assert_eq!(canvas.output.height(), 20);
+    /// assert_eq!(canvas.screen.width(), 80);
+    /// assert_eq!(canvas.screen.height(), 80);
+    /// ```
+    #[must_use]
+    pub fn for_aspect(width: i32, aspect: f64) -> Self {
+        assert!(
+            aspect.is_finite() && aspect > 0.0,
+            "Aspect ratio must be positive, but {aspect} was given."
+        );
+
+        // screen width / screen height = (width * 2) / (height * 4)
+        let height = f64::from(width) / (2.0 * aspect);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let height = height.round().clamp(1.0, f64::from(i32::MAX)) as i32;
+
+        Self::new(width, height)
+    }
+
+    /// Create new [`TextCanvasBuilder`], to configure a canvas.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let canvas = TextCanvas::builder().size(3, 1).inverted(true).build();
+    ///
+    /// assert!(canvas.is_inverted);
+    /// assert_eq!(canvas.to_string(), "⠀⠀⠀\n");
+    /// ```
+    #[must_use]
+    pub fn builder() -> TextCanvasBuilder {
+        TextCanvasBuilder::new()
+    }
+
     /// Default canvas size.
     ///
     /// This value is used by [`TextCanvas::default()`], but it may be
@@ -379,6 +659,53 @@ impl TextCanvas {
         Ok((width, height))
     }
 
+    /// Create new `TextCanvas` the size of the terminal.
+    ///
+    /// The size is queried from the terminal (see
+    /// [`get_terminal_size()`](TextCanvas::get_terminal_size)). If it
+    /// cannot be read (e.g., output is not a TTY), the default size is
+    /// used instead (see
+    /// [`get_default_size()`](TextCanvas::get_default_size)).
+    ///
+    /// Note: Requires the `terminal` feature.
+    #[cfg(feature = "terminal")]
+    #[must_use]
+    pub fn new_fullscreen() -> Self {
+        let (width, height) =
+            Self::get_terminal_size().unwrap_or_else(|_| Self::get_default_size());
+        Self::new(width, height)
+    }
+
+    /// Read canvas size from the terminal.
+    ///
+    /// Contrary to [`get_auto_size()`](TextCanvas::get_auto_size),
+    /// which relies on environment variables, this asks the terminal
+    /// for its actual size (`TIOCGWINSZ`). Stdout, stderr and stdin
+    /// are tried in turn, so the size can be read even if one of them
+    /// is redirected.
+    ///
+    /// Note: Requires the `terminal` feature.
+    ///
+    /// # Errors
+    ///
+    /// If none of stdout, stderr and stdin is a terminal, or if the
+    /// platform is not supported (only Unix is).
+    #[cfg(feature = "terminal")]
+    pub fn get_terminal_size() -> Result<(i32, i32), TextCanvasError> {
+        #[cfg(unix)]
+        for fd in [libc::STDOUT_FILENO, libc::STDERR_FILENO, libc::STDIN_FILENO] {
+            // SAFETY: `winsize` is plain old data, for which all-zero
+            // is a valid value, and `TIOCGWINSZ` only writes to it.
+            let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+            let res = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) };
+            if res == 0 && size.ws_col > 0 && size.ws_row > 0 {
+                return Ok((i32::from(size.ws_col), i32::from(size.ws_row)));
+            }
+        }
+
+        Err(TextCanvasError("cannot read terminal size"))
+    }
+
     /// High-level string representation of the canvas.
     ///
     /// # Examples
@@ -395,11 +722,9 @@ impl TextCanvas {
     /// ```
     #[must_use]
     pub fn repr(&self) -> String {
-        let out_w = self.output.width();
-        let out_h = self.output.height();
-        let screen_w = self.screen.width();
-        let screen_h = self.screen.height();
-        format!("Canvas(output=({out_w}×{out_h}), screen=({screen_w}×{screen_h})))")
+        let output = &self.output;
+        let screen = &self.screen;
+        format!("Canvas(output=({output}), screen=({screen})))")
     }
 
     /// Shortcut for width of pixel screen (index of last column).
@@ -474,6 +799,105 @@ impl TextCanvas {
         f64::from(self.cy())
     }
 
+    /// Convert screen coordinates to output coordinates.
+    ///
+    /// Returns the output cell (character) containing the screen pixel
+    /// `(x, y)`. One output cell is 2×4 screen pixels.
+    ///
+    /// Coordinates are not checked against the bounds of the canvas.
+    /// Negative coordinates are rounded down, so they map to negative
+    /// cells.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let canvas = TextCanvas::new(15, 5);
+    ///
+    /// assert_eq!(canvas.screen_to_output(0, 0), (0, 0));
+    /// assert_eq!(canvas.screen_to_output(3, 7), (1, 1));
+    /// assert_eq!(canvas.screen_to_output(4, 8), (2, 2));
+    /// ```
+    #[must_use]
+    pub fn screen_to_output(&self, x: i32, y: i32) -> (i32, i32) {
+        (x.div_euclid(2), y.div_euclid(4))
+    }
+
+    /// Convert output coordinates to screen coordinates.
+    ///
+    /// Returns the top-left screen pixel of the output cell (character)
+    /// `(x, y)`. One output cell is 2×4 screen pixels.
+    ///
+    /// Coordinates are not checked against the bounds of the canvas.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let canvas = TextCanvas::new(15, 5);
+    ///
+    /// assert_eq!(canvas.output_to_screen(0, 0), (0, 0));
+    /// assert_eq!(canvas.output_to_screen(1, 1), (2, 4));
+    /// assert_eq!(canvas.output_to_screen(14, 4), (28, 16));
+    /// ```
+    #[must_use]
+    pub fn output_to_screen(&self, x: i32, y: i32) -> (i32, i32) {
+        (x * 2, y * 4)
+    }
+
+    /// Output size needed to hold the lit pixels.
+    ///
+    /// This is the size, in output characters, of the bounding box of
+    /// all pixels turned _on_ (`(0, 0)` if there are none). Useful to
+    /// know how big a canvas must be to hold a drawing trimmed to its
+    /// content.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_line(4, 4, 8, 12);
+    ///
+    /// // 5×9 pixels, 3×3 characters.
+    /// assert_eq!(canvas.min_output_size(), (3, 3));
+    /// ```
+    #[must_use]
+    pub fn min_output_size(&self) -> (i32, i32) {
+        let Some((_, _, width, height)) = self.content_bounds() else {
+            return (0, 0);
+        };
+        ((width + 1) / 2, (height + 3) / 4)
+    }
+
+    /// Bounding box `(x, y, width, height)` of lit pixels, in screen
+    /// coordinates.
+    fn content_bounds(&self) -> Option<(i32, i32, i32, i32)> {
+        let mut bounds: Option<(i32, i32, i32, i32)> = None;
+        for (x, y) in self.iter_buffer() {
+            let (ux, uy) = (to_usize!(x), to_usize!(y));
+            if self.buffer[uy][ux] == OFF {
+                continue;
+            }
+            bounds = Some(match bounds {
+                None => (x, y, x, y),
+                Some((min_x, min_y, max_x, max_y)) => (
+                    cmp::min(min_x, x),
+                    cmp::min(min_y, y),
+                    cmp::max(max_x, x),
+                    cmp::max(max_y, y),
+                ),
+            });
+        }
+        bounds.map(|(min_x, min_y, max_x, max_y)| {
+            (min_x, min_y, max_x - min_x + 1, max_y - min_y + 1)
+        })
+    }
+
     /// Turn all pixels off and remove color and text.
     ///
     /// Note: This method does not drop the color and text buffers, it
@@ -483,9 +907,12 @@ impl TextCanvas {
     /// Note: `clear()` is not affected by inverted mode, it works on a
     /// lower level.
     pub fn clear(&mut self) {
+        self.invalidate_render();
         self.clear_buffer();
         self.clear_color_buffer();
         self.clear_text_buffer();
+        self.row_colors.fill(Color::new());
+        self.col_colors.fill(Color::new());
     }
 
     fn clear_buffer(&mut self) {
@@ -521,11 +948,42 @@ impl TextCanvas {
     /// Note: `fill()` is not affected by inverted mode, it works on a
     /// lower level.
     pub fn fill(&mut self) {
+        self.invalidate_render();
         for (x, y) in self.uiter_buffer() {
             self.buffer[y][x] = ON;
         }
     }
 
+    /// Transform all pixels.
+    ///
+    /// `f` receives the coordinates and the current state of each
+    /// pixel, and returns its new state.
+    ///
+    /// This does not affect the color and text buffers.
+    ///
+    /// Note: `map_pixels()` is not affected by inverted mode, it works
+    /// on a lower level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(3, 1);
+    ///
+    /// // Stripes.
+    /// canvas.map_pixels(|x, _, _| x % 2 == 0);
+    ///
+    /// assert_eq!(canvas.to_string(), "⡇⡇⡇\n");
+    /// ```
+    pub fn map_pixels(&mut self, f: impl Fn(i32, i32, bool) -> bool) {
+        self.invalidate_render();
+        for (x, y) in self.uiter_buffer() {
+            let state = self.buffer[y][x];
+            self.buffer[y][x] = f(to_i32!(x), to_i32!(y), state);
+        }
+    }
+
     /// Invert drawing mode.
     ///
     /// In inverted mode, functions that usually turn pixels _on_, will
@@ -535,6 +993,77 @@ impl TextCanvas {
         self.is_inverted = !self.is_inverted;
     }
 
+    /// Draw with inverted mode temporarily forced on or off.
+    ///
+    /// Inverted mode is set to `is_inverted` for the duration of the
+    /// closure, and restored to its previous state afterward. This is
+    /// cleaner than manual [`invert()`](TextCanvas::invert) pairs, and
+    /// the state is restored even if the closure itself toggles it, or
+    /// panics (the panic is then propagated, after restoring).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(3, 1);
+    ///
+    /// canvas.fill();
+    /// canvas.invert();
+    /// canvas.fill_rect(0, 0, 4, 4);
+    ///
+    /// // Cursor, drawn normally.
+    /// canvas.with_inversion(false, |canvas| {
+    ///     canvas.stroke_line(1, 3, 2, 3);
+    /// });
+    ///
+    /// assert!(canvas.is_inverted);
+    /// assert_eq!(canvas.to_string(), "⢀⡀⣿\n");
+    /// ```
+    pub fn with_inversion(&mut self, is_inverted: bool, f: impl FnOnce(&mut Self)) {
+        let was_inverted = self.is_inverted;
+        self.is_inverted = is_inverted;
+        let res = panic::catch_unwind(AssertUnwindSafe(|| f(self)));
+        self.is_inverted = was_inverted;
+        if let Err(payload) = res {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    /// Reset drawing state.
+    ///
+    /// Inverted mode is turned off, the context color is reset to
+    /// `Color::new()` (no color), and any clip region is removed. This
+    /// gives a clean slate for drawing, without leftover state from
+    /// previous drawing code.
+    ///
+    /// This does not affect the pixel, color and text buffers (see
+    /// [`clear()`](TextCanvas::clear) for that).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(3, 1);
+    ///
+    /// canvas.fill();
+    /// canvas.invert();
+    /// canvas.set_color(Color::new().red());
+    ///
+    /// canvas.reset_state();
+    /// canvas.draw_text("foo", 0, 0);
+    ///
+    /// assert!(!canvas.is_inverted);
+    /// assert_eq!(canvas.to_string(), "foo\n");
+    /// assert_eq!(canvas.pixels_to_string(), "⣿⣿⣿\n");
+    /// ```
+    pub fn reset_state(&mut self) {
+        self.is_inverted = false;
+        self.color = Color::new();
+        self.clip = None;
+    }
+
     /// Whether the canvas can contain colors.
     ///
     /// Note: This does not mean that any colors are displayed. This
@@ -595,60 +1124,389 @@ impl TextCanvas {
     /// canvas.draw_text("foo", 0, 0);
     /// assert_eq!(
     ///     canvas.to_string(),
-    ///     "\x1b[0;92mf\x1b[0m\x1b[0;92mo\x1b[0m\x1b[0;92mo\x1b[0m\n"
+    ///     "\x1b[0;92mfoo\x1b[0m\n"
     /// );
     /// ```
     pub fn set_color(&mut self, color: &Color) {
+        if self.is_color_disabled {
+            return;
+        }
         if !self.is_colorized() {
             self.init_color_buffer();
         }
         self.color = color.clone();
     }
 
-    fn init_color_buffer(&mut self) {
-        self.color_buffer = Vec::with_capacity(self.output.uheight());
-        for _ in 0..self.output.uheight() {
-            let row = vec![Color::new(); self.output.uwidth()];
-            self.color_buffer.push(row);
-        }
+    /// Disable color, for pure monochrome use.
+    ///
+    /// Once disabled, [`set_color()`](TextCanvas::set_color) is a
+    /// no-op, and the color buffer is never allocated (if it already
+    /// was, it is freed, and existing colors are lost). The canvas
+    /// stays non-colorized, even when drawing colored canvases onto it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(3, 1);
+    ///
+    /// canvas.disable_color();
+    /// canvas.set_color(Color::new().red());
+    /// canvas.draw_text("foo", 0, 0);
+    ///
+    /// assert!(!canvas.is_colorized());
+    /// assert_eq!(canvas.to_string(), "foo\n");
+    /// ```
+    pub fn disable_color(&mut self) {
+        self.invalidate_render();
+        self.is_color_disabled = true;
+        self.color = Color::new();
+        self.background = Color::new();
+        self.row_colors = Vec::new();
+        self.col_colors = Vec::new();
+        self.color_buffer = Vec::new();
     }
 
-    /// Get the state of a screen pixel.
+    /// Set the fallback color of pixels.
     ///
-    /// `Some(true)` if the pixel is turned _on_, `Some(false)` if it is
-    /// turned _off_, and `None` if the coordinates are outside the
-    /// bounds of the buffer.
+    /// Cells that contain lit pixels but have no color of their own
+    /// are rendered in this color. Cells colored with
+    /// [`set_color()`](TextCanvas::set_color) keep their color, and
+    /// text is not affected.
     ///
-    /// # Arguments
+    /// This is convenient for monochrome drawings, as it does not
+    /// require the color buffer.
     ///
-    /// - `x` - Screen X (high resolution).
-    /// - `y` - Screen Y (high resolution).
-    #[must_use]
-    pub fn get_pixel(&self, x: i32, y: i32) -> Option<bool> {
-        if !self.check_screen_bounds(x, y) {
-            return None;
-        }
-        let (x, y) = (to_usize!(x), to_usize!(y));
-        Some(self.buffer[y][x])
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(3, 1);
+    ///
+    /// canvas.set_default_pixel_color(Color::new().green());
+    /// canvas.stroke_line(0, 0, 3, 0);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\x1b[0;32m⠉⠉\x1b[0m⠀\n"
+    /// );
+    /// ```
+    pub fn set_default_pixel_color(&mut self, color: &Color) {
+        self.invalidate_render();
+        self.default_pixel_color = color.clone();
     }
 
-    /// Set the state of a screen pixel.
+    /// Set the background color of the pixel layer.
     ///
-    /// Note: Coordinates outside the screen bounds are ignored.
+    /// The background of `color` is applied to every cell of the pixel
+    /// layer, lit or not, unless the cell has a background color of its
+    /// own. Text is not affected (use
+    /// [`draw_text_boxed()`](TextCanvas::draw_text_boxed) for that).
     ///
-    /// Note: Turning a pixel _off_ also removes color. This side effect
-    /// does not affect text, as text has a separate color buffer.
+    /// Like [`set_color()`](TextCanvas::set_color), this is a no-op if
+    /// color is disabled.
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// - `x` - Screen X (high resolution).
-    /// - `y` - Screen Y (high resolution).
-    /// - `state` - `true` means _on_, `false` means _off_.
-    pub fn set_pixel(&mut self, x: i32, y: i32, mut state: bool) {
-        if !self.check_screen_bounds(x, y) {
-            return;
-        }
-        let (x, y) = (to_usize!(x), to_usize!(y));
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(2, 1);
+    ///
+    /// canvas.set_background(Color::new().bg_blue());
+    /// canvas.set_pixel(0, 0, true);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\x1b[0;44m⠁⠀\x1b[0m\n"
+    /// );
+    /// ```
+    pub fn set_background(&mut self, color: &Color) {
+        if self.is_color_disabled {
+            return;
+        }
+        self.invalidate_render();
+        self.background = color.clone();
+    }
+
+    /// Set the color of an entire row of output cells.
+    ///
+    /// Every cell of row `y` is colored with `color` in the color
+    /// buffer, lit or not. This applies to existing pixels of the row,
+    /// and to pixels drawn into it afterward, whatever the context
+    /// color. Blank cells take the color too (which makes a background
+    /// color visible across the whole row). This is convenient to style
+    /// tables.
+    ///
+    /// Row colors take precedence over column colors. Text keeps its
+    /// own color. Pass an empty color (`Color::new()`) to remove the
+    /// row color (this also decolors the cells of the row).
+    ///
+    /// Note: Row colors are reset by [`clear()`](TextCanvas::clear).
+    /// They ignore the clip region of [`CanvasView`]s.
+    ///
+    /// Like [`set_color()`](TextCanvas::set_color), this is a no-op if
+    /// color is disabled. It is also a no-op if `y` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(2, 2);
+    ///
+    /// canvas.set_row_color(1, Color::new().red());
+    /// canvas.set_color(Color::new().blue());
+    /// canvas.fill_rect(0, 0, 4, 8);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\x1b[0;34m⣿⣿\x1b[0m\n\x1b[0;31m⣿⣿\x1b[0m\n"
+    /// );
+    /// ```
+    pub fn set_row_color(&mut self, y: i32, color: &Color) {
+        if self.is_color_disabled || y < 0 || y >= self.output.height() {
+            return;
+        }
+        self.invalidate_render();
+        if !self.is_colorized() {
+            self.init_color_buffer();
+        }
+        if self.row_colors.is_empty() {
+            self.row_colors = vec![Color::new(); self.output.uheight()];
+        }
+        let y = to_usize!(y);
+        self.row_colors[y] = color.clone();
+        for x in 0..self.output.uwidth() {
+            self.color_buffer[y][x] = self.line_color(x, y).cloned().unwrap_or_default();
+        }
+    }
+
+    /// Set the color of an entire column of output cells.
+    ///
+    /// This is the same as [`set_row_color()`](TextCanvas::set_row_color),
+    /// but for column `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(2, 2);
+    ///
+    /// canvas.fill();
+    /// canvas.set_col_color(1, Color::new().red());
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "⣿\x1b[0;31m⣿\x1b[0m\n⣿\x1b[0;31m⣿\x1b[0m\n"
+    /// );
+    /// ```
+    pub fn set_col_color(&mut self, x: i32, color: &Color) {
+        if self.is_color_disabled || x < 0 || x >= self.output.width() {
+            return;
+        }
+        self.invalidate_render();
+        if !self.is_colorized() {
+            self.init_color_buffer();
+        }
+        if self.col_colors.is_empty() {
+            self.col_colors = vec![Color::new(); self.output.uwidth()];
+        }
+        let x = to_usize!(x);
+        self.col_colors[x] = color.clone();
+        for y in 0..self.output.uheight() {
+            self.color_buffer[y][x] = self.line_color(x, y).cloned().unwrap_or_default();
+        }
+    }
+
+    /// Color of the row or column of an output cell, if any.
+    fn line_color(&self, x: usize, y: usize) -> Option<&Color> {
+        let row_color = self.row_colors.get(y).filter(|color| !color.is_empty());
+        let col_color = self.col_colors.get(x).filter(|color| !color.is_empty());
+        row_color.or(col_color)
+    }
+
+    /// Set the color of an output cell, unless its row or column has a
+    /// color of its own.
+    fn set_cell_color(&mut self, x: usize, y: usize, color: Color) {
+        self.color_buffer[y][x] = match self.line_color(x, y) {
+            Some(line_color) => line_color.clone(),
+            None => color,
+        };
+    }
+
+    fn init_color_buffer(&mut self) {
+        self.color_buffer = Vec::with_capacity(self.output.uheight());
+        for _ in 0..self.output.uheight() {
+            let row = vec![Color::new(); self.output.uwidth()];
+            self.color_buffer.push(row);
+        }
+    }
+
+    /// Get the state of a screen pixel.
+    ///
+    /// `Some(true)` if the pixel is turned _on_, `Some(false)` if it is
+    /// turned _off_, and `None` if the coordinates are outside the
+    /// bounds of the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `x` - Screen X (high resolution).
+    /// - `y` - Screen Y (high resolution).
+    #[must_use]
+    pub fn get_pixel(&self, x: i32, y: i32) -> Option<bool> {
+        if !self.check_screen_bounds(x, y) {
+            return None;
+        }
+        let (x, y) = (to_usize!(x), to_usize!(y));
+        Some(self.buffer[y][x])
+    }
+
+    /// Count the lit pixels around a screen pixel.
+    ///
+    /// This looks at the 8 surrounding pixels (the pixel itself is not
+    /// counted). Pixels outside the bounds of the buffer count as
+    /// _off_. Useful for cellular automata, like Conway's Game of Life.
+    ///
+    /// # Arguments
+    ///
+    /// - `x` - Screen X (high resolution).
+    /// - `y` - Screen Y (high resolution).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(2, 1);
+    ///
+    /// canvas.fill();
+    ///
+    /// assert_eq!(canvas.count_neighbors(0, 1), 5);
+    /// assert_eq!(canvas.count_neighbors(0, 0), 3);
+    /// ```
+    #[must_use]
+    pub fn count_neighbors(&self, x: i32, y: i32) -> u8 {
+        let mut count = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if (dx, dy) == (0, 0) {
+                    continue;
+                }
+                if self.get_pixel(x + dx, y + dy) == Some(true) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Advance the pixels one generation of Conway's Game of Life.
+    ///
+    /// Each pixel is a cell, _on_ being alive. All cells are updated at
+    /// once, following the rules:
+    ///
+    /// - A live cell with 2 or 3 live neighbours survives.
+    /// - A dead cell with exactly 3 live neighbours becomes alive.
+    /// - All other cells die, or stay dead.
+    ///
+    /// Cells outside the canvas count as dead (see
+    /// [`count_neighbors()`](TextCanvas::count_neighbors)).
+    ///
+    /// This does not affect the color and text buffers.
+    ///
+    /// Note: `life_step()` is not affected by inverted mode, it works
+    /// on a lower level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(2, 1);
+    ///
+    /// // Blinker.
+    /// canvas.stroke_line(1, 0, 1, 2);
+    /// assert_eq!(canvas.to_string(), "⠸⠀\n");
+    ///
+    /// canvas.life_step();
+    /// assert_eq!(canvas.to_string(), "⠒⠂\n");
+    /// ```
+    pub fn life_step(&mut self) {
+        let next: Vec<Vec<bool>> = (0..self.screen.height())
+            .map(|y| {
+                (0..self.screen.width())
+                    .map(|x| {
+                        let is_alive = self.buffer[to_usize!(y)][to_usize!(x)];
+                        matches!(
+                            (is_alive, self.count_neighbors(x, y)),
+                            (true, 2 | 3) | (false, 3)
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
+        self.invalidate_render();
+        self.buffer = next;
+    }
+
+    /// Whether an output cell contains text.
+    ///
+    /// Text is drawn on top of pixels, so this tells whether the cell
+    /// is rendered as text or as pixels. Coordinates outside the bounds
+    /// of the output have no text.
+    ///
+    /// Note: Spaces in text are transparent, they do not count as text.
+    ///
+    /// # Arguments
+    ///
+    /// - `x` - Output X (character cells).
+    /// - `y` - Output Y (character cells).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(5, 1);
+    ///
+    /// canvas.fill();
+    /// canvas.draw_text("foo", 1, 0);
+    ///
+    /// assert!(!canvas.has_text_at(0, 0));
+    /// assert!(canvas.has_text_at(1, 0));
+    /// ```
+    #[must_use]
+    pub fn has_text_at(&self, x: i32, y: i32) -> bool {
+        if !self.is_textual() || !self.check_output_bounds(x, y) {
+            return false;
+        }
+        let (x, y) = (to_usize!(x), to_usize!(y));
+        !self.text_buffer[y][x].is_empty()
+    }
+
+    /// Set the state of a screen pixel.
+    ///
+    /// Note: Coordinates outside the screen bounds are ignored.
+    ///
+    /// Note: Turning a pixel _off_ also removes color. This side effect
+    /// does not affect text, as text has a separate color buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `x` - Screen X (high resolution).
+    /// - `y` - Screen Y (high resolution).
+    /// - `state` - `true` means _on_, `false` means _off_.
+    pub fn set_pixel(&mut self, x: i32, y: i32, mut state: bool) {
+        if !self.check_screen_bounds(x, y) {
+            return;
+        }
+        self.invalidate_render();
+        let (x, y) = (to_usize!(x), to_usize!(y));
 
         if self.is_inverted {
             state = !state;
@@ -665,12 +1523,61 @@ impl TextCanvas {
         }
     }
 
+    /// Set the state of multiple screen pixels.
+    ///
+    /// This is the same as calling [`set_pixel()`](TextCanvas::set_pixel)
+    /// for each point, but faster, because the drawing mode is looked
+    /// up only once for all points.
+    ///
+    /// Note: Coordinates outside the screen bounds are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `points` - Screen X and Y (high resolution) of the pixels.
+    /// - `state` - `true` means _on_, `false` means _off_.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(2, 1);
+    ///
+    /// canvas.set_pixels(&[(0, 0), (1, 1), (2, 2), (3, 3)], true);
+    ///
+    /// assert_eq!(canvas.to_string(), "⠑⢄\n");
+    /// ```
+    pub fn set_pixels(&mut self, points: &[(i32, i32)], mut state: bool) {
+        self.invalidate_render();
+        if self.is_inverted {
+            state = !state;
+        }
+        let is_colorized = self.is_colorized();
+
+        for &(x, y) in points {
+            if !self.check_screen_bounds(x, y) {
+                continue;
+            }
+            let (x, y) = (to_usize!(x), to_usize!(y));
+
+            self.buffer[y][x] = state;
+
+            if is_colorized {
+                if state == ON {
+                    self.color_pixel(x, y);
+                } else {
+                    self.decolor_pixel(x, y);
+                }
+            }
+        }
+    }
+
     fn color_pixel(&mut self, x: usize, y: usize) {
-        self.color_buffer[y / 4][x / 2] = self.color.clone();
+        self.set_cell_color(x / 2, y / 4, self.color.clone());
     }
 
     fn decolor_pixel(&mut self, x: usize, y: usize) {
-        self.color_buffer[y / 4][x / 2] = Color::new();
+        self.set_cell_color(x / 2, y / 4, Color::new());
     }
 
     /// Draw text onto the canvas.
@@ -697,2192 +1604,8435 @@ impl TextCanvas {
         }
     }
 
-    pub fn draw_text_vertical(&mut self, text: &str, x: i32, mut y: i32) {
-        if !self.is_textual() {
-            self.init_text_buffer();
-        }
-
-        for char in text.chars() {
-            self.draw_char(char, x, y, false);
-            y += 1;
+    /// Draw text onto the canvas, aligned relative to `x`.
+    ///
+    /// `x` is the anchor column. With [`Align::Left`], text starts at
+    /// the anchor (same as [`draw_text()`](TextCanvas::draw_text)).
+    /// With [`Align::Center`], text is centered on the anchor (if it
+    /// cannot be centered exactly, it leans left). With
+    /// [`Align::Right`], the last character is drawn on the anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Align, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(9, 3);
+    ///
+    /// canvas.draw_text_aligned("foo", 4, 0, Align::Left);
+    /// canvas.draw_text_aligned("foo", 4, 1, Align::Center);
+    /// canvas.draw_text_aligned("foo", 4, 2, Align::Right);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀foo⠀⠀
+    /// ⠀⠀⠀foo⠀⠀⠀
+    /// ⠀⠀foo⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn draw_text_aligned(&mut self, text: &str, x: i32, y: i32, align: Align) {
+        let x = Self::align_text(text, x, align);
+        self.draw_text(text, x, y);
+    }
+
+    /// Compute the start column of text aligned on `x`.
+    fn align_text(text: &str, x: i32, align: Align) -> i32 {
+        let len = i32::try_from(text.chars().count()).unwrap_or(i32::MAX);
+        match align {
+            Align::Left => x,
+            Align::Center => x.saturating_sub(len / 2),
+            Align::Right => x.saturating_sub(len).saturating_add(1),
         }
     }
 
-    /// Merge text onto the canvas.
+    /// Draw text onto the canvas, over a background color.
     ///
-    /// This is the same as [`draw_text()`](TextCanvas::draw_text), but
-    /// spaces do not erase text underneath.
-    pub fn merge_text(&mut self, text: &str, mut x: i32, y: i32) {
+    /// The background of `bg` is applied to every character cell of
+    /// the text, including spaces (which are _not_ transparent here),
+    /// so the text appears inside a colored box. The foreground comes
+    /// from the context color (see [`set_color()`](TextCanvas::set_color)).
+    ///
+    /// Note: `bg` should be of the same color mode as the context
+    /// color (see [`Color`]'s limitations). If they differ, the
+    /// foreground is dropped in favor of the background.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(4, 1);
+    ///
+    /// canvas.draw_text_boxed("a b", 0, 0, Color::new().bg_blue());
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\x1b[0;44ma b\x1b[0m⠀\n"
+    /// );
+    /// ```
+    pub fn draw_text_boxed(&mut self, text: &str, mut x: i32, y: i32, bg: &Color) {
+        self.invalidate_render();
         if !self.is_textual() {
             self.init_text_buffer();
         }
 
-        for char in text.chars() {
-            self.draw_char(char, x, y, true);
-            x += 1;
-        }
-    }
-
-    pub fn merge_text_vertical(&mut self, text: &str, x: i32, mut y: i32) {
-        if !self.is_textual() {
-            self.init_text_buffer();
-        }
+        let color = self.color.with_background_of(bg);
 
         for char in text.chars() {
-            self.draw_char(char, x, y, true);
-            y += 1;
+            if self.check_output_bounds(x, y) {
+                let (ux, uy) = (to_usize!(x), to_usize!(y));
+                self.text_buffer[uy][ux] = color.format(&String::from(char));
+            }
+            x += 1;
         }
     }
 
-    fn draw_char(&mut self, char: char, x: i32, y: i32, merge: bool) {
-        if !self.check_output_bounds(x, y) {
-            return;
-        }
-
-        let char = if char == ' ' {
-            if merge {
-                return;
-            }
-            String::new()
-        } else {
-            self.color.format(&String::from(char))
+    /// Draw text over a background color, with a readable foreground.
+    ///
+    /// This is the same as [`draw_text_boxed()`](TextCanvas::draw_text_boxed),
+    /// but the foreground is chosen automatically, black or white,
+    /// whichever contrasts best with the background of `bg` (based on
+    /// its [`luminance()`](Color::luminance)).
+    ///
+    /// The context color is left untouched.
+    ///
+    /// Note: This works with RGB backgrounds only. Otherwise, the
+    /// foreground comes from the context color, like with
+    /// [`draw_text_boxed()`](TextCanvas::draw_text_boxed).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(2, 1);
+    ///
+    /// canvas.draw_text_auto_contrast("a", 0, 0, Color::new().bg_rgb(0, 0, 128));
+    /// canvas.draw_text_auto_contrast("b", 1, 0, Color::new().bg_rgb(255, 255, 128));
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     concat!(
+    ///         "\x1b[0;38;2;255;255;255m\x1b[48;2;0;0;128ma\x1b[0m",
+    ///         "\x1b[0;38;2;0;0;0m\x1b[48;2;255;255;128mb\x1b[0m",
+    ///         "\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn draw_text_auto_contrast(&mut self, text: &str, x: i32, y: i32, bg: &Color) {
+        let Some(luminance) = bg.bg_luminance() else {
+            self.draw_text_boxed(text, x, y, bg);
+            return;
         };
 
-        let (ux, uy) = (to_usize!(x), to_usize!(y));
-        self.text_buffer[uy][ux] = char;
-    }
+        // WCAG contrast ratios, with white and with black.
+        let contrast_white = 1.05 / (luminance + 0.05);
+        let contrast_black = (luminance + 0.05) / 0.05;
+        let fg = if contrast_black > contrast_white {
+            Color::new().rgb(0, 0, 0).fix()
+        } else {
+            Color::new().rgb(255, 255, 255).fix()
+        };
 
-    fn init_text_buffer(&mut self) {
-        self.text_buffer = Vec::with_capacity(self.output.uheight());
-        for _ in 0..self.output.uheight() {
-            let row = vec![String::new(); self.output.uwidth()];
-            self.text_buffer.push(row);
-        }
+        let context_color = self.color.clone();
+        self.set_color(&fg);
+        self.draw_text_boxed(text, x, y, bg);
+        self.color = context_color;
     }
 
-    /// Render canvas as a `print!()`-able string.
+    /// Draw a hyperlink onto the canvas.
     ///
-    /// Returns rendered canvas, with pixels, text and colors. Each
-    /// canvas row becomes a line of text (lines are separated by
-    /// `\n`s), and each canvas column becomes a single character in
-    /// each line. What you would expect. It can be printed as-is.
-    fn render(&self) -> String {
-        let nb_output_chars = (self.output.uwidth() + 1) * self.output.uheight();
-        let mut res = String::with_capacity(nb_output_chars);
-
-        for (i, pixel_block) in self.iter_buffer_by_blocks_lrtb().enumerate() {
-            let x = i % self.output.uwidth();
-            let y = i / self.output.uwidth();
-
-            let text_char = self.get_text_char(x, y);
-            // Pixel layer.
-            if text_char.is_empty() {
-                let braille_char = Self::pixel_block_to_braille_char(pixel_block);
-                let braille_char = self.color_pixel_char(x, y, braille_char);
-                res.push_str(&braille_char);
-            }
-            // Text layer.
-            else {
-                res.push_str(&text_char);
-            }
-
-            // If end of line is reached, go to next line.
-            if (i + 1) % self.output.uwidth() == 0 {
-                res.push('\n');
-            }
-        }
-
-        res
-    }
-
-    fn get_text_char(&self, x: usize, y: usize) -> String {
-        if self.is_textual() {
-            return self.text_buffer[y][x].clone();
-        }
-        String::new()
-    }
-
-    fn pixel_block_to_braille_char(pixel_block: PixelBlock) -> BrailleChar {
-        let mut braille_char = BRAILLE_UNICODE_0;
-        // Iterate over individual pixels to turn them on or off.
-        for y in 0..4 {
-            for x in 0..2 {
-                if pixel_block[y][x] == ON {
-                    braille_char += BRAILLE_UNICODE_OFFSET_MAP[y][x];
-                }
-            }
-        }
-        // Convert Unicode integer value to char.
-        char::from_u32(braille_char).expect("from valid hardcoded values")
-    }
-
-    fn color_pixel_char(&self, x: usize, y: usize, pixel_char: char) -> String {
-        let pixel_char = String::from(pixel_char);
-        if self.is_colorized() {
-            let color = &self.color_buffer[y][x];
-            return color.format(&pixel_char);
-        }
-        pixel_char
-    }
-
-    fn iter_buffer_by_blocks_lrtb(&self) -> IterPixelBufferByBlocksLRTB {
-        IterPixelBufferByBlocksLRTB::new(&self.buffer, &self.screen)
-    }
-
-    /// Iterate over all cells of the pixel buffer.
+    /// The text is drawn like with [`draw_text()`](TextCanvas::draw_text),
+    /// but each character cell is wrapped in an OSC 8 escape sequence
+    /// pointing to `url`. Terminals that support OSC 8 make the text
+    /// clickable, others display it as plain text.
     ///
-    /// Yields all X/Y coordinates, left-right, top-bottom.
+    /// Every cell opens and closes the link on its own, so the link
+    /// cannot leak into the rest of the output, even if some cells are
+    /// overwritten or left out (e.g., by a trimmed or delta render).
     ///
-    /// Values are `i32`, this is meant to be used with the public API.
-    #[must_use]
-    pub fn iter_buffer(&self) -> IterPixelBuffer<i32> {
-        IterPixelBuffer::<i32>::new(&self.buffer)
-    }
-
-    /// Iterate over all cells of the pixel buffer.
+    /// Bytes of `url` outside of printable ASCII (including control
+    /// characters like `ESC` or `BEL`) are percent-encoded, so the URL
+    /// cannot terminate the sequence early.
     ///
-    /// Yields all X/Y coordinates, left-right, top-bottom.
+    /// Note: Spaces are _not_ transparent here, so that the link is
+    /// uninterrupted.
     ///
-    /// Values are `usize`, this is meant to index directly into the
-    /// buffer.
-    #[must_use]
-    pub fn uiter_buffer(&self) -> IterPixelBuffer<usize> {
-        IterPixelBuffer::<usize>::new(&self.buffer)
-    }
-}
-
-/// Implementation of drawing primitives.
-impl TextCanvas {
-    /// Stroke line.
+    /// Note: Coordinates outside the screen bounds are ignored. If the
+    /// text is partially out of bounds, only the visible part is drawn.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
+    /// let mut canvas = TextCanvas::new(5, 1);
     ///
-    /// canvas.stroke_line(5, 5, 25, 15);
+    /// canvas.draw_hyperlink("docs", "https://docs.rs", 0, 0);
     ///
     /// assert_eq!(
     ///     canvas.to_string(),
     ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⠐⠤⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⠀⠉⠒⠤⣀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠒⠤⣀⠀⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// "
+    /// \x1b]8;;https://docs.rs\x1b\\d\x1b]8;;\x1b\\\
+    /// \x1b]8;;https://docs.rs\x1b\\o\x1b]8;;\x1b\\\
+    /// \x1b]8;;https://docs.rs\x1b\\c\x1b]8;;\x1b\\\
+    /// \x1b]8;;https://docs.rs\x1b\\s\x1b]8;;\x1b\\\
+    /// ⠀\n"
     /// );
     /// ```
-    pub fn stroke_line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) {
-        self.bresenham_line(x1, y1, x2, y2);
-    }
-
-    /// Stroke line using Bresenham's line algorithm.
-    fn bresenham_line(&mut self, mut x1: i32, mut y1: i32, x2: i32, y2: i32) {
-        let dx = (x2 - x1).abs();
-        let sx = if x1 < x2 { 1 } else { -1 };
-        let dy = -(y2 - y1).abs();
-        let sy = if y1 < y2 { 1 } else { -1 };
-        let mut error = dx + dy;
+    pub fn draw_hyperlink(&mut self, text: &str, url: &str, mut x: i32, y: i32) {
+        self.invalidate_render();
+        if !self.is_textual() {
+            self.init_text_buffer();
+        }
 
-        // Treat vertical and horizontal lines as special cases.
-        if dx == 0 {
-            let x = x1;
-            let from_y = cmp::min(y1, y2);
-            let to_y = cmp::max(y1, y2);
-            for y in from_y..=to_y {
-                self.set_pixel(x, y, true);
-            }
-            return;
-        } else if dy == 0 {
-            let y = y1;
-            let from_x = cmp::min(x1, x2);
-            let to_x = cmp::max(x1, x2);
-            for x in from_x..=to_x {
-                self.set_pixel(x, y, true);
+        let open = format!("\x1b]8;;{}\x1b\\", Self::escape_url(url));
+        for char in text.chars() {
+            if self.check_output_bounds(x, y) {
+                let (ux, uy) = (to_usize!(x), to_usize!(y));
+                let char = self.color.format(&String::from(char));
+                self.text_buffer[uy][ux] = format!("{open}{char}\x1b]8;;\x1b\\");
             }
-            return;
+            x += 1;
         }
+    }
 
-        #[cfg(not(tarpaulin_include))]
-        loop {
-            self.set_pixel(x1, y1, true);
-            if x1 == x2 && y1 == y2 {
-                break;
-            }
-            let e2 = 2 * error;
-            if e2 >= dy {
-                if x1 == x2 {
-                    break;
-                }
-                error += dy;
-                x1 += sx;
-            }
-            if e2 <= dx {
-                if y1 == y2 {
-                    break;
-                }
-                error += dx;
-                y1 += sy;
+    /// Percent-encode bytes outside of printable ASCII.
+    fn escape_url(url: &str) -> String {
+        let mut escaped = String::with_capacity(url.len());
+        for byte in url.bytes() {
+            if (0x20..=0x7E).contains(&byte) {
+                escaped.push(char::from(byte));
+            } else {
+                write!(escaped, "%{byte:02X}").unwrap_or(());
             }
         }
+        escaped
     }
 
-    /// Stroke rectangle.
+    /// Draw a color swatch onto the canvas.
+    ///
+    /// `width` output cells, starting at `(x, y)`, are filled with full
+    /// blocks (`█`) of the given color. This is useful for custom
+    /// legends, next to a label.
+    ///
+    /// Like text, the swatch is drawn on top of pixels. If color is
+    /// disabled, blocks are drawn without color.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use textcanvas::TextCanvas;
+    /// use textcanvas::{Color, TextCanvas};
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
+    /// let mut canvas = TextCanvas::new(6, 1);
     ///
-    /// canvas.stroke_rect(5, 5, 20, 10);
+    /// canvas.draw_swatch(0, 0, 2, Color::new().red());
+    /// canvas.draw_text("foo", 3, 0);
     ///
     /// assert_eq!(
     ///     canvas.to_string(),
-    ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⢰⠒⠒⠒⠒⠒⠒⠒⠒⠒⡆⠀⠀
-    /// ⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀
-    /// ⠀⠀⠸⠤⠤⠤⠤⠤⠤⠤⠤⠤⠇⠀⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// "
+    ///     "\x1b[0;31m██\x1b[0m⠀foo\n"
     /// );
     /// ```
-    pub fn stroke_rect(&mut self, x: i32, y: i32, width: i32, height: i32) {
-        let (width, height) = (width - 1, height - 1);
-        self.stroke_line(x, y, x + width, y);
-        self.stroke_line(x + width, y, x + width, y + height);
-        self.stroke_line(x + width, y + height, x, y + height);
-        self.stroke_line(x, y + height, x, y);
+    pub fn draw_swatch(&mut self, mut x: i32, y: i32, width: i32, color: &Color) {
+        self.invalidate_render();
+        if !self.is_textual() {
+            self.init_text_buffer();
+        }
+
+        let block = if self.is_color_disabled {
+            String::from('█')
+        } else {
+            color.format("█")
+        };
+
+        for _ in 0..width {
+            if self.check_output_bounds(x, y) {
+                let (ux, uy) = (to_usize!(x), to_usize!(y));
+                self.text_buffer[uy][ux].clone_from(&block);
+            }
+            x += 1;
+        }
     }
 
-    /// Draw a border around the canvas.
+    /// Render a one-row progress bar.
+    ///
+    /// The bar is `width` cells wide. The first `fraction` of it is
+    /// drawn in `filled` color, and the rest in `empty` color. Partial
+    /// blocks (`▏▎▍▌▋▊▉`) are used for the boundary cell, which gives
+    /// a precision of 1/8th of a cell.
+    ///
+    /// `fraction` is clamped to `[0; 1]`; `NaN` is treated as `0`.
+    ///
+    /// This is independent of any canvas, the bar is returned as a
+    /// string, without a trailing newline.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use textcanvas::TextCanvas;
+    /// use textcanvas::{Color, TextCanvas};
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
+    /// let bar = TextCanvas::progress_bar(4, 0.5625, &Color::new(), &Color::new());
     ///
-    /// canvas.frame();
+    /// assert_eq!(bar, "██▎█");
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⡏⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⢹
-    /// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-    /// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-    /// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-    /// ⣇⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣸
-    /// "
-    /// );
+    /// let bar = TextCanvas::progress_bar(2, 0.5, Color::new().green(), Color::new().bright_gray());
+    ///
+    /// assert_eq!(bar, "\x1b[0;32m█\x1b[0m\x1b[0;90m█\x1b[0m");
     /// ```
-    pub fn frame(&mut self) {
-        self.stroke_rect(0, 0, self.screen.width(), self.screen.height());
+    #[must_use]
+    pub fn progress_bar(width: i32, fraction: f64, filled: &Color, empty: &Color) -> String {
+        const PARTIAL_BLOCKS: [&str; 7] = ["▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+        let width = usize::try_from(width).unwrap_or(0);
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+
+        // Work in 1/8th of cells.
+        #[allow(clippy::cast_precision_loss)]
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        let nb_eighths = (fraction * (width * 8) as f64).round() as usize;
+        let (nb_full, remainder) = (nb_eighths / 8, nb_eighths % 8);
+
+        let mut bar = String::new();
+        for i in 0..width {
+            if i < nb_full {
+                bar.push_str(&filled.format("█"));
+            } else if i == nb_full && remainder > 0 {
+                bar.push_str(&filled.format(PARTIAL_BLOCKS[remainder - 1]));
+            } else {
+                bar.push_str(&empty.format("█"));
+            }
+        }
+        bar
     }
 
-    /// Fill rectangle.
+    pub fn draw_text_vertical(&mut self, text: &str, x: i32, mut y: i32) {
+        if !self.is_textual() {
+            self.init_text_buffer();
+        }
+
+        for char in text.chars() {
+            self.draw_char(char, x, y, false);
+            y += 1;
+        }
+    }
+
+    /// Draw text vertically, rotated 90° clockwise.
+    ///
+    /// Like [`draw_text_vertical()`](TextCanvas::draw_text_vertical),
+    /// characters are stacked top to bottom. In addition, characters
+    /// that have a rotated counterpart (box-drawing characters and
+    /// arrows) are substituted, so that the text looks rotated. Other
+    /// characters (letters, digits, etc.) stay upright.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
+    /// let mut canvas = TextCanvas::new(1, 4);
     ///
-    /// canvas.fill_rect(5, 5, 20, 10);
+    /// canvas.draw_text_vertical_rotated("├─→", 0, 0);
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⢰⣶⣶⣶⣶⣶⣶⣶⣶⣶⡆⠀⠀
-    /// ⠀⠀⢸⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇⠀⠀
-    /// ⠀⠀⠸⠿⠿⠿⠿⠿⠿⠿⠿⠿⠇⠀⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// "
-    /// );
+    /// assert_eq!(canvas.to_string(), "┬\n│\n↓\n⠀\n");
     /// ```
-    pub fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32) {
-        for y in y..y + height {
-            self.stroke_line(x, y, x + width - 1, y);
+    pub fn draw_text_vertical_rotated(&mut self, text: &str, x: i32, mut y: i32) {
+        if !self.is_textual() {
+            self.init_text_buffer();
+        }
+
+        for char in text.chars() {
+            self.draw_char(Self::rotate_char_clockwise(char), x, y, false);
+            y += 1;
         }
     }
 
-    /// Stroke triangle.
+    fn rotate_char_clockwise(char: char) -> char {
+        match char {
+            // Lines.
+            '─' => '│',
+            '│' => '─',
+            '━' => '┃',
+            '┃' => '━',
+            '═' => '║',
+            '║' => '═',
+            '┄' => '┆',
+            '┆' => '┄',
+            '┈' => '┊',
+            '┊' => '┈',
+            // Corners.
+            '┌' => '┐',
+            '┐' => '┘',
+            '┘' => '└',
+            '└' => '┌',
+            '┏' => '┓',
+            '┓' => '┛',
+            '┛' => '┗',
+            '┗' => '┏',
+            '╔' => '╗',
+            '╗' => '╝',
+            '╝' => '╚',
+            '╚' => '╔',
+            '╭' => '╮',
+            '╮' => '╯',
+            '╯' => '╰',
+            '╰' => '╭',
+            // Junctions.
+            '├' => '┬',
+            '┬' => '┤',
+            '┤' => '┴',
+            '┴' => '├',
+            '┣' => '┳',
+            '┳' => '┫',
+            '┫' => '┻',
+            '┻' => '┣',
+            '╠' => '╦',
+            '╦' => '╣',
+            '╣' => '╩',
+            '╩' => '╠',
+            // Arrows.
+            '→' => '↓',
+            '↓' => '←',
+            '←' => '↑',
+            '↑' => '→',
+            '↔' => '↕',
+            '↕' => '↔',
+            '⇒' => '⇓',
+            '⇓' => '⇐',
+            '⇐' => '⇑',
+            '⇑' => '⇒',
+            _ => char,
+        }
+    }
+
+    /// Merge text onto the canvas.
+    ///
+    /// This is the same as [`draw_text()`](TextCanvas::draw_text), but
+    /// spaces do not erase text underneath.
+    pub fn merge_text(&mut self, text: &str, mut x: i32, y: i32) {
+        if !self.is_textual() {
+            self.init_text_buffer();
+        }
+
+        for char in text.chars() {
+            self.draw_char(char, x, y, true);
+            x += 1;
+        }
+    }
+
+    pub fn merge_text_vertical(&mut self, text: &str, x: i32, mut y: i32) {
+        if !self.is_textual() {
+            self.init_text_buffer();
+        }
+
+        for char in text.chars() {
+            self.draw_char(char, x, y, true);
+            y += 1;
+        }
+    }
+
+    /// Fill the text layer with a character.
+    ///
+    /// Every output cell is set to `char`, in the context color. This
+    /// is like calling [`draw_text()`](TextCanvas::draw_text) on every
+    /// cell, so filling with a space clears the text layer (spaces are
+    /// transparent).
+    ///
+    /// Text is drawn on top of pixels, so this hides all pixels. Draw
+    /// text over it to make placeholders or dotted grids.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
+    /// let mut canvas = TextCanvas::new(5, 2);
     ///
-    /// canvas.stroke_triangle(5, 5, 20, 10, 4, 17);
+    /// canvas.fill_text('·');
+    /// canvas.draw_text("foo", 1, 1);
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⢰⠢⠤⣀⡀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⢸⠀⠀⠀⠈⠉⢒⡢⠄⠀⠀⠀⠀
-    /// ⠀⠀⡇⠀⣀⠤⠔⠊⠁⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⠓⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// "
-    /// );
+    /// assert_eq!(canvas.to_string(), "·····\n·foo·\n");
     /// ```
-    pub fn stroke_triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32) {
-        self.stroke_line(x1, y1, x2, y2);
-        self.stroke_line(x2, y2, x3, y3);
-        self.stroke_line(x3, y3, x1, y1);
+    pub fn fill_text(&mut self, char: char) {
+        if !self.is_textual() {
+            self.init_text_buffer();
+        }
+
+        for y in 0..self.output.height() {
+            for x in 0..self.output.width() {
+                self.draw_char(char, x, y, false);
+            }
+        }
     }
 
-    /// Fill triangle.
+    /// Draw a ruler along the top and left edges, for debugging.
+    ///
+    /// Column numbers are drawn along the top edge every 10 output
+    /// cells, with a tick (`·`) half-way. Row numbers are drawn along
+    /// the left edge every 5 output cells. Numbers start at the cell
+    /// they label, so you can read off coordinates while positioning
+    /// elements. Remove the call to hide the ruler.
+    ///
+    /// The ruler is drawn as text, in a dim color (unless color is
+    /// disabled), and is merged like
+    /// [`merge_text()`](TextCanvas::merge_text). The context color is
+    /// left unchanged.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
+    /// let mut canvas = TextCanvas::builder().size(30, 10).with_color(false).build();
     ///
-    /// canvas.fill_triangle(5, 5, 20, 10, 4, 17);
+    /// canvas.draw_ruler();
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⢰⣦⣤⣀⡀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⢸⣿⣿⣿⣿⣿⣶⡦⠄⠀⠀⠀⠀
-    /// ⠀⠀⣿⣿⣿⠿⠟⠋⠁⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⠛⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-    /// "
-    /// );
+    /// let output = canvas.to_string();
+    /// let lines: Vec<&str> = output.lines().collect();
+    ///
+    /// assert_eq!(lines[0], "0⠀⠀⠀⠀·⠀⠀⠀⠀10⠀⠀⠀·⠀⠀⠀⠀20⠀⠀⠀·⠀⠀⠀⠀");
+    /// assert!(lines[5].starts_with("5⠀⠀⠀"));
     /// ```
-    pub fn fill_triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32) {
-        // This makes for neater edges.
-        self.stroke_triangle(x1, y1, x2, y2, x3, y3);
+    pub fn draw_ruler(&mut self) {
+        const STEP_X: i32 = 10;
+        const STEP_Y: i32 = 5;
 
-        // Barycentric Algorithm: Compute the bounding box of the
-        // triangle. Then for each point in the box, determine if it
-        // lies inside or outside the triangle.
-
-        // Bounding box.
-        let min_x = cmp::min(x1, cmp::min(x2, x3));
-        let max_x = cmp::max(x1, cmp::max(x2, x3));
-        let min_y = cmp::min(y1, cmp::min(y2, y3));
-        let max_y = cmp::max(y1, cmp::max(y2, y3));
-
-        let p1 = (f64::from(x1), f64::from(y1));
-        let p2 = (f64::from(x2), f64::from(y2));
-        let p3 = (f64::from(x3), f64::from(y3));
-        let triangle = (p1, p2, p3);
+        let color = self.color.clone();
+        self.set_color(Color::new().bright_gray());
 
-        for x in min_x..=max_x {
-            for y in min_y..=max_y {
-                let point = (f64::from(x), f64::from(y));
-                if Self::is_point_in_triangle(point, triangle) {
-                    self.set_pixel(x, y, true);
-                }
+        for x in (0..self.output.width()).step_by(to_usize!(STEP_X / 2)) {
+            if x % STEP_X == 0 {
+                self.merge_text(&x.to_string(), x, 0);
+            } else {
+                self.merge_text("·", x, 0);
             }
         }
-    }
 
-    #[allow(clippy::similar_names)]
-    fn is_point_in_triangle(
-        (px, py): (f64, f64),
-        ((p0x, p0y), (p1x, p1y), (p2x, p2y)): ((f64, f64), (f64, f64), (f64, f64)),
-    ) -> bool {
-        // This version correctly handles triangles specified in either
-        // winding direction (clockwise vs. counterclockwise).
-        // https://stackoverflow.com/a/20861130 — Glenn Slayden
-        let s = (p0x - p2x) * (py - p2y) - (p0y - p2y) * (px - p2x);
-        let t = (p1x - p0x) * (py - p0y) - (p1y - p0y) * (px - p0x);
+        for y in (STEP_Y..self.output.height()).step_by(to_usize!(STEP_Y)) {
+            self.merge_text(&y.to_string(), 0, y);
+        }
 
-        if (s < 0.0) != (t < 0.0) && s != 0.0 && t != 0.0 {
-            return false;
+        self.color = color;
+    }
+
+    fn draw_char(&mut self, char: char, x: i32, y: i32, merge: bool) {
+        if !self.check_output_bounds(x, y) {
+            return;
         }
+        self.invalidate_render();
 
-        let d = (p2x - p1x) * (py - p1y) - (p2y - p1y) * (px - p1x);
+        let char = if char == ' ' {
+            if merge {
+                return;
+            }
+            String::new()
+        } else {
+            self.color.format(&String::from(char))
+        };
 
-        d == 0.0 || (d < 0.0) == (s + t <= 0.0)
+        let (ux, uy) = (to_usize!(x), to_usize!(y));
+        self.text_buffer[uy][ux] = char;
     }
 
-    /// Stroke circle.
+    fn init_text_buffer(&mut self) {
+        self.text_buffer = Vec::with_capacity(self.output.uheight());
+        for _ in 0..self.output.uheight() {
+            let row = vec![String::new(); self.output.uwidth()];
+            self.text_buffer.push(row);
+        }
+    }
+
+    /// Render canvas as a `print!()`-able string.
+    ///
+    /// Returns rendered canvas, with pixels, text and colors. Each
+    /// canvas row becomes a line of text (lines are separated by
+    /// `\n`s), and each canvas column becomes a single character in
+    /// each line. What you would expect. It can be printed as-is.
+    ///
+    /// If the render cache is enabled, the result is cached until the
+    /// canvas is mutated, so rendering an unchanged canvas again is
+    /// free.
+    fn render(&self) -> Cow<'_, str> {
+        let render = || {
+            let nb_output_chars = (self.output.uwidth() + 1) * self.output.uheight();
+            let mut res = String::with_capacity(nb_output_chars);
+            self.render_layers_into(&mut res, true);
+            res
+        };
+        if self.is_render_cache_enabled {
+            Cow::Borrowed(self.render_cache.get_or_init(render))
+        } else {
+            Cow::Owned(render())
+        }
+    }
+
+    /// Enable or disable the render cache (disabled by default).
+    ///
+    /// With the cache enabled, the last render is re-used by
+    /// `to_string()` (and friends) until the canvas is mutated. This
+    /// saves cycles in TUIs that print the same canvas repeatedly.
+    ///
+    /// Note: Drawing methods invalidate the cache automatically, but
+    /// writing to the public buffers directly does not. If you do that,
+    /// call [`invalidate_render()`](TextCanvas::invalidate_render)
+    /// yourself, or the render will be stale.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
-    ///
-    /// canvas.stroke_circle(canvas.cx(), canvas.cy(), 7);
+    /// let mut canvas = TextCanvas::new(1, 1);
+    /// canvas.set_render_cache(true);
+    /// assert_eq!(canvas.to_string(), "⠀\n");
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⡠⠊⠀⠀⠀⠈⠢⡀⠀⠀⠀
-    /// ⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀
-    /// ⠀⠀⠀⠀⠣⡀⠀⠀⠀⠀⡠⠃⠀⠀⠀
-    /// ⠀⠀⠀⠀⠀⠈⠒⠒⠒⠊⠀⠀⠀⠀⠀
-    /// "
-    /// );
+    /// canvas.set_pixel(0, 0, true);
+    /// assert_eq!(canvas.to_string(), "⠁\n");
     /// ```
-    pub fn stroke_circle(&mut self, x: i32, y: i32, radius: i32) {
-        self.bresenham_circle(x, y, radius, false);
+    pub fn set_render_cache(&mut self, is_enabled: bool) {
+        self.is_render_cache_enabled = is_enabled;
+        self.invalidate_render();
     }
 
-    /// Fill circle.
+    /// Discard the cached render.
+    ///
+    /// This only matters if the render cache is enabled (see
+    /// [`set_render_cache()`](TextCanvas::set_render_cache)). Drawing
+    /// methods do this automatically. Call it yourself if you write to
+    /// the buffers directly, after the canvas was rendered.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
+    /// let mut canvas = TextCanvas::new(1, 1);
+    /// canvas.set_render_cache(true);
+    /// assert_eq!(canvas.to_string(), "⠀\n");
     ///
-    /// canvas.fill_circle(canvas.cx(), canvas.cy(), 7);
+    /// canvas.buffer[0][0] = true;
+    /// canvas.invalidate_render();
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⣠⣾⣿⣿⣿⣿⣦⡀⠀⠀⠀
-    /// ⠀⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⡇⠀⠀⠀
-    /// ⠀⠀⠀⠀⠻⣿⣿⣿⣿⣿⡿⠃⠀⠀⠀
-    /// ⠀⠀⠀⠀⠀⠈⠛⠛⠛⠋⠀⠀⠀⠀⠀
-    /// "
-    /// );
+    /// assert_eq!(canvas.to_string(), "⠁\n");
     /// ```
-    pub fn fill_circle(&mut self, x: i32, y: i32, radius: i32) {
-        self.bresenham_circle(x, y, radius, true);
-    }
-
-    /// Draw circle using Jesko's Method of the Bresenham's circle
-    /// algorithm.
-    fn bresenham_circle(&mut self, x: i32, y: i32, radius: i32, fill: bool) {
-        let (cx, cy) = (x, y);
-        let mut t1 = radius / 16;
-        let mut x = radius;
-        let mut y = 0;
-        while x >= y {
-            if fill {
-                // Connect each pair of points with the same `y`.
-                self.stroke_line(cx - x, cy - y, cx + x, cy - y);
-                self.stroke_line(cx + x, cy + y, cx - x, cy + y);
-                self.stroke_line(cx - y, cy - x, cx + y, cy - x);
-                self.stroke_line(cx + y, cy + x, cx - y, cy + x);
-            } else {
-                self.set_pixel(cx - x, cy - y, true);
-                self.set_pixel(cx + x, cy - y, true);
-                self.set_pixel(cx + x, cy + y, true);
-                self.set_pixel(cx - x, cy + y, true);
-                self.set_pixel(cx - y, cy - x, true);
-                self.set_pixel(cx + y, cy - x, true);
-                self.set_pixel(cx + y, cy + x, true);
-                self.set_pixel(cx - y, cy + x, true);
-            }
-
-            y += 1;
-            t1 += y;
-            let t2 = t1 - x;
-            if t2 >= 0 {
-                t1 = t2;
-                x -= 1;
-            }
-        }
+    pub fn invalidate_render(&mut self) {
+        self.render_cache.take();
     }
 
-    /// Stroke n-gon.
+    /// Render canvas into an existing `String`.
+    ///
+    /// This is the same as `to_string()`, but the buffer is cleared
+    /// and re-used instead of allocating a new `String` on every call.
+    /// This is useful in render loops, where one buffer can be re-used
+    /// across all frames.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
-    /// use std::f64::consts::PI;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
+    /// let mut canvas = TextCanvas::new(3, 1);
+    /// let mut frame = String::new();
     ///
-    /// canvas.stroke_ngon(canvas.cx(), canvas.cy(), 7, 5, PI / 2.0);
+    /// canvas.draw_text("foo", 0, 0);
+    /// canvas.render_into(&mut frame);
+    /// assert_eq!(frame, "foo\n");
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⠀⢀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⢀⡠⠊⠁⠉⠢⣀⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⢣⠀⠀⠀⠀⠀⢠⠃⠀⠀⠀
-    /// ⠀⠀⠀⠀⠀⢇⠀⠀⠀⢀⠎⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⠀⠈⠉⠉⠉⠉⠀⠀⠀⠀⠀
-    /// "
-    /// );
+    /// canvas.draw_text("bar", 0, 0);
+    /// canvas.render_into(&mut frame);
+    /// assert_eq!(frame, "bar\n");
     /// ```
-    ///
-    /// # Panics
-    ///
-    /// Panics if `sides` < 3.
-    pub fn stroke_ngon(&mut self, x: i32, y: i32, radius: i32, sides: i32, angle: f64) {
-        self.ngon(x, y, radius, sides, angle, false);
+    pub fn render_into(&self, buf: &mut String) {
+        if let Some(cached) = self.render_cache.get() {
+            buf.clear();
+            buf.push_str(cached);
+            return;
+        }
+        self.render_layers_into(buf, true);
     }
 
-    /// Fill n-gon.
+    /// Render canvas for a full-screen redraw.
+    ///
+    /// This is the same as `to_string()`, prefixed with clear-screen
+    /// (`\x1b[2J`) and cursor-home (`\x1b[H`) escape sequences, so
+    /// the frame is painted in place, from the top-left corner of the
+    /// terminal.
+    ///
+    /// Note: Like `to_string()`, the render ends with a newline. If
+    /// the canvas is as tall as the terminal, this makes it scroll by
+    /// one line (see [`Animation`](crate::anim::Animation) for
+    /// flicker-free frames).
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
-    ///
-    /// canvas.fill_ngon(canvas.cx(), canvas.cy(), 7, 4, 0.0);
+    /// let mut canvas = TextCanvas::new(3, 1);
+    /// canvas.draw_text("foo", 0, 0);
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠀⠀⠀⠀⠀⠀⠀⢀⠀⠀⠀⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⠀⢀⣴⣿⣷⣄⠀⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⢴⣿⣿⣿⣿⣿⣷⠄⠀⠀⠀
-    /// ⠀⠀⠀⠀⠀⠙⢿⣿⣿⠟⠁⠀⠀⠀⠀
-    /// ⠀⠀⠀⠀⠀⠀⠀⠙⠁⠀⠀⠀⠀⠀⠀
-    /// "
-    /// );
+    /// assert_eq!(canvas.render_fullscreen(), "\x1b[2J\x1b[Hfoo\n");
     /// ```
+    #[must_use]
+    pub fn render_fullscreen(&self) -> String {
+        const CLEAR_AND_HOME: &str = "\x1b[2J\x1b[H";
+
+        let render = self.render();
+        let mut res = String::with_capacity(CLEAR_AND_HOME.len() + render.len());
+        res.push_str(CLEAR_AND_HOME);
+        res.push_str(&render);
+        res
+    }
+
+    /// Render the pixel layer only.
     ///
-    /// # Panics
+    /// This is the same as `to_string()`, but text is ignored. Cells
+    /// with text show the pixels underneath instead. This is useful
+    /// to toggle off text annotations.
     ///
-    /// Panics if `sides` < 3.
-    pub fn fill_ngon(&mut self, x: i32, y: i32, radius: i32, sides: i32, angle: f64) {
-        self.ngon(x, y, radius, sides, angle, true);
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(3, 1);
+    ///
+    /// canvas.fill();
+    /// canvas.draw_text("foo", 0, 0);
+    ///
+    /// assert_eq!(canvas.to_string(), "foo\n");
+    /// assert_eq!(canvas.pixels_to_string(), "⣿⣿⣿\n");
+    /// ```
+    #[must_use]
+    pub fn pixels_to_string(&self) -> String {
+        let mut res = String::new();
+        self.render_layers_into(&mut res, false);
+        res
     }
 
-    fn ngon(&mut self, x: i32, y: i32, radius: i32, sides: i32, angle: f64, fill: bool) {
-        assert!(
-            sides >= 3,
-            "Minimum 3 sides needed to draw an n-gon, but only {sides} requested."
-        );
+    /// Render canvas without escape sequences.
+    ///
+    /// This is the same as `to_string()`, but colors (of pixels and
+    /// text) and other escape sequences (e.g., hyperlinks) are left
+    /// out. Every line has exactly `output.width()` characters, which
+    /// makes it safe for fixed-width layouts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(5, 1);
+    ///
+    /// canvas.set_color(Color::new().red());
+    /// canvas.fill_rect(0, 0, 4, 4);
+    /// canvas.draw_text("foo", 2, 0);
+    ///
+    /// assert_eq!(canvas.to_plain_string(), "⣿⣿foo\n");
+    /// ```
+    #[must_use]
+    pub fn to_plain_string(&self) -> String {
+        let nb_output_chars = (self.output.uwidth() + 1) * self.output.uheight();
+        let mut res = String::with_capacity(nb_output_chars);
 
-        let mut join_vertices = |from: &(i32, i32), to: &(i32, i32)| {
-            if fill {
-                self.fill_triangle(self.cx(), self.cy(), from.0, from.1, to.0, to.1);
+        for (i, pixel_block) in self.iter_buffer_by_blocks_lrtb().enumerate() {
+            let x = i % self.output.uwidth();
+            let y = i / self.output.uwidth();
+
+            let text_char = self.get_text_char(x, y);
+            if text_char.is_empty() {
+                res.push(Self::pixel_block_to_braille_char(pixel_block));
             } else {
-                self.stroke_line(from.0, from.1, to.0, to.1);
+                res.push_str(&Self::strip_escape_sequences(&text_char));
             }
-        };
-
-        let vertices = Self::compute_ngon_vertices(x, y, radius, sides, angle);
-        let mut vertices = vertices.iter();
 
-        let first = vertices.next().expect("there are at least 3 vertex");
-        let mut previous = first;
-        for vertex in vertices {
-            join_vertices(previous, vertex);
-            previous = vertex;
+            if (i + 1) % self.output.uwidth() == 0 {
+                res.push('\n');
+            }
         }
-        join_vertices(previous, first);
+
+        res
     }
 
-    #[allow(clippy::cast_possible_truncation)]
-    fn compute_ngon_vertices(
-        x: i32,
-        y: i32,
-        radius: i32,
-        sides: i32,
-        angle: f64,
-    ) -> Vec<(i32, i32)> {
-        let cx = f64::from(x);
-        let cy = f64::from(y);
-        let radius = f64::from(radius);
-        let slice = (2.0 * std::f64::consts::PI) / f64::from(sides);
+    /// Remove CSI (e.g., colors) and OSC (e.g., hyperlinks) sequences.
+    fn strip_escape_sequences(text: &str) -> String {
+        let mut res = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
 
-        let mut vertices: Vec<(i32, i32)> = Vec::with_capacity(to_usize!(sides));
-        for vertex in 0..sides {
-            let theta = f64::from(vertex) * slice + angle;
-            let x = cx + (theta.cos() * radius);
-            let y = cy - (theta.sin() * radius); // Screen Y coordinates are inverted.
-            let point = (x.round() as i32, y.round() as i32);
-            vertices.push(point);
+        while let Some(char) = chars.next() {
+            if char != '\x1b' {
+                res.push(char);
+                continue;
+            }
+            match chars.next() {
+                // CSI, ends with a byte in the `@`–`~` range.
+                Some('[') => {
+                    for char in chars.by_ref() {
+                        if ('@'..='~').contains(&char) {
+                            break;
+                        }
+                    }
+                }
+                // OSC, ends with BEL or ST (`ESC \`).
+                Some(']') => {
+                    while let Some(char) = chars.next() {
+                        if char == '\x07' {
+                            break;
+                        }
+                        if char == '\x1b' && chars.peek() == Some(&'\\') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
         }
-        vertices
+
+        res
     }
 
-    /// Draw another canvas onto the current canvas.
+    /// Render canvas as a flat array of Unicode code points.
     ///
-    /// The other canvas completely overrides the current canvas where
-    /// it is drawn (but it does not affect the portions where it is
-    /// _not_ drawn).
+    /// Cells are laid out row-major (left-right, top-bottom), one code
+    /// point per output cell, so the length is always `output.width() *
+    /// output.height()`. Text and Braille characters are resolved like
+    /// in [`to_plain_string()`](TextCanvas::to_plain_string), and
+    /// colors are ignored.
     ///
-    /// Note: Inverted mode has no effect here, this is a low level
-    /// copy-paste.
+    /// This is easy to marshal across FFI boundaries.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
-    /// canvas.stroke_line(0, 0, canvas.w(), canvas.h());
-    /// canvas.stroke_line(0, canvas.h(), canvas.w(), 0);
-    ///
-    /// let mut overlay = TextCanvas::new(7, 3);
-    /// overlay.frame();
+    /// let mut canvas = TextCanvas::new(3, 1);
     ///
-    /// canvas.draw_canvas(&overlay, 8, 4);
+    /// canvas.set_pixel(0, 0, true);
+    /// canvas.draw_text("a", 2, 0);
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠑⠢⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠔⠊
-    /// ⠀⠀⠀⠑⡏⠉⠉⠉⠉⠉⢹⠊⠀⠀⠀
-    /// ⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀
-    /// ⠀⠀⠀⡠⣇⣀⣀⣀⣀⣀⣸⢄⠀⠀⠀
-    /// ⡠⠔⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠢⢄
-    /// "
-    /// );
+    /// assert_eq!(canvas.to_code_points(), [0x2801, 0x2800, 0x61]);
     /// ```
-    pub fn draw_canvas(&mut self, canvas: &Self, dx: i32, dy: i32) {
-        self.draw_canvas_onto_canvas(canvas, dx, dy, false);
+    #[must_use]
+    pub fn to_code_points(&self) -> Vec<u32> {
+        let mut res = Vec::with_capacity(self.output.uwidth() * self.output.uheight());
+
+        for (i, pixel_block) in self.iter_buffer_by_blocks_lrtb().enumerate() {
+            let x = i % self.output.uwidth();
+            let y = i / self.output.uwidth();
+
+            let text_char = Self::strip_escape_sequences(&self.get_text_char(x, y));
+            let char = text_char
+                .chars()
+                .next()
+                .unwrap_or_else(|| Self::pixel_block_to_braille_char(pixel_block));
+
+            res.push(u32::from(char));
+        }
+
+        res
     }
 
-    /// Merge another canvas with the current canvas.
+    /// Render canvas, trimmed to its content.
     ///
-    /// The other canvas is merged with the current canvas. That is,
-    /// pixels that are turned on get draw, but those that are off are
-    /// ignored.
+    /// This is the same as `to_string()`, but only the output cells
+    /// within the bounding box of the content (lit pixels and text) are
+    /// rendered. Blank rows and columns around the content are left
+    /// out, so the string is tight.
     ///
-    /// Note: Inverted mode has no effect here, this is a low level
-    /// copy-paste.
+    /// An empty canvas renders to an empty string.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use textcanvas::TextCanvas;
     ///
-    /// let mut canvas = TextCanvas::new(15, 5);
-    /// canvas.stroke_line(0, 0, canvas.w(), canvas.h());
-    /// canvas.stroke_line(0, canvas.h(), canvas.w(), 0);
-    ///
-    /// let mut overlay = TextCanvas::new(7, 3);
-    /// overlay.frame();
+    /// let mut canvas = TextCanvas::new(7, 3);
     ///
-    /// canvas.merge_canvas(&overlay, 8, 4);
+    /// canvas.fill_rect(4, 4, 6, 4);
     ///
-    /// assert_eq!(
-    ///     canvas.to_string(),
-    ///     "\
-    /// ⠑⠢⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠔⠊
-    /// ⠀⠀⠀⠑⡯⣉⠉⠉⠉⣉⢽⠊⠀⠀⠀
-    /// ⠀⠀⠀⠀⡇⠀⡱⠶⢎⠀⢸⠀⠀⠀⠀
-    /// ⠀⠀⠀⡠⣗⣉⣀⣀⣀⣉⣺⢄⠀⠀⠀
-    /// ⡠⠔⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠢⢄
-    /// "
-    /// );
+    /// assert_eq!(canvas.render_trimmed(), "⣿⣿⣿\n");
     /// ```
-    pub fn merge_canvas(&mut self, canvas: &Self, dx: i32, dy: i32) {
-        self.draw_canvas_onto_canvas(canvas, dx, dy, true);
-    }
-
-    fn draw_canvas_onto_canvas(&mut self, canvas: &Self, dx: i32, dy: i32, merge: bool) {
-        if !self.is_colorized() && canvas.is_colorized() {
-            self.init_color_buffer();
-        }
-
-        if !self.is_textual() && canvas.is_textual() {
-            self.init_text_buffer();
+    #[must_use]
+    pub fn render_trimmed(&self) -> String {
+        // Bounds of lit pixels, converted to output cells.
+        let mut bounds = self
+            .content_bounds()
+            .map(|(x, y, width, height)| (x / 2, y / 4, (x + width - 1) / 2, (y + height - 1) / 4));
+
+        // Extend to text.
+        for y in 0..self.output.height() {
+            for x in 0..self.output.width() {
+                if !self.has_text_at(x, y) {
+                    continue;
+                }
+                bounds = Some(match bounds {
+                    None => (x, y, x, y),
+                    Some((min_x, min_y, max_x, max_y)) => (
+                        cmp::min(min_x, x),
+                        cmp::min(min_y, y),
+                        cmp::max(max_x, x),
+                        cmp::max(max_y, y),
+                    ),
+                });
+            }
         }
 
-        // We cannot convert `offset_x` and `offset_y` to `usize` yet,
-        // because negative values are possible here (you can draw the
-        // canvas out of bounds). The conversion must be made at the
-        // pixel level, because we can't draw pixels out of bound.
-        let (offset_x, offset_y) = (dx, dy);
-
-        for (x, y) in canvas.uiter_buffer() {
-            // Source coordinates of pixel.
-            // x, y
+        let Some((min_x, min_y, max_x, max_y)) = bounds else {
+            return String::new();
+        };
 
-            // Destination coordinates of pixel.
-            let (dx, dy) = (offset_x + to_i32!(x), offset_y + to_i32!(y));
-            if !self.check_screen_bounds(dx, dy) {
-                continue;
+        let mut res = String::new();
+        let mut active = String::new();
+        for y in to_usize!(min_y)..=to_usize!(max_y) {
+            for x in to_usize!(min_x)..=to_usize!(max_x) {
+                let text_char = self.get_text_char(x, y);
+                self.push_cell(
+                    &mut res,
+                    &mut active,
+                    x,
+                    y,
+                    &text_char,
+                    self.get_pixel_block(x, y),
+                );
             }
-            // Here we are safe. If a pixel is within the screen bounds,
-            // it can safely be converted to buffer coordinates in
-            // `usize`. And, we can also safely convert it to output
-            // coordinates (`x / 2`, `y / 4`) later.
-            let (dx, dy) = (to_usize!(dx), to_usize!(dy));
+            Self::close_color(&mut res, &mut active);
+            res.push('\n');
+        }
+        res
+    }
 
-            // Pixels.
-            let pixel = canvas.buffer[y][x];
-            // In merge mode, only draw if pixel is on, treating off
-            // pixels as transparent.
-            if !merge || pixel == ON {
-                self.buffer[dy][dx] = pixel;
+    /// Render pixels (and text) into `buf`.
+    ///
+    /// Adjacent cells of the same color share a single escape sequence.
+    /// The color is only changed when it differs from the previous
+    /// cell, and it is reset at the end of each line, so lines can be
+    /// printed independently.
+    fn render_layers_into(&self, buf: &mut String, with_text: bool) {
+        buf.clear();
 
-                if canvas.is_colorized() {
-                    let color = canvas.color_buffer[y / 4][x / 2].clone();
-                    self.color_buffer[dy / 4][dx / 2] = color;
-                }
-            }
+        // SGR sequence currently in effect (empty if none).
+        let mut active = String::new();
 
-            // Text.
-            if canvas.is_textual() {
-                // Text buffer has color embedded into the String.
-                let text = canvas.text_buffer[y / 4][x / 2].clone();
+        for (i, pixel_block) in self.iter_buffer_by_blocks_lrtb().enumerate() {
+            let x = i % self.output.uwidth();
+            let y = i / self.output.uwidth();
 
-                if !merge || !text.is_empty() {
-                    self.text_buffer[dy / 4][dx / 2] = text;
-                }
+            let text_char = if with_text {
+                self.get_text_char(x, y)
+            } else {
+                String::new()
+            };
+            self.push_cell(buf, &mut active, x, y, &text_char, pixel_block);
+
+            // If end of line is reached, go to next line.
+            if (i + 1) % self.output.uwidth() == 0 {
+                Self::close_color(buf, &mut active);
+                buf.push('\n');
             }
         }
     }
-}
 
-impl Default for TextCanvas {
-    fn default() -> Self {
-        let (width, heigt) = Self::get_default_size();
-        Self::new(width, heigt)
+    /// Push a single output cell, re-using the active color if possible.
+    fn push_cell(
+        &self,
+        buf: &mut String,
+        active: &mut String,
+        x: usize,
+        y: usize,
+        text_char: &str,
+        pixel_block: PixelBlock,
+    ) {
+        // Pixel layer.
+        if text_char.is_empty() {
+            let braille_char = Self::pixel_block_to_braille_char(pixel_block);
+            let open = self.pixel_char_color(x, y, braille_char).open();
+            Self::push_colored(buf, active, &open, braille_char.encode_utf8(&mut [0; 4]));
+        }
+        // Text layer.
+        else if let Some((open, glyph)) = Self::split_colored_text(text_char) {
+            Self::push_colored(buf, active, open, glyph);
+        }
+        // Other escape sequences (e.g., hyperlinks), pushed as-is.
+        else {
+            Self::close_color(buf, active);
+            buf.push_str(text_char);
+        }
     }
-}
 
-impl fmt::Display for TextCanvas {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.render())
+    fn push_colored(buf: &mut String, active: &mut String, open: &str, glyph: &str) {
+        if open != active {
+            Self::close_color(buf, active);
+            buf.push_str(open);
+            open.clone_into(active);
+        }
+        buf.push_str(glyph);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[allow(clippy::explicit_counter_loop)]
-    fn stroke_line_accros_canvas(canvas: &mut TextCanvas) {
-        let mut y = 0;
-        for x in 0..canvas.screen.width() {
-            canvas.set_pixel(x, y, true);
-            y += 1;
+    fn close_color(buf: &mut String, active: &mut String) {
+        if !active.is_empty() {
+            buf.push_str(Color::reset());
+            active.clear();
         }
     }
 
-    // Errors.
-
-    #[test]
-    fn textcanvaserror_format() {
-        let error = TextCanvasError("an error has occurred");
-
-        assert_eq!(error.to_string(), "an error has occurred");
+    /// Split text formatted by [`Color::format()`] into SGR and text.
+    ///
+    /// Plain text has an empty SGR. Returns `None` if the text contains
+    /// any other escape sequence.
+    fn split_colored_text(text: &str) -> Option<(&str, &str)> {
+        if !text.contains('\x1b') {
+            return Some(("", text));
+        }
+        let inner = text.strip_suffix(Color::reset())?;
+        let end = inner.strip_prefix("\x1b[")?.find('m')? + "\x1b[m".len();
+        let (open, glyph) = inner.split_at(end);
+        if glyph.contains('\x1b') {
+            return None;
+        }
+        Some((open, glyph))
     }
 
-    // Surface.
+    /// Render canvas as plain ASCII, for previews and debugging.
+    ///
+    /// Each output cell (2×4 pixels) becomes a single ASCII character,
+    /// chosen by the number of pixels that are turned on in the cell,
+    /// from ` ` (empty) to `@` (full), along the ramp ` .:-=+*#@`.
+    ///
+    /// Color and text are ignored, only the pixels matter. This is
+    /// less precise than Braille, but it is easier to read in diffs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(5, 3);
+    ///
+    /// canvas.fill_rect(2, 4, 6, 4);
+    /// canvas.stroke_line(0, 11, canvas.w(), 11);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_ascii_preview(),
+    ///     concat!(
+    ///         "     \n",
+    ///         " @@@ \n",
+    ///         ":::::\n",
+    ///     )
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_ascii_preview(&self) -> String {
+        // One char per number of pixels turned on (0 to 8).
+        const RAMP: [char; 9] = [' ', '.', ':', '-', '=', '+', '*', '#', '@'];
+
+        let nb_output_chars = (self.output.uwidth() + 1) * self.output.uheight();
+        let mut res = String::with_capacity(nb_output_chars);
+
+        for (i, pixel_block) in self.iter_buffer_by_blocks_lrtb().enumerate() {
+            let nb_pixels_on = pixel_block.iter().flatten().filter(|&&pixel| pixel).count();
+            res.push(RAMP[nb_pixels_on]);
+
+            if (i + 1) % self.output.uwidth() == 0 {
+                res.push('\n');
+            }
+        }
+
+        res
+    }
+
+    /// Get the raw Braille code points of the canvas.
+    ///
+    /// Returns one `u32` per output cell (rows of columns), in the
+    /// `0x2800`–`0x28FF` range. Color and text are ignored, only the
+    /// pixels matter.
+    ///
+    /// This is useful to feed the canvas into another rendering
+    /// pipeline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(2, 1);
+    ///
+    /// canvas.fill_rect(0, 0, 2, 2);
+    ///
+    /// assert_eq!(canvas.to_braille_codes(), [[0x281B, 0x2800]]);
+    /// ```
+    #[must_use]
+    pub fn to_braille_codes(&self) -> Vec<Vec<u32>> {
+        let mut res = Vec::with_capacity(self.output.uheight());
+        let mut row = Vec::with_capacity(self.output.uwidth());
+
+        for pixel_block in self.iter_buffer_by_blocks_lrtb() {
+            let braille_char = Self::pixel_block_to_braille_char(pixel_block);
+            row.push(u32::from(braille_char));
+
+            if row.len() == self.output.uwidth() {
+                res.push(row);
+                row = Vec::with_capacity(self.output.uwidth());
+            }
+        }
+
+        res
+    }
+
+    /// Serialize the pixel buffer into compact binary form.
+    ///
+    /// The format is a 4-byte header, with the width and height of the
+    /// canvas (in output characters, as `u16` little-endian), followed
+    /// by the pixel buffer, bit-packed row by row (most significant bit
+    /// first). A cell is 8 pixels, so the buffer takes exactly one byte
+    /// per output cell.
+    ///
+    /// Only pixels are serialized, color and text are not. Restore the
+    /// canvas with [`from_packed_bytes()`](TextCanvas::from_packed_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(2, 1);
+    ///
+    /// canvas.set_pixel(0, 0, true);
+    /// canvas.set_pixel(3, 3, true);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_packed_bytes(),
+    ///     [2, 0, 1, 0, 0b1000_0000, 0b0000_0001]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_packed_bytes(&self) -> Vec<u8> {
+        let nb_cells = self.output.uwidth() * self.output.uheight();
+        let mut res = Vec::with_capacity(4 + nb_cells);
+
+        // Size is always within `u16` (see `new()`).
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let (width, height) = (self.output.width() as u16, self.output.height() as u16);
+        res.extend(width.to_le_bytes());
+        res.extend(height.to_le_bytes());
+
+        for chunk in self.buffer.concat().chunks(8) {
+            let byte = chunk
+                .iter()
+                .enumerate()
+                .filter(|(_, &pixel)| pixel)
+                .fold(0_u8, |byte, (i, _)| byte | (0b1000_0000 >> i));
+            res.push(byte);
+        }
+
+        res
+    }
+
+    /// Restore a canvas serialized with
+    /// [`to_packed_bytes()`](TextCanvas::to_packed_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    /// canvas.stroke_circle(canvas.cx(), canvas.cy(), 7);
+    ///
+    /// let bytes = canvas.to_packed_bytes();
+    /// let restored = TextCanvas::from_packed_bytes(&bytes).unwrap();
+    ///
+    /// assert_eq!(restored.to_string(), canvas.to_string());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the header is missing or holds an invalid size, or if the
+    /// length of the pixel buffer does not match the size.
+    pub fn from_packed_bytes(bytes: &[u8]) -> Result<Self, TextCanvasError> {
+        let (Some(header), Some(pixels)) = (bytes.get(..4), bytes.get(4..)) else {
+            return Err(TextCanvasError("packed bytes are missing the header"));
+        };
+
+        let width = i32::from(u16::from_le_bytes([header[0], header[1]]));
+        let height = i32::from(u16::from_le_bytes([header[2], header[3]]));
+        if !Self::check_canvas_size(width, height) {
+            return Err(TextCanvasError("invalid canvas size in packed bytes"));
+        }
+        if pixels.len() != to_usize!(width) * to_usize!(height) {
+            return Err(TextCanvasError("packed bytes do not match canvas size"));
+        }
+
+        let mut canvas = Self::new(width, height);
+        let screen_width = canvas.screen.uwidth();
+        for (i, byte) in pixels.iter().enumerate() {
+            for bit in 0..8 {
+                let index = i * 8 + bit;
+                let (x, y) = (index % screen_width, index / screen_width);
+                canvas.buffer[y][x] = byte & (0b1000_0000 >> bit) != 0;
+            }
+        }
+
+        Ok(canvas)
+    }
+
+    /// Render the text layer only.
+    ///
+    /// Pixels are ignored, and cells without text are rendered as
+    /// spaces. Text keeps its colors. If the canvas has no text, this
+    /// is all spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(5, 2);
+    ///
+    /// canvas.fill();
+    /// canvas.draw_text("foo", 1, 1);
+    ///
+    /// assert_eq!(canvas.text_to_string(), "     \n foo \n");
+    /// ```
+    #[must_use]
+    pub fn text_to_string(&self) -> String {
+        let nb_output_chars = (self.output.uwidth() + 1) * self.output.uheight();
+        let mut res = String::with_capacity(nb_output_chars);
+
+        for y in 0..self.output.uheight() {
+            for x in 0..self.output.uwidth() {
+                let text_char = self.get_text_char(x, y);
+                if text_char.is_empty() {
+                    res.push(' ');
+                } else {
+                    res.push_str(&text_char);
+                }
+            }
+            res.push('\n');
+        }
+
+        res
+    }
+
+    /// Render only the cells that differ from a previous frame.
+    ///
+    /// Each changed cell is emitted as a cursor move escape sequence
+    /// (`ESC[⟨row⟩;⟨col⟩H`, 1-based) followed by the rendered cell.
+    /// Unchanged cells are skipped entirely. Printing the result over
+    /// the previous frame yields the current frame.
+    ///
+    /// If the two canvases are not the same size, this falls back to a
+    /// full render (see [`render_into()`](TextCanvas::render_into)).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let previous = TextCanvas::new(3, 2);
+    ///
+    /// let mut canvas = TextCanvas::new(3, 2);
+    /// canvas.set_pixel(2, 4, true);
+    ///
+    /// assert_eq!(canvas.render_delta(&previous), "\x1b[2;2H⠁");
+    /// ```
+    #[must_use]
+    pub fn render_delta(&self, previous: &Self) -> String {
+        if self.output.width() != previous.output.width()
+            || self.output.height() != previous.output.height()
+        {
+            return self.render().into_owned();
+        }
+
+        let mut res = String::new();
+
+        // Cells are rendered standalone on purpose (unlike `render()`,
+        // which coalesces colors). Each changed cell is written at its
+        // own position, so it must carry its own color.
+        for y in 0..self.output.uheight() {
+            for x in 0..self.output.uwidth() {
+                let cell = self.render_cell(x, y);
+                if cell == previous.render_cell(x, y) {
+                    continue;
+                }
+                let (row, column) = (y + 1, x + 1);
+                write!(res, "\x1b[{row};{column}H{cell}").unwrap_or(());
+            }
+        }
+
+        res
+    }
+
+    /// Iterate over all rendered output cells.
+    ///
+    /// Yields the X/Y coordinates of each output cell, left-right,
+    /// top-bottom, along with the rendered cell (text or Braille
+    /// character, with color).
+    ///
+    /// Cells are standalone: each one opens and resets its own color.
+    /// This differs from `to_string()`, in which adjacent cells of the
+    /// same color share a single escape sequence. Concatenating the
+    /// cells gives the same visual output, but not the same string.
+    ///
+    /// This is useful to walk the final output programmatically, e.g.,
+    /// for custom compositing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(2, 1);
+    /// canvas.set_pixel(2, 0, true);
+    /// canvas.draw_text("a", 0, 0);
+    ///
+    /// let cells: Vec<(i32, i32, String)> = canvas.iter_cells().collect();
+    ///
+    /// assert_eq!(cells, [(0, 0, "a".to_string()), (1, 0, "⠁".to_string())]);
+    /// ```
+    pub fn iter_cells(&self) -> impl Iterator<Item = (i32, i32, String)> + '_ {
+        (0..self.output.uheight()).flat_map(move |y| {
+            (0..self.output.uwidth()).map(move |x| (to_i32!(x), to_i32!(y), self.render_cell(x, y)))
+        })
+    }
+
+    /// Render all output cells, with text and color (rows of columns).
+    fn render_cells(&self) -> Vec<Vec<String>> {
+        (0..self.output.uheight())
+            .map(|y| {
+                (0..self.output.uwidth())
+                    .map(|x| self.render_cell(x, y))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Render a single output cell, with text and color.
+    fn render_cell(&self, x: usize, y: usize) -> String {
+        let text_char = self.get_text_char(x, y);
+        if !text_char.is_empty() {
+            return text_char;
+        }
+        let pixel_block = self.get_pixel_block(x, y);
+        let braille_char = Self::pixel_block_to_braille_char(pixel_block);
+        self.color_pixel_char(x, y, braille_char)
+    }
+
+    /// Get the pixel block of an output cell.
+    fn get_pixel_block(&self, x: usize, y: usize) -> PixelBlock {
+        let (x, y) = (x * 2, y * 4);
+        let mut block = [[OFF; 2]; 4];
+        for (dy, row) in block.iter_mut().enumerate() {
+            row.copy_from_slice(&self.buffer[y + dy][x..x + 2]);
+        }
+        block
+    }
+
+    fn get_text_char(&self, x: usize, y: usize) -> String {
+        if self.is_textual() {
+            return self.text_buffer[y][x].clone();
+        }
+        String::new()
+    }
+
+    fn pixel_block_to_braille_char(pixel_block: PixelBlock) -> BrailleChar {
+        let mut braille_char = BRAILLE_UNICODE_0;
+        // Iterate over individual pixels to turn them on or off.
+        for y in 0..4 {
+            for x in 0..2 {
+                if pixel_block[y][x] == ON {
+                    braille_char += BRAILLE_UNICODE_OFFSET_MAP[y][x];
+                }
+            }
+        }
+        // Convert Unicode integer value to char.
+        char::from_u32(braille_char).expect("from valid hardcoded values")
+    }
+
+    fn color_pixel_char(&self, x: usize, y: usize, pixel_char: char) -> String {
+        let color = self.pixel_char_color(x, y, pixel_char);
+        let pixel_char = String::from(pixel_char);
+        if color.is_empty() {
+            return pixel_char;
+        }
+        color.format(&pixel_char)
+    }
+
+    fn pixel_char_color(&self, x: usize, y: usize, pixel_char: char) -> Color {
+        let is_lit = u32::from(pixel_char) != BRAILLE_UNICODE_0;
+
+        let color = if self.is_colorized() && !self.color_buffer[y][x].is_empty() {
+            self.color_buffer[y][x].clone()
+        } else if is_lit {
+            self.default_pixel_color.clone()
+        } else {
+            Color::new()
+        };
+
+        if self.background.is_empty() || color.has_background() {
+            return color;
+        }
+        color.with_background_of(&self.background)
+    }
+
+    fn iter_buffer_by_blocks_lrtb(&self) -> IterPixelBufferByBlocksLRTB<'_> {
+        IterPixelBufferByBlocksLRTB::new(&self.buffer, &self.screen)
+    }
+
+    /// Iterate over all cells of the pixel buffer.
+    ///
+    /// Yields all X/Y coordinates, left-right, top-bottom.
+    ///
+    /// Values are `i32`, this is meant to be used with the public API.
+    #[must_use]
+    pub fn iter_buffer(&self) -> IterPixelBuffer<i32> {
+        IterPixelBuffer::<i32>::new(&self.buffer)
+    }
+
+    /// Iterate over all cells of the pixel buffer.
+    ///
+    /// Yields all X/Y coordinates, left-right, top-bottom.
+    ///
+    /// Values are `usize`, this is meant to index directly into the
+    /// buffer.
+    #[must_use]
+    pub fn uiter_buffer(&self) -> IterPixelBuffer<usize> {
+        IterPixelBuffer::<usize>::new(&self.buffer)
+    }
+}
+
+/// Implementation of drawing primitives.
+impl TextCanvas {
+    /// Stroke line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_line(5, 5, 25, 15);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠐⠤⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠉⠒⠤⣀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠒⠤⣀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) {
+        self.bresenham_line(x1, y1, x2, y2);
+    }
+
+    /// Stroke line, smoothed.
+    ///
+    /// This uses an adaptation of Xiaolin Wu's line algorithm. Braille
+    /// dots can only be _on_ or _off_, so instead of blending two
+    /// pixels, the dots on either side of the ideal line are lit when
+    /// the line passes close enough between them. This softens the
+    /// "steps" of shallow lines.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 2);
+    ///
+    /// canvas.stroke_line(0, 0, 29, 3);
+    /// canvas.stroke_line_smooth(0, 4, 29, 7);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠉⠉⠑⠒⠒⠒⠒⠢⠤⠤⠤⠤⢄⣀⣀
+    /// ⠉⠉⠛⠓⠒⠒⠲⠶⠦⠤⠤⢤⣤⣀⣀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_line_smooth(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) {
+        self.wu_line(x1, y1, x2, y2);
+    }
+
+    /// Stroke line, with a color that varies along the line.
+    ///
+    /// `f` receives the progress `t` along the line, in `[0; 1]` (0 is
+    /// the start point, 1 is the end point), and returns the color to
+    /// apply to the pixel. Since color is stored per output cell, the
+    /// last pixel drawn in a cell determines its color.
+    ///
+    /// The context color is restored once the line is drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(3, 1);
+    ///
+    /// // Red to blue.
+    /// canvas.stroke_line_colored(0, 0, canvas.w(), 0, |t| {
+    ///     let red = (255.0 * (1.0 - t)).round() as u8;
+    ///     let blue = (255.0 * t).round() as u8;
+    ///     Color::new().rgb(red, 0, blue).fix()
+    /// });
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// \x1b[0;38;2;204;0;51m⠉\x1b[0m\
+    /// \x1b[0;38;2;102;0;153m⠉\x1b[0m\
+    /// \x1b[0;38;2;0;0;255m⠉\x1b[0m
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_line_colored(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        f: impl Fn(f64) -> Color,
+    ) {
+        let context_color = self.color.clone();
+
+        let points = Self::compute_bresenham_line_points(x1, y1, x2, y2);
+        let last = points.len().saturating_sub(1);
+        for (i, (x, y)) in points.into_iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let t = if last == 0 {
+                0.0
+            } else {
+                i as f64 / last as f64
+            };
+            self.set_color(&f(t));
+            self.set_pixel(x, y, true);
+        }
+
+        self.color = context_color;
+    }
+
+    /// Stroke dashed line.
+    ///
+    /// The line alternates between `dash` pixels _on_ and `gap` pixels
+    /// left untouched, starting with a dash at `(x1, y1)`. If `dash` is
+    /// < 1, nothing is drawn. If `gap` is < 1, the line is solid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(7, 1);
+    ///
+    /// canvas.stroke_line_dashed(0, 0, canvas.w(), 0, 3, 2);
+    ///
+    /// assert_eq!(canvas.to_string(), "⠉⠁⠈⠉⠀⠉⠁\n");
+    /// ```
+    pub fn stroke_line_dashed(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, dash: i32, gap: i32) {
+        let points = Self::compute_bresenham_line_points(x1, y1, x2, y2);
+        self.stroke_points_dashed(points, dash, gap);
+    }
+
+    /// Turn on `dash` points out of every `dash + gap`, in order.
+    fn stroke_points_dashed(&mut self, points: Vec<(i32, i32)>, dash: i32, gap: i32) {
+        if dash < 1 {
+            return;
+        }
+        let period = to_usize!(dash + cmp::max(gap, 0));
+        let dash = to_usize!(dash);
+
+        for (i, (x, y)) in points.into_iter().enumerate() {
+            if i % period < dash {
+                self.set_pixel(x, y, true);
+            }
+        }
+    }
+
+    /// Stroke arrow, from `(x1, y1)` to `(x2, y2)`.
+    ///
+    /// The arrowhead is drawn at `(x2, y2)`. Its size depends on the
+    /// length of the arrow, up to 3 pixels. An arrow of length 0 is a
+    /// single pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(7, 2);
+    ///
+    /// canvas.stroke_arrow(1, 3, 12, 3);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⢀⣀⣀⣀⣠⣄⡀
+    /// ⠀⠀⠀⠀⠈⠁⠀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_arrow(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) {
+        const MAX_HEAD_LENGTH: f64 = 3.0;
+        const HEAD_ANGLE: f64 = std::f64::consts::PI / 6.0;
+
+        self.stroke_line(x1, y1, x2, y2);
+
+        let dx = f64::from(x2 - x1);
+        let dy = f64::from(y2 - y1);
+        let length = dx.hypot(dy);
+        if length == 0.0 {
+            return;
+        }
+
+        let head_length = (length / 2.0).min(MAX_HEAD_LENGTH);
+        let (ux, uy) = (dx / length, dy / length);
+        let back = head_length * HEAD_ANGLE.cos();
+        let side = head_length * HEAD_ANGLE.sin();
+
+        // Offsets are rounded relative to the tip, so that the head
+        // stays symmetric.
+        for sign in [-1.0, 1.0] {
+            #[allow(clippy::cast_possible_truncation)]
+            let (ox, oy) = (
+                (-ux * back - sign * uy * side).round() as i32,
+                (-uy * back + sign * ux * side).round() as i32,
+            );
+            self.stroke_line(x2, y2, x2 + ox, y2 + oy);
+        }
+    }
+
+    /// Stroke tick marks along a line.
+    ///
+    /// `count` ticks are evenly spaced along the line from `(x1, y1)`
+    /// to `(x2, y2)`, the first and last ones being at the ends (a
+    /// single tick is placed in the middle). Ticks are perpendicular to
+    /// the line, `length` pixels long, and centered on it.
+    ///
+    /// The line itself is not drawn, combine this with
+    /// [`stroke_line()`](TextCanvas::stroke_line) for rulers or
+    /// gauges. A line of length 0 has vertical ticks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_line(2, 2, 26, 18);
+    /// canvas.stroke_ticks(2, 2, 26, 18, 5, 5);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⢀⢎⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠈⠀⠈⢒⢎⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠈⠀⠈⢒⢎⡀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠈⠀⠈⢒⢎⡀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠀⠈⢒⠎⠀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_ticks(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, count: usize, length: i32) {
+        if count == 0 || length < 1 {
+            return;
+        }
+
+        let (dx, dy) = (f64::from(x2 - x1), f64::from(y2 - y1));
+        let line_length = dx.hypot(dy);
+        // Unit vector, perpendicular to the line.
+        let (nx, ny) = if line_length == 0.0 {
+            (0.0, 1.0)
+        } else {
+            (-dy / line_length, dx / line_length)
+        };
+
+        let half = f64::from(length - 1) / 2.0;
+        for i in 0..count {
+            #[allow(clippy::cast_precision_loss)]
+            let t = if count == 1 {
+                0.5
+            } else {
+                i as f64 / (count - 1) as f64
+            };
+            let (x, y) = (f64::from(x1) + dx * t, f64::from(y1) + dy * t);
+
+            #[allow(clippy::cast_possible_truncation)]
+            let (from, to) = (
+                (
+                    (x - nx * half).round() as i32,
+                    (y - ny * half).round() as i32,
+                ),
+                (
+                    (x + nx * half).round() as i32,
+                    (y + ny * half).round() as i32,
+                ),
+            );
+            self.stroke_line(from.0, from.1, to.0, to.1);
+        }
+    }
+
+    /// Draw crosshair.
+    ///
+    /// Strokes a full-height vertical line and a full-width horizontal
+    /// line through `(x, y)`. This is typically used in inverted mode,
+    /// or with a color, to mark a pointer in interactive tools.
+    ///
+    /// If `(x, y)` is out of bounds, only the visible portions are
+    /// drawn (e.g., only the vertical line if `y` is off-screen).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.draw_crosshair(canvas.cx(), canvas.cy());
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+    /// ⠤⠤⠤⠤⠤⠤⠤⢼⠤⠤⠤⠤⠤⠤⠤
+    /// ⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn draw_crosshair(&mut self, x: i32, y: i32) {
+        if x >= 0 && x < self.screen.width() {
+            self.stroke_line(x, 0, x, self.h());
+        }
+        if y >= 0 && y < self.screen.height() {
+            self.stroke_line(0, y, self.w(), y);
+        }
+    }
+
+    /// Stroke polyline, with thick lines.
+    ///
+    /// Consecutive points are joined by segments `thickness` pixels
+    /// wide, centered on the line. `join` controls how segments meet at
+    /// corners (see [`LineJoin`]). The ends of the polyline are cut
+    /// square, at the first and last points.
+    ///
+    /// Lines of `thickness` 1 are stroked like with
+    /// [`stroke_line()`](TextCanvas::stroke_line), and nothing is
+    /// drawn if `thickness < 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{LineJoin, TextCanvas};
+    ///
+    /// let corner = [(4, 18), (15, 5), (26, 18)];
+    ///
+    /// let mut round = TextCanvas::new(15, 5);
+    /// round.stroke_polyline_thick(&corner, 7, LineJoin::Round);
+    ///
+    /// assert_eq!(
+    ///     round.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⢀⣤⣄⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⣴⣿⣿⣿⣷⡄⠀⠀⠀⠀
+    /// ⠀⠀⠀⣠⣾⣿⣿⡟⣿⣿⣿⣦⡀⠀⠀
+    /// ⠀⢀⣼⣿⣿⡿⠋⠀⠈⠻⣿⣿⣿⣄⠀
+    /// ⠀⠙⢿⣿⠟⠀⠀⠀⠀⠀⠘⢿⣿⠟⠁
+    /// "
+    /// );
+    ///
+    /// // The corner is cut flat.
+    /// let mut bevel = TextCanvas::new(15, 5);
+    /// bevel.stroke_polyline_thick(&corner, 7, LineJoin::Bevel);
+    ///
+    /// assert_eq!(
+    ///     bevel.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⢀⣀⣀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⣴⣿⣿⣿⣷⡄⠀⠀⠀⠀
+    /// ⠀⠀⠀⣠⣾⣿⣿⡟⣿⣿⣿⣦⡀⠀⠀
+    /// ⠀⢀⣼⣿⣿⡿⠋⠀⠈⠻⣿⣿⣿⣄⠀
+    /// ⠀⠙⢿⣿⠟⠀⠀⠀⠀⠀⠘⢿⣿⠟⠁
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_polyline_thick(&mut self, points: &[(i32, i32)], thickness: i32, join: LineJoin) {
+        if thickness < 1 {
+            return;
+        }
+        if thickness == 1 {
+            for segment in points.windows(2) {
+                let ((x1, y1), (x2, y2)) = (segment[0], segment[1]);
+                self.stroke_line(x1, y1, x2, y2);
+            }
+            return;
+        }
+
+        let half = f64::from(thickness - 1) / 2.0;
+        let to_f64 = |(x, y): (i32, i32)| (f64::from(x), f64::from(y));
+        // Unit vector, perpendicular to the segment.
+        let normal = |(x1, y1): (f64, f64), (x2, y2): (f64, f64)| {
+            let (dx, dy) = (x2 - x1, y2 - y1);
+            let length = dx.hypot(dy);
+            if length == 0.0 {
+                (0.0, 0.0)
+            } else {
+                (-dy / length, dx / length)
+            }
+        };
+
+        for segment in points.windows(2) {
+            let (p1, p2) = (to_f64(segment[0]), to_f64(segment[1]));
+            let (nx, ny) = normal(p1, p2);
+            let (ox, oy) = (nx * half, ny * half);
+            self.fill_polygon_f64(&[
+                (p1.0 + ox, p1.1 + oy),
+                (p2.0 + ox, p2.1 + oy),
+                (p2.0 - ox, p2.1 - oy),
+                (p1.0 - ox, p1.1 - oy),
+            ]);
+        }
+
+        for corner in points.windows(3) {
+            let (p1, p2, p3) = (to_f64(corner[0]), to_f64(corner[1]), to_f64(corner[2]));
+            let (n1, n2) = (normal(p1, p2), normal(p2, p3));
+
+            // The outer side of the corner is opposite to the turn.
+            let turn = n1.0 * (p3.0 - p2.0) + n1.1 * (p3.1 - p2.1);
+            let side = if turn > 0.0 { -half } else { half };
+            let outer1 = (p2.0 + n1.0 * side, p2.1 + n1.1 * side);
+            let outer2 = (p2.0 + n2.0 * side, p2.1 + n2.1 * side);
+
+            // Inverse of the cosine of the half-angle between segments
+            // is the length of the miter, relative to `half`. Limit it
+            // to 4 (like SVG), or very sharp corners spike out.
+            let cos = n1.0 * n2.0 + n1.1 * n2.1;
+            let is_miter_too_long = 1.0 + cos < 2.0 / 16.0;
+
+            match join {
+                LineJoin::Round => {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let (x, y) = (p2.0.round() as i32, p2.1.round() as i32);
+                    self.fill_circle(x, y, thickness / 2);
+                }
+                LineJoin::Miter if !is_miter_too_long => {
+                    let scale = side / (1.0 + cos);
+                    let miter = (p2.0 + (n1.0 + n2.0) * scale, p2.1 + (n1.1 + n2.1) * scale);
+                    self.fill_polygon_f64(&[p2, outer1, miter, outer2]);
+                }
+                LineJoin::Miter | LineJoin::Bevel => {
+                    self.fill_polygon_f64(&[p2, outer1, outer2]);
+                }
+            }
+        }
+    }
+
+    /// Fill a convex polygon, given as floating point vertices.
+    fn fill_polygon_f64(&mut self, vertices: &[(f64, f64)]) {
+        #[allow(clippy::cast_possible_truncation)]
+        let vertices: Vec<(i32, i32)> = vertices
+            .iter()
+            .map(|&(x, y)| (x.round() as i32, y.round() as i32))
+            .collect();
+        // Fan of triangles, from the first vertex.
+        for pair in vertices[1..].windows(2) {
+            let ((x1, y1), (x2, y2), (x3, y3)) = (vertices[0], pair[0], pair[1]);
+            self.fill_triangle(x1, y1, x2, y2, x3, y3);
+        }
+    }
+
+    /// Compute the points of a line using Bresenham's line algorithm.
+    ///
+    /// Contrary to [`bresenham_line()`](TextCanvas::bresenham_line),
+    /// points are always ordered from `(x1, y1)` to `(x2, y2)`.
+    fn compute_bresenham_line_points(
+        mut x1: i32,
+        mut y1: i32,
+        x2: i32,
+        y2: i32,
+    ) -> Vec<(i32, i32)> {
+        let dx = (x2 - x1).abs();
+        let sx = if x1 < x2 { 1 } else { -1 };
+        let dy = -(y2 - y1).abs();
+        let sy = if y1 < y2 { 1 } else { -1 };
+        let mut error = dx + dy;
+
+        let mut points = Vec::with_capacity(to_usize!(cmp::max(dx, -dy) + 1));
+        loop {
+            points.push((x1, y1));
+            if x1 == x2 && y1 == y2 {
+                break;
+            }
+            let e2 = 2 * error;
+            if e2 >= dy {
+                error += dy;
+                x1 += sx;
+            }
+            if e2 <= dx {
+                error += dx;
+                y1 += sy;
+            }
+        }
+        points
+    }
+
+    /// Stroke line using a Braille-adapted Xiaolin Wu's algorithm.
+    fn wu_line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) {
+        // Minimum coverage for a dot to be lit.
+        const THRESHOLD: f64 = 1.0 / 3.0;
+
+        // Always iterate over the major axis.
+        let is_steep = (y2 - y1).abs() > (x2 - x1).abs();
+        let (mut x1, mut y1, mut x2, mut y2) = if is_steep {
+            (y1, x1, y2, x2)
+        } else {
+            (x1, y1, x2, y2)
+        };
+        if x1 > x2 {
+            (x1, x2) = (x2, x1);
+            (y1, y2) = (y2, y1);
+        }
+
+        let dx = x2 - x1;
+        let dy = y2 - y1;
+        let gradient = if dx == 0 {
+            0.0
+        } else {
+            f64::from(dy) / f64::from(dx)
+        };
+
+        for x in x1..=x2 {
+            let y = f64::from(y1) + gradient * f64::from(x - x1);
+            let y_floor = y.floor();
+            // Coverage of the dot at `y_floor + 1`, the one at `y_floor`
+            // gets the rest.
+            let coverage = y - y_floor;
+            #[allow(clippy::cast_possible_truncation)]
+            let y_floor = y_floor as i32;
+
+            for (y, coverage) in [(y_floor, 1.0 - coverage), (y_floor + 1, coverage)] {
+                if coverage < THRESHOLD {
+                    continue;
+                }
+                if is_steep {
+                    self.set_pixel(y, x, true);
+                } else {
+                    self.set_pixel(x, y, true);
+                }
+            }
+        }
+    }
+
+    /// Stroke line using Bresenham's line algorithm.
+    fn bresenham_line(&mut self, mut x1: i32, mut y1: i32, x2: i32, y2: i32) {
+        let dx = (x2 - x1).abs();
+        let sx = if x1 < x2 { 1 } else { -1 };
+        let dy = -(y2 - y1).abs();
+        let sy = if y1 < y2 { 1 } else { -1 };
+        let mut error = dx + dy;
+
+        // Treat vertical and horizontal lines as special cases.
+        if dx == 0 {
+            let x = x1;
+            let from_y = cmp::min(y1, y2);
+            let to_y = cmp::max(y1, y2);
+            for y in from_y..=to_y {
+                self.set_pixel(x, y, true);
+            }
+            return;
+        } else if dy == 0 {
+            let y = y1;
+            let from_x = cmp::min(x1, x2);
+            let to_x = cmp::max(x1, x2);
+            for x in from_x..=to_x {
+                self.set_pixel(x, y, true);
+            }
+            return;
+        }
+
+        #[cfg(not(tarpaulin_include))]
+        loop {
+            self.set_pixel(x1, y1, true);
+            if x1 == x2 && y1 == y2 {
+                break;
+            }
+            let e2 = 2 * error;
+            if e2 >= dy {
+                if x1 == x2 {
+                    break;
+                }
+                error += dy;
+                x1 += sx;
+            }
+            if e2 <= dx {
+                if y1 == y2 {
+                    break;
+                }
+                error += dx;
+                y1 += sy;
+            }
+        }
+    }
+
+    /// Stroke rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_rect(5, 5, 20, 10);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⢰⠒⠒⠒⠒⠒⠒⠒⠒⠒⡆⠀⠀
+    /// ⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀
+    /// ⠀⠀⠸⠤⠤⠤⠤⠤⠤⠤⠤⠤⠇⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_rect(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        let (width, height) = (width - 1, height - 1);
+        self.stroke_line(x, y, x + width, y);
+        self.stroke_line(x + width, y, x + width, y + height);
+        self.stroke_line(x + width, y + height, x, y + height);
+        self.stroke_line(x, y + height, x, y);
+    }
+
+    /// Stroke dashed rectangle.
+    ///
+    /// The perimeter is walked clockwise, starting at the top-left
+    /// corner, and the dash pattern continues across corners (see
+    /// [`stroke_line_dashed()`](TextCanvas::stroke_line_dashed) for
+    /// `dash` and `gap`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_rect_dashed(5, 5, 20, 10, 2, 2);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠐⠂⠐⠂⠐⠂⠐⠂⠐⠂⡄⠀⠀
+    /// ⠀⠀⠘⠀⠀⠀⠀⠀⠀⠀⠀⠀⡄⠀⠀
+    /// ⠀⠀⠘⠠⠄⠠⠄⠠⠄⠠⠄⠠⠄⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_rect_dashed(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        dash: i32,
+        gap: i32,
+    ) {
+        let (width, height) = (width - 1, height - 1);
+        let corners = [
+            (x, y),
+            (x + width, y),
+            (x + width, y + height),
+            (x, y + height),
+            (x, y),
+        ];
+
+        let mut points = vec![(x, y)];
+        for edge in corners.windows(2) {
+            let (from, to) = (edge[0], edge[1]);
+            // The first point of each edge is the last of the previous.
+            points.extend(
+                Self::compute_bresenham_line_points(from.0, from.1, to.0, to.1)
+                    .into_iter()
+                    .skip(1),
+            );
+        }
+        points.pop(); // Back to the start.
+
+        self.stroke_points_dashed(points, dash, gap);
+    }
+
+    /// Draw a border around the canvas.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.frame();
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⡏⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⢹
+    /// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+    /// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+    /// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+    /// ⣇⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣸
+    /// "
+    /// );
+    /// ```
+    pub fn frame(&mut self) {
+        self.stroke_rect(0, 0, self.screen.width(), self.screen.height());
+    }
+
+    /// Draw a border around the canvas, with box-drawing characters.
+    ///
+    /// Contrary to [`frame()`](TextCanvas::frame), which strokes
+    /// pixels, this writes characters into the text buffer, on the
+    /// outermost rows and columns of the output. This renders crisper
+    /// than Braille for rectangular panels, but it hides the pixels
+    /// underneath.
+    ///
+    /// Note: `set_color()` applies to the border, like for any text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{BorderStyle, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.frame_with_text_border(BorderStyle::Single);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ┌─────────────┐
+    /// │⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀│
+    /// │⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀│
+    /// │⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀│
+    /// └─────────────┘
+    /// "
+    /// );
+    /// ```
+    pub fn frame_with_text_border(&mut self, style: BorderStyle) {
+        let (top_left, top_right, bottom_left, bottom_right, horizontal, vertical) = style.chars();
+
+        let (right, bottom) = (self.output.width() - 1, self.output.height() - 1);
+
+        let horizontal = String::from(horizontal).repeat(self.output.uwidth());
+        let vertical = String::from(vertical).repeat(self.output.uheight());
+
+        self.draw_text(&horizontal, 0, 0);
+        self.draw_text(&horizontal, 0, bottom);
+        self.draw_text_vertical(&vertical, 0, 0);
+        self.draw_text_vertical(&vertical, right, 0);
+
+        // Corners last, so they take precedence.
+        self.draw_text(&String::from(top_left), 0, 0);
+        self.draw_text(&String::from(top_right), right, 0);
+        self.draw_text(&String::from(bottom_left), 0, bottom);
+        self.draw_text(&String::from(bottom_right), right, bottom);
+    }
+
+    /// Fill rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.fill_rect(5, 5, 20, 10);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⢰⣶⣶⣶⣶⣶⣶⣶⣶⣶⡆⠀⠀
+    /// ⠀⠀⢸⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇⠀⠀
+    /// ⠀⠀⠸⠿⠿⠿⠿⠿⠿⠿⠿⠿⠇⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        for y in y..y + height {
+            self.stroke_line(x, y, x + width - 1, y);
+        }
+    }
+
+    /// Fill rectangle with a repeating pattern.
+    ///
+    /// `pattern` is tiled across the rectangle, starting from its
+    /// top-left corner. Pixels are turned _on_ where the pattern is
+    /// `true`, and left untouched where it is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let checkerboard = [[true, false], [false, true]];
+    /// canvas.fill_rect_stipple(5, 5, 20, 10, &checkerboard);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⢐⢔⢔⢔⢔⢔⢔⢔⢔⢔⠄⠀⠀
+    /// ⠀⠀⢐⢕⢕⢕⢕⢕⢕⢕⢕⢕⠅⠀⠀
+    /// ⠀⠀⠐⠕⠕⠕⠕⠕⠕⠕⠕⠕⠅⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn fill_rect_stipple<const N: usize, const M: usize>(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        pattern: &[[bool; N]; M],
+    ) {
+        if N == 0 || M == 0 {
+            return;
+        }
+
+        for dy in 0..cmp::max(height, 0) {
+            let row = &pattern[to_usize!(dy) % M];
+            for dx in 0..cmp::max(width, 0) {
+                if row[to_usize!(dx) % N] {
+                    self.set_pixel(x + dx, y + dy, true);
+                }
+            }
+        }
+    }
+
+    /// Fill rectangle with a color gradient.
+    ///
+    /// Color is interpolated with [`Color::lerp_rgb()`], from `from` to
+    /// `to`, in the given direction. Since color is stored per output
+    /// cell, the gradient is computed at the cell level: the first row
+    /// or column of cells gets `from`, the last one gets `to`.
+    ///
+    /// The context color is restored once the rectangle is filled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, GradientDir, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(3, 1);
+    /// let red = Color::new().rgb(255, 0, 0).fix();
+    /// let blue = Color::new().rgb(0, 0, 255).fix();
+    ///
+    /// canvas.fill_rect_gradient(0, 0, 6, 4, &red, &blue, GradientDir::Horizontal);
+    ///
+    /// assert_eq!(canvas.color_buffer[0][0], red);
+    /// assert_eq!(canvas.color_buffer[0][1], Color::new().rgb(128, 0, 128).fix());
+    /// assert_eq!(canvas.color_buffer[0][2], blue);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_rect_gradient(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        from: &Color,
+        to: &Color,
+        direction: GradientDir,
+    ) {
+        if width < 1 || height < 1 {
+            return;
+        }
+
+        let context_color = self.color.clone();
+
+        // Range of output cells covered by the rectangle, in the
+        // direction of the gradient.
+        let (first_cell, last_cell) = match direction {
+            GradientDir::Horizontal => (x.div_euclid(2), (x + width - 1).div_euclid(2)),
+            GradientDir::Vertical => (y.div_euclid(4), (y + height - 1).div_euclid(4)),
+        };
+        let nb_steps = last_cell - first_cell;
+
+        for py in y..y + height {
+            for px in x..x + width {
+                let cell = match direction {
+                    GradientDir::Horizontal => px.div_euclid(2),
+                    GradientDir::Vertical => py.div_euclid(4),
+                };
+                let t = if nb_steps == 0 {
+                    0.0
+                } else {
+                    f64::from(cell - first_cell) / f64::from(nb_steps)
+                };
+                self.set_color(&Color::lerp_rgb(from, to, t));
+                self.set_pixel(px, py, true);
+            }
+        }
+
+        self.color = context_color;
+    }
+
+    /// Stroke triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_triangle(5, 5, 20, 10, 4, 17);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⢰⠢⠤⣀⡀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⢸⠀⠀⠀⠈⠉⢒⡢⠄⠀⠀⠀⠀
+    /// ⠀⠀⡇⠀⣀⠤⠔⠊⠁⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠓⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32) {
+        self.stroke_line(x1, y1, x2, y2);
+        self.stroke_line(x2, y2, x3, y3);
+        self.stroke_line(x3, y3, x1, y1);
+    }
+
+    /// Fill triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.fill_triangle(5, 5, 20, 10, 4, 17);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⢰⣦⣤⣀⡀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⢸⣿⣿⣿⣿⣿⣶⡦⠄⠀⠀⠀⠀
+    /// ⠀⠀⣿⣿⣿⠿⠟⠋⠁⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠛⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn fill_triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32) {
+        // This makes for neater edges.
+        self.stroke_triangle(x1, y1, x2, y2, x3, y3);
+
+        // Barycentric Algorithm: Compute the bounding box of the
+        // triangle. Then for each point in the box, determine if it
+        // lies inside or outside the triangle.
+
+        // Bounding box.
+        let min_x = cmp::min(x1, cmp::min(x2, x3));
+        let max_x = cmp::max(x1, cmp::max(x2, x3));
+        let min_y = cmp::min(y1, cmp::min(y2, y3));
+        let max_y = cmp::max(y1, cmp::max(y2, y3));
+
+        let p1 = (f64::from(x1), f64::from(y1));
+        let p2 = (f64::from(x2), f64::from(y2));
+        let p3 = (f64::from(x3), f64::from(y3));
+        let triangle = (p1, p2, p3);
+
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                let point = (f64::from(x), f64::from(y));
+                if Self::is_point_in_triangle(point, triangle) {
+                    self.set_pixel(x, y, true);
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::similar_names)]
+    fn is_point_in_triangle(
+        (px, py): (f64, f64),
+        ((p0x, p0y), (p1x, p1y), (p2x, p2y)): ((f64, f64), (f64, f64), (f64, f64)),
+    ) -> bool {
+        // This version correctly handles triangles specified in either
+        // winding direction (clockwise vs. counterclockwise).
+        // https://stackoverflow.com/a/20861130 — Glenn Slayden
+        let s = (p0x - p2x) * (py - p2y) - (p0y - p2y) * (px - p2x);
+        let t = (p1x - p0x) * (py - p0y) - (p1y - p0y) * (px - p0x);
+
+        if (s < 0.0) != (t < 0.0) && s != 0.0 && t != 0.0 {
+            return false;
+        }
+
+        let d = (p2x - p1x) * (py - p1y) - (p2y - p1y) * (px - p1x);
+
+        d == 0.0 || (d < 0.0) == (s + t <= 0.0)
+    }
+
+    /// Stroke circle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_circle(canvas.cx(), canvas.cy(), 7);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⡠⠊⠀⠀⠀⠈⠢⡀⠀⠀⠀
+    /// ⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀
+    /// ⠀⠀⠀⠀⠣⡀⠀⠀⠀⠀⡠⠃⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠈⠒⠒⠒⠊⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_circle(&mut self, x: i32, y: i32, radius: i32) {
+        self.bresenham_circle(x, y, radius, false);
+    }
+
+    /// Stroke dashed circle.
+    ///
+    /// The circumference is walked clockwise, starting at the right-most
+    /// point (see [`stroke_line_dashed()`](TextCanvas::stroke_line_dashed)
+    /// for `dash` and `gap`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_circle_dashed(canvas.cx(), canvas.cy(), 7, 3, 2);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⡀⢀⣀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠊⠀⠀⠀⠀⠢⡀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠇⠀⠀⠀⠀⠀⠀⡄⠀⠀⠀
+    /// ⠀⠀⠀⠀⠢⡀⠀⠀⠀⠀⡀⠁⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠐⠒⠀⠊⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn stroke_circle_dashed(&mut self, x: i32, y: i32, radius: i32, dash: i32, gap: i32) {
+        let points = Self::compute_bresenham_circle_points(x, y, radius);
+        self.stroke_points_dashed(points, dash, gap);
+    }
+
+    /// Points of a circle, ordered clockwise from the right-most point.
+    fn compute_bresenham_circle_points(cx: i32, cy: i32, radius: i32) -> Vec<(i32, i32)> {
+        let mut points: Vec<(i32, i32)> = Self::compute_bresenham_circle_octant(radius)
+            .flat_map(|(x, y)| {
+                [
+                    (x, y),
+                    (y, x),
+                    (-y, x),
+                    (-x, y),
+                    (-x, -y),
+                    (-y, -x),
+                    (y, -x),
+                    (x, -y),
+                ]
+            })
+            .collect();
+
+        // Y-axis points down, so increasing angles go clockwise.
+        let angle = |&(x, y): &(i32, i32)| {
+            f64::from(y)
+                .atan2(f64::from(x))
+                .rem_euclid(std::f64::consts::TAU)
+        };
+        points.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+        points.dedup();
+
+        points.into_iter().map(|(x, y)| (cx + x, cy + y)).collect()
+    }
+
+    /// Fill circle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.fill_circle(canvas.cx(), canvas.cy(), 7);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⣠⣾⣿⣿⣿⣿⣦⡀⠀⠀⠀
+    /// ⠀⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⡇⠀⠀⠀
+    /// ⠀⠀⠀⠀⠻⣿⣿⣿⣿⣿⡿⠃⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠈⠛⠛⠛⠋⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn fill_circle(&mut self, x: i32, y: i32, radius: i32) {
+        self.bresenham_circle(x, y, radius, true);
+    }
+
+    /// Fill circle, pixel-accurately.
+    ///
+    /// Every pixel of the bounding box whose distance to the center is
+    /// `<= radius` is turned on. Contrary to
+    /// [`fill_circle()`](TextCanvas::fill_circle), which fills the
+    /// spans between the points of a Bresenham circle (an
+    /// approximation that overshoots the radius around the diagonals
+    /// and flattens the tips), this gives a disk that is exactly
+    /// `radius` wide.
+    ///
+    /// Note: Both fills are _O(radius²)_, but this one tests every
+    /// pixel of the bounding box, so it is a bit slower for large radii
+    /// (see `benches/fill_circle.rs`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut bresenham = TextCanvas::new(15, 5);
+    /// bresenham.fill_circle(bresenham.cx(), bresenham.cy(), 6);
+    ///
+    /// let mut exact = TextCanvas::new(15, 5);
+    /// exact.fill_circle_exact(exact.cx(), exact.cy(), 6);
+    ///
+    /// assert_eq!(
+    ///     bresenham.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⣴⣾⣿⣿⣶⡄⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⢸⣿⣿⣿⣿⣿⣿⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠈⢿⣿⣿⣿⣿⠏⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠈⠉⠉⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    ///
+    /// assert_eq!(
+    ///     exact.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⣠⣶⣾⣶⣦⡀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠠⣿⣿⣿⣿⣿⡧⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠻⣿⣿⣿⡿⠃⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠈⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    ///
+    /// // (4, 5) is further than 6 from the center.
+    /// let (x, y) = (exact.cx() + 4, exact.cy() + 5);
+    /// assert_eq!(bresenham.get_pixel(x, y), Some(true));
+    /// assert_eq!(exact.get_pixel(x, y), Some(false));
+    /// ```
+    pub fn fill_circle_exact(&mut self, x: i32, y: i32, radius: i32) {
+        for (px, py) in Self::compute_disk_points(x, y, radius, self.w(), self.h()) {
+            self.set_pixel(px, py, true);
+        }
+    }
+
+    /// On-screen points whose distance to the center is `<= radius`,
+    /// left-right, top-bottom.
+    ///
+    /// `w` and `h` are the last screen indices. Only the part of the
+    /// bounding box that is on screen is visited.
+    fn compute_disk_points(
+        x: i32,
+        y: i32,
+        radius: i32,
+        w: i32,
+        h: i32,
+    ) -> impl Iterator<Item = (i32, i32)> {
+        let (from_x, to_x) = (cmp::max(x - radius, 0), cmp::min(x + radius, w));
+        let (from_y, to_y) = (cmp::max(y - radius, 0), cmp::min(y + radius, h));
+
+        let radius_squared = i64::from(radius) * i64::from(radius);
+        (from_y..=to_y)
+            .flat_map(move |py| (from_x..=to_x).map(move |px| (px, py)))
+            .filter(move |&(px, py)| {
+                let (dx, dy) = (i64::from(px - x), i64::from(py - y));
+                radius >= 0 && dx * dx + dy * dy <= radius_squared
+            })
+    }
+
+    /// Fill circle with a radial color gradient.
+    ///
+    /// Color is interpolated with [`Color::lerp_rgb()`], from `center`
+    /// to `edge`, based on the distance of each output cell from the
+    /// center of the circle (normalized by `radius`). This is great
+    /// for glow or sphere effects.
+    ///
+    /// The disk is the same as with
+    /// [`fill_circle_exact()`](TextCanvas::fill_circle_exact). The
+    /// context color is not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let white = Color::new().rgb(255, 255, 255).fix();
+    /// let blue = Color::new().rgb(0, 0, 255).fix();
+    ///
+    /// canvas.fill_circle_gradient(canvas.cx(), canvas.cy(), 7, &white, &blue);
+    ///
+    /// let center = &canvas.color_buffer[2][7];
+    /// let edge = &canvas.color_buffer[2][4];
+    ///
+    /// assert_ne!(center, edge);
+    /// assert_eq!(*center, Color::new().rgb(229, 229, 255).fix());
+    /// assert_eq!(*edge, Color::new().rgb(18, 18, 255).fix());
+    /// ```
+    pub fn fill_circle_gradient(
+        &mut self,
+        x: i32,
+        y: i32,
+        radius: i32,
+        center: &Color,
+        edge: &Color,
+    ) {
+        if radius < 0 {
+            return;
+        }
+        if !self.is_colorized() && !self.is_color_disabled {
+            self.init_color_buffer();
+        }
+
+        // Output cells that received pixels (sorted, no duplicates).
+        let mut cells: Vec<(usize, usize)> = Vec::new();
+        for (px, py) in Self::compute_disk_points(x, y, radius, self.w(), self.h()) {
+            if !self.check_screen_bounds(px, py) {
+                continue; // Clipped.
+            }
+            self.set_pixel(px, py, true);
+            cells.push((to_usize!(py) / 4, to_usize!(px) / 2));
+        }
+        cells.sort_unstable();
+        cells.dedup();
+
+        // In inverted mode, pixels were turned off (and decolored).
+        if !self.is_colorized() || self.is_inverted {
+            return;
+        }
+
+        for (cell_y, cell_x) in cells {
+            // Distance from the center of the output cell.
+            let distance = (f64::from(to_i32!(cell_x * 2)) + 0.5 - f64::from(x))
+                .hypot(f64::from(to_i32!(cell_y * 4)) + 1.5 - f64::from(y));
+            let t = if radius == 0 {
+                0.0
+            } else {
+                (distance / f64::from(radius)).min(1.0)
+            };
+            self.set_cell_color(cell_x, cell_y, Color::lerp_rgb(center, edge, t));
+        }
+    }
+
+    /// Draw circle using Jesko's Method of the Bresenham's circle
+    /// algorithm.
+    fn bresenham_circle(&mut self, x: i32, y: i32, radius: i32, fill: bool) {
+        let (cx, cy) = (x, y);
+        for (x, y) in Self::compute_bresenham_circle_octant(radius) {
+            if fill {
+                // Connect each pair of points with the same `y`.
+                self.stroke_line(cx - x, cy - y, cx + x, cy - y);
+                self.stroke_line(cx + x, cy + y, cx - x, cy + y);
+                self.stroke_line(cx - y, cy - x, cx + y, cy - x);
+                self.stroke_line(cx + y, cy + x, cx - y, cy + x);
+            } else {
+                self.set_pixel(cx - x, cy - y, true);
+                self.set_pixel(cx + x, cy - y, true);
+                self.set_pixel(cx + x, cy + y, true);
+                self.set_pixel(cx - x, cy + y, true);
+                self.set_pixel(cx - y, cy - x, true);
+                self.set_pixel(cx + y, cy - x, true);
+                self.set_pixel(cx + y, cy + x, true);
+                self.set_pixel(cx - y, cy + x, true);
+            }
+        }
+    }
+
+    /// Points of the first octant of a circle centered on `(0, 0)`,
+    /// from `(radius, 0)` to the diagonal (Jesko's Method).
+    ///
+    /// The rest of the circle is obtained by symmetry.
+    fn compute_bresenham_circle_octant(radius: i32) -> impl Iterator<Item = (i32, i32)> {
+        let mut t1 = radius / 16;
+        let (mut x, mut y) = (radius, 0);
+        std::iter::from_fn(move || {
+            if x < y {
+                return None;
+            }
+            let point = (x, y);
+
+            y += 1;
+            t1 += y;
+            let t2 = t1 - x;
+            if t2 >= 0 {
+                t1 = t2;
+                x -= 1;
+            }
+
+            Some(point)
+        })
+    }
+
+    /// Stroke n-gon.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_ngon(canvas.cx(), canvas.cy(), 7, 5, PI / 2.0);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⢀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⢀⡠⠊⠁⠉⠢⣀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⢣⠀⠀⠀⠀⠀⢠⠃⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⢇⠀⠀⠀⢀⠎⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠈⠉⠉⠉⠉⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sides` < 3.
+    pub fn stroke_ngon(&mut self, x: i32, y: i32, radius: i32, sides: i32, angle: f64) {
+        self.ngon(x, y, f64::from(radius), sides, angle, false);
+    }
+
+    /// Stroke n-gon, inscribed in or circumscribing a circle.
+    ///
+    /// With [`NgonFit::Inscribe`], this is the same as
+    /// [`stroke_ngon()`](TextCanvas::stroke_ngon): `radius` is the
+    /// distance from the center to the vertices. With
+    /// [`NgonFit::Circumscribe`], `radius` is the distance from the
+    /// center to the middle of the sides (the apothem), so the sides
+    /// are tangent to the circle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{NgonFit, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_ngon_fit(canvas.cx(), canvas.cy(), 7, 6, 0.0, NgonFit::Inscribe);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⡰⠉⠉⠉⠙⡄⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⢜⠀⠀⠀⠀⠀⢘⠄⠀⠀⠀
+    /// ⠀⠀⠀⠀⠈⢆⠀⠀⠀⢠⠊⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠈⠉⠉⠉⠁⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    ///
+    /// // Same radius, but the hexagon is now around the circle.
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.stroke_ngon_fit(canvas.cx(), canvas.cy(), 7, 6, 0.0, NgonFit::Circumscribe);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⢀⣀⣀⣀⣀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⢠⠃⠀⠀⠀⠀⢣⠀⠀⠀⠀
+    /// ⠀⠀⠀⠠⡃⠀⠀⠀⠀⠀⠀⡣⠀⠀⠀
+    /// ⠀⠀⠀⠀⠱⡀⠀⠀⠀⠀⡰⠁⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠑⠒⠒⠒⠒⠁⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sides` < 3.
+    pub fn stroke_ngon_fit(
+        &mut self,
+        x: i32,
+        y: i32,
+        radius: i32,
+        sides: i32,
+        angle: f64,
+        fit: NgonFit,
+    ) {
+        let radius = match fit {
+            NgonFit::Inscribe => f64::from(radius),
+            NgonFit::Circumscribe => {
+                f64::from(radius) / (std::f64::consts::PI / f64::from(sides)).cos()
+            }
+        };
+        self.ngon(x, y, radius, sides, angle, false);
+    }
+
+    /// Fill n-gon.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// canvas.fill_ngon(canvas.cx(), canvas.cy(), 7, 4, 0.0);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⢀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⢀⣴⣿⣷⣄⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⢴⣿⣿⣿⣿⣿⣷⠄⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠙⢿⣿⣿⠟⠁⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠙⠁⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sides` < 3.
+    pub fn fill_ngon(&mut self, x: i32, y: i32, radius: i32, sides: i32, angle: f64) {
+        self.ngon(x, y, f64::from(radius), sides, angle, true);
+    }
+
+    /// Fill n-gon, with a gradient around the center.
+    ///
+    /// The n-gon is made of `sides` triangular slices, going from the
+    /// center to each side. Slices are colored in order (starting at
+    /// `angle`, going counter-clockwise), with colors interpolated from
+    /// `from` to `to` (see [`Color::lerp_rgb()`]).
+    ///
+    /// Note: Colors apply to whole output cells, so where slices
+    /// share a cell, the last slice drawn takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{Color, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let red = Color::new().rgb(255, 0, 0).fix();
+    /// let blue = Color::new().rgb(0, 0, 255).fix();
+    ///
+    /// canvas.fill_ngon_gradient(canvas.cx(), canvas.cy(), 9, 3, 0.0, &red, &blue);
+    ///
+    /// let output = canvas.to_string();
+    ///
+    /// assert!(output.contains("\x1b[0;38;2;255;0;0m"));
+    /// assert!(output.contains("\x1b[0;38;2;128;0;128m"));
+    /// assert!(output.contains("\x1b[0;38;2;0;0;255m"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sides` < 3.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_ngon_gradient(
+        &mut self,
+        x: i32,
+        y: i32,
+        radius: i32,
+        sides: i32,
+        angle: f64,
+        from: &Color,
+        to: &Color,
+    ) {
+        assert!(
+            sides >= 3,
+            "Minimum 3 sides needed to draw an n-gon, but only {sides} requested."
+        );
+
+        let context_color = self.color.clone();
+
+        let vertices = Self::compute_ngon_vertices(x, y, f64::from(radius), sides, angle);
+        for (i, a) in vertices.iter().enumerate() {
+            let b = vertices[(i + 1) % vertices.len()];
+            let t = f64::from(to_i32!(i)) / f64::from(sides - 1);
+            self.set_color(&Color::lerp_rgb(from, to, t));
+            self.fill_triangle(x, y, a.0, a.1, b.0, b.1);
+        }
+
+        self.color = context_color;
+    }
+
+    fn ngon(&mut self, x: i32, y: i32, radius: f64, sides: i32, angle: f64, fill: bool) {
+        assert!(
+            sides >= 3,
+            "Minimum 3 sides needed to draw an n-gon, but only {sides} requested."
+        );
+
+        let mut join_vertices = |from: &(i32, i32), to: &(i32, i32)| {
+            if fill {
+                self.fill_triangle(self.cx(), self.cy(), from.0, from.1, to.0, to.1);
+            } else {
+                self.stroke_line(from.0, from.1, to.0, to.1);
+            }
+        };
+
+        let vertices = Self::compute_ngon_vertices(x, y, radius, sides, angle);
+        let mut vertices = vertices.iter();
+
+        let first = vertices.next().expect("there are at least 3 vertex");
+        let mut previous = first;
+        for vertex in vertices {
+            join_vertices(previous, vertex);
+            previous = vertex;
+        }
+        join_vertices(previous, first);
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn compute_ngon_vertices(
+        x: i32,
+        y: i32,
+        radius: f64,
+        sides: i32,
+        angle: f64,
+    ) -> Vec<(i32, i32)> {
+        let cx = f64::from(x);
+        let cy = f64::from(y);
+        let slice = (2.0 * std::f64::consts::PI) / f64::from(sides);
+
+        let mut vertices: Vec<(i32, i32)> = Vec::with_capacity(to_usize!(sides));
+        for vertex in 0..sides {
+            let theta = f64::from(vertex) * slice + angle;
+            let x = cx + (theta.cos() * radius);
+            let y = cy - (theta.sin() * radius); // Screen Y coordinates are inverted.
+            let point = (x.round() as i32, y.round() as i32);
+            vertices.push(point);
+        }
+        vertices
+    }
+
+    /// Stroke a path made of cubic Bézier curves.
+    ///
+    /// `points` is a start point, followed by groups of three points
+    /// for each segment: two control points, and the end point. Each
+    /// segment starts where the previous one ends. This is the same as
+    /// chaining `C` commands in SVG paths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// // S-curve.
+    /// canvas.stroke_bezier_path(&[
+    ///     (2, 10),
+    ///     (2, 0), (14, 0), (14, 10),
+    ///     (14, 20), (26, 20), (26, 10),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⢀⣀⣀⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⣰⠉⠀⠀⠈⢱⡀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠇⠀⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⡄⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⢳⠀⠀⠀⠀⢰⠃⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠙⠒⠔⠚⠁⠀⠀
+    /// "
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of points is not `1 + 3k`.
+    pub fn stroke_bezier_path(&mut self, points: &[(i32, i32)]) {
+        assert!(
+            points.len() % 3 == 1,
+            "A Bézier path needs 1 + 3k points, but {} were given.",
+            points.len()
+        );
+
+        let (mut x, mut y) = points[0];
+        if points.len() == 1 {
+            self.set_pixel(x, y, true);
+            return;
+        }
+
+        for segment in points[1..].chunks_exact(3) {
+            let control_points = [(x, y), segment[0], segment[1], segment[2]];
+            let curve = Self::compute_cubic_bezier_points(control_points);
+
+            for window in curve.windows(2) {
+                let (from, to) = (window[0], window[1]);
+                self.stroke_line(from.0, from.1, to.0, to.1);
+            }
+
+            (x, y) = segment[2];
+        }
+    }
+
+    /// Sample a cubic Bézier curve.
+    ///
+    /// The number of samples is derived from the length of the control
+    /// polygon, which is always longer than the curve itself. This
+    /// gives at least one sample per pixel.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn compute_cubic_bezier_points(control_points: [(i32, i32); 4]) -> Vec<(i32, i32)> {
+        let [p0, p1, p2, p3] = control_points.map(|(x, y)| (f64::from(x), f64::from(y)));
+
+        let distance = |a: (f64, f64), b: (f64, f64)| (b.0 - a.0).hypot(b.1 - a.1);
+        let length = distance(p0, p1) + distance(p1, p2) + distance(p2, p3);
+        let nb_steps = cmp::max(length.ceil() as usize, 1);
+
+        let bezier = |a: f64, b: f64, c: f64, d: f64, t: f64| {
+            let u = 1.0 - t;
+            u * u * u * a + 3.0 * u * u * t * b + 3.0 * u * t * t * c + t * t * t * d
+        };
+
+        let mut points: Vec<(i32, i32)> = Vec::with_capacity(nb_steps + 1);
+        for step in 0..=nb_steps {
+            #[allow(clippy::cast_precision_loss)]
+            let t = step as f64 / nb_steps as f64;
+            let x = bezier(p0.0, p1.0, p2.0, p3.0, t).round() as i32;
+            let y = bezier(p0.1, p1.1, p2.1, p3.1, t).round() as i32;
+            // Consecutive samples often fall on the same pixel.
+            if points.last() != Some(&(x, y)) {
+                points.push((x, y));
+            }
+        }
+        points
+    }
+
+    /// Draw a grid of luminance values (e.g., a grayscale image).
+    ///
+    /// The grid is stretched over the whole screen, using
+    /// nearest-neighbor sampling. `data` is a list of rows, and pixels
+    /// are turned _on_ where the sampled luminance is greater than
+    /// `threshold`. Other pixels are left untouched.
+    ///
+    /// Rows are sampled independently, so they need not be the same
+    /// length. An empty grid (or an empty row) draws nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(4, 2);
+    ///
+    /// let data = vec![
+    ///     vec![0.0, 1.0, 0.0, 1.0],
+    ///     vec![1.0, 0.0, 1.0, 0.0],
+    /// ];
+    /// canvas.draw_luminance(&data, 0.5);
+    ///
+    /// assert_eq!(canvas.to_string(), "⠀⣿⠀⣿\n⣿⠀⣿⠀\n");
+    /// ```
+    pub fn draw_luminance(&mut self, data: &[Vec<f64>], threshold: f64) {
+        if data.is_empty() {
+            return;
+        }
+
+        for y in 0..self.screen.uheight() {
+            let row = &data[y * data.len() / self.screen.uheight()];
+            if row.is_empty() {
+                continue;
+            }
+
+            for x in 0..self.screen.uwidth() {
+                if row[x * row.len() / self.screen.uwidth()] > threshold {
+                    self.set_pixel(to_i32!(x), to_i32!(y), true);
+                }
+            }
+        }
+    }
+
+    /// Draw another canvas onto the current canvas.
+    ///
+    /// The other canvas completely overrides the current canvas where
+    /// it is drawn (but it does not affect the portions where it is
+    /// _not_ drawn).
+    ///
+    /// Note: Inverted mode has no effect here, this is a low level
+    /// copy-paste.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    /// canvas.stroke_line(0, 0, canvas.w(), canvas.h());
+    /// canvas.stroke_line(0, canvas.h(), canvas.w(), 0);
+    ///
+    /// let mut overlay = TextCanvas::new(7, 3);
+    /// overlay.frame();
+    ///
+    /// canvas.draw_canvas(&overlay, 8, 4);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠑⠢⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠔⠊
+    /// ⠀⠀⠀⠑⡏⠉⠉⠉⠉⠉⢹⠊⠀⠀⠀
+    /// ⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀
+    /// ⠀⠀⠀⡠⣇⣀⣀⣀⣀⣀⣸⢄⠀⠀⠀
+    /// ⡠⠔⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠢⢄
+    /// "
+    /// );
+    /// ```
+    pub fn draw_canvas(&mut self, canvas: &Self, dx: i32, dy: i32) {
+        self.draw_canvas_onto_canvas(canvas, dx, dy, false, false);
+    }
+
+    /// Draw another canvas onto the current canvas, keeping text.
+    ///
+    /// This is the same as [`draw_canvas()`](TextCanvas::draw_canvas),
+    /// pixels are overridden, but empty text cells of the other canvas
+    /// are transparent, like in
+    /// [`merge_canvas()`](TextCanvas::merge_canvas). Existing labels
+    /// survive, unless the other canvas has text of its own on top.
+    ///
+    /// Note: Inverted mode has no effect here, this is a low level
+    /// copy-paste.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(5, 1);
+    /// canvas.draw_text("label", 0, 0);
+    ///
+    /// let mut overlay = TextCanvas::new(5, 1);
+    /// overlay.fill();
+    /// overlay.draw_text("X", 4, 0);
+    ///
+    /// canvas.draw_canvas_preserve_text(&overlay, 0, 0);
+    ///
+    /// assert_eq!(canvas.to_string(), "labeX\n");
+    /// ```
+    pub fn draw_canvas_preserve_text(&mut self, canvas: &Self, dx: i32, dy: i32) {
+        self.draw_canvas_onto_canvas(canvas, dx, dy, false, true);
+    }
+
+    /// Merge another canvas with the current canvas.
+    ///
+    /// The other canvas is merged with the current canvas. That is,
+    /// pixels that are turned on get draw, but those that are off are
+    /// ignored.
+    ///
+    /// Note: Inverted mode has no effect here, this is a low level
+    /// copy-paste.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    /// canvas.stroke_line(0, 0, canvas.w(), canvas.h());
+    /// canvas.stroke_line(0, canvas.h(), canvas.w(), 0);
+    ///
+    /// let mut overlay = TextCanvas::new(7, 3);
+    /// overlay.frame();
+    ///
+    /// canvas.merge_canvas(&overlay, 8, 4);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠑⠢⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⠔⠊
+    /// ⠀⠀⠀⠑⡯⣉⠉⠉⠉⣉⢽⠊⠀⠀⠀
+    /// ⠀⠀⠀⠀⡇⠀⡱⠶⢎⠀⢸⠀⠀⠀⠀
+    /// ⠀⠀⠀⡠⣗⣉⣀⣀⣀⣉⣺⢄⠀⠀⠀
+    /// ⡠⠔⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠢⢄
+    /// "
+    /// );
+    /// ```
+    pub fn merge_canvas(&mut self, canvas: &Self, dx: i32, dy: i32) {
+        self.draw_canvas_onto_canvas(canvas, dx, dy, true, true);
+    }
+
+    /// Draw another canvas onto the current canvas, scaled.
+    ///
+    /// The other canvas is resized by `scale_x` and `scale_y` (e.g.,
+    /// `0.5` halves its width, `2.0` doubles it), using
+    /// nearest-neighbor sampling, and then drawn like in
+    /// [`draw_canvas()`](TextCanvas::draw_canvas). This is useful for
+    /// thumbnails and insets.
+    ///
+    /// Note: Only pixels and their colors are drawn. Text cannot be
+    /// scaled, and is ignored.
+    ///
+    /// Note: Scales that are not strictly positive draw nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(20, 4);
+    ///
+    /// let mut drawing = TextCanvas::new(12, 4);
+    /// drawing.fill_circle(drawing.cx(), drawing.cy(), 7);
+    /// drawing.fill_rect(0, 0, 8, 8);
+    ///
+    /// canvas.draw_canvas(&drawing, 0, 0);
+    /// canvas.draw_canvas_scaled(&drawing, 28, 4, 0.5, 0.5);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⣿⣿⣿⣿⣴⣶⣶⣶⣄⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⣿⣿⣿⣿⣿⣿⣿⣿⣿⣷⠀⠀⠀⠀⣿⣿⣶⣶⣦⠀
+    /// ⠀⠀⢸⣿⣿⣿⣿⣿⣿⣿⠀⠀⠀⠀⠀⠸⣿⣿⡿⠀
+    /// ⠀⠀⠀⠙⢿⣿⣿⣿⠟⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    pub fn draw_canvas_scaled(
+        &mut self,
+        canvas: &Self,
+        dx: i32,
+        dy: i32,
+        scale_x: f64,
+        scale_y: f64,
+    ) {
+        if !(scale_x > 0.0 && scale_y > 0.0 && scale_x.is_finite() && scale_y.is_finite()) {
+            return;
+        }
+
+        self.invalidate_render();
+        if !self.is_colorized() && canvas.is_colorized() && !self.is_color_disabled {
+            self.init_color_buffer();
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let (width, height) = (
+            (canvas.screen.fwidth() * scale_x).round() as usize,
+            (canvas.screen.fheight() * scale_y).round() as usize,
+        );
+
+        // Only iterate over the part that lands on the screen.
+        let (xs, ys) = (
+            Self::visible_range(dx, width, self.screen.uwidth()),
+            Self::visible_range(dy, height, self.screen.uheight()),
+        );
+
+        for y in ys {
+            for x in xs.clone() {
+                if !self.check_screen_bounds(to_i32!(x), to_i32!(y)) {
+                    continue;
+                }
+
+                // Nearest source pixel.
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_precision_loss,
+                    clippy::cast_sign_loss
+                )]
+                let (sx, sy) = (
+                    (((x as f64 - f64::from(dx)) / scale_x) as usize)
+                        .min(canvas.screen.uwidth() - 1),
+                    (((y as f64 - f64::from(dy)) / scale_y) as usize)
+                        .min(canvas.screen.uheight() - 1),
+                );
+
+                let pixel = canvas.buffer[sy][sx];
+                self.buffer[y][x] = pixel;
+
+                // Cells mix pixels from different source cells, only
+                // take the color of those that are on.
+                if pixel == ON && self.is_colorized() && canvas.is_colorized() {
+                    let color = canvas.color_buffer[sy / 4][sx / 2].clone();
+                    self.set_cell_color(x / 2, y / 4, color);
+                }
+            }
+        }
+    }
+
+    /// Screen range covered by `offset..offset + size`, clamped to
+    /// `0..screen`.
+    fn visible_range(offset: i32, size: usize, screen: usize) -> std::ops::Range<usize> {
+        let start = usize::try_from(offset).unwrap_or(0).min(screen);
+        let end =
+            i64::try_from(size).map_or(i64::MAX, |size| i64::from(offset).saturating_add(size));
+        let end = usize::try_from(end).unwrap_or(0).min(screen);
+        start..end.max(start)
+    }
+
+    /// Blend another canvas with the current canvas.
+    ///
+    /// Pixels of both canvases are combined according to `mode` (see
+    /// [`BlendMode`]). Where the resulting pixel is _on_ and comes from
+    /// the other canvas, so does the color. Text is merged (see
+    /// [`merge_canvas()`](TextCanvas::merge_canvas)).
+    ///
+    /// Note: Inverted mode has no effect here, this is a low level
+    /// operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{BlendMode, TextCanvas};
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    /// canvas.fill_rect(0, 0, 20, 20);
+    ///
+    /// let mut overlay = TextCanvas::new(15, 5);
+    /// overlay.fill_rect(10, 0, 20, 20);
+    ///
+    /// canvas.blend_canvas(&overlay, 0, 0, BlendMode::Xor);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⣿⣿⣿⣿⣿⠀⠀⠀⠀⠀⣿⣿⣿⣿⣿
+    /// ⣿⣿⣿⣿⣿⠀⠀⠀⠀⠀⣿⣿⣿⣿⣿
+    /// ⣿⣿⣿⣿⣿⠀⠀⠀⠀⠀⣿⣿⣿⣿⣿
+    /// ⣿⣿⣿⣿⣿⠀⠀⠀⠀⠀⣿⣿⣿⣿⣿
+    /// ⣿⣿⣿⣿⣿⠀⠀⠀⠀⠀⣿⣿⣿⣿⣿
+    /// "
+    /// );
+    /// ```
+    pub fn blend_canvas(&mut self, canvas: &Self, dx: i32, dy: i32, mode: BlendMode) {
+        self.invalidate_render();
+        if !self.is_colorized() && canvas.is_colorized() && !self.is_color_disabled {
+            self.init_color_buffer();
+        }
+
+        if !self.is_textual() && canvas.is_textual() {
+            self.init_text_buffer();
+        }
+
+        let (offset_x, offset_y) = (dx, dy);
+
+        for (x, y) in canvas.uiter_buffer() {
+            let (dx, dy) = (offset_x + to_i32!(x), offset_y + to_i32!(y));
+            if !self.check_screen_bounds(dx, dy) {
+                continue;
+            }
+            let (dx, dy) = (to_usize!(dx), to_usize!(dy));
+
+            // Pixels.
+            let pixel = canvas.buffer[y][x];
+            let blended = mode.blend(self.buffer[dy][dx], pixel);
+            self.buffer[dy][dx] = blended;
+
+            if blended == ON && pixel == ON && self.is_colorized() && canvas.is_colorized() {
+                let color = canvas.color_buffer[y / 4][x / 2].clone();
+                self.set_cell_color(dx / 2, dy / 4, color);
+            }
+
+            // Text.
+            if canvas.is_textual() {
+                let text = &canvas.text_buffer[y / 4][x / 2];
+                if !text.is_empty() {
+                    self.text_buffer[dy / 4][dx / 2].clone_from(text);
+                }
+            }
+        }
+    }
+
+    /// Blend two canvases into a new one.
+    ///
+    /// The new canvas is a copy of `a`, with `b` blended onto it (see
+    /// [`blend_canvas()`](TextCanvas::blend_canvas)).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::{BlendMode, TextCanvas};
+    ///
+    /// let mut a = TextCanvas::new(2, 1);
+    /// a.fill_rect(0, 0, 2, 4);
+    ///
+    /// let mut b = TextCanvas::new(2, 1);
+    /// b.fill_rect(0, 0, 4, 2);
+    ///
+    /// let canvas = TextCanvas::blended(&a, &b, BlendMode::And).unwrap();
+    ///
+    /// assert_eq!(canvas.to_string(), "⠛⠀\n");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `a` and `b` are not of the same size.
+    pub fn blended(a: &Self, b: &Self, mode: BlendMode) -> Result<Self, TextCanvasError> {
+        if a.output.width() != b.output.width() || a.output.height() != b.output.height() {
+            return Err(TextCanvasError("cannot blend canvases of different sizes"));
+        }
+
+        let mut canvas = Self::new(a.output.width(), a.output.height());
+        canvas.draw_canvas(a, 0, 0);
+        canvas.blend_canvas(b, 0, 0, mode);
+        Ok(canvas)
+    }
+
+    /// Arrange canvases into a grid (i.e., "small multiples").
+    ///
+    /// Canvases are laid out left to right, top to bottom, in rows of
+    /// `cols` canvases, with `gap` output cells between them. All grid
+    /// cells have the same size, that of the largest canvas; smaller
+    /// canvases are aligned to the top-left of their cell.
+    ///
+    /// The new canvas is sized to fit the grid exactly, and each
+    /// canvas is copied with [`draw_canvas()`](TextCanvas::draw_canvas).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut a = TextCanvas::new(3, 1);
+    /// a.fill();
+    ///
+    /// let mut b = TextCanvas::new(2, 2);
+    /// b.frame();
+    ///
+    /// let mut c = TextCanvas::new(1, 1);
+    /// c.draw_text("c", 0, 0);
+    ///
+    /// let canvas = TextCanvas::grid(&[&a, &b, &c], 2, 1);
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⣿⣿⣿⠀⡏⢹⠀
+    /// ⠀⠀⠀⠀⣇⣸⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀
+    /// c⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `cells` is empty, if `cols` is 0, or if `gap` is negative.
+    #[must_use]
+    pub fn grid(cells: &[&Self], cols: usize, gap: i32) -> Self {
+        assert!(!cells.is_empty(), "Grid must have at least one cell.");
+        assert!(cols > 0, "Grid must have at least one column.");
+        assert!(gap >= 0, "Grid gap cannot be negative.");
+
+        let cell_width = cells.iter().map(|cell| cell.output.width()).max();
+        let cell_height = cells.iter().map(|cell| cell.output.height()).max();
+        let (cell_width, cell_height) = (cell_width.unwrap_or(1), cell_height.unwrap_or(1));
+
+        let cols = cols.min(cells.len());
+        let rows = cells.len().div_ceil(cols);
+        let (cols, rows) = (to_i32!(cols), to_i32!(rows));
+
+        let mut canvas = Self::new(
+            cols * cell_width + (cols - 1) * gap,
+            rows * cell_height + (rows - 1) * gap,
+        );
+
+        for (i, cell) in cells.iter().enumerate() {
+            let (row, col) = (to_i32!(i) / cols, to_i32!(i) % cols);
+            let (dx, dy) =
+                canvas.output_to_screen(col * (cell_width + gap), row * (cell_height + gap));
+            canvas.draw_canvas(cell, dx, dy);
+        }
+
+        canvas
+    }
+
+    fn draw_canvas_onto_canvas(
+        &mut self,
+        canvas: &Self,
+        dx: i32,
+        dy: i32,
+        merge_pixels: bool,
+        merge_text: bool,
+    ) {
+        self.invalidate_render();
+        if !self.is_colorized() && canvas.is_colorized() && !self.is_color_disabled {
+            self.init_color_buffer();
+        }
+
+        if !self.is_textual() && canvas.is_textual() {
+            self.init_text_buffer();
+        }
+
+        // We cannot convert `offset_x` and `offset_y` to `usize` yet,
+        // because negative values are possible here (you can draw the
+        // canvas out of bounds). The conversion must be made at the
+        // pixel level, because we can't draw pixels out of bound.
+        let (offset_x, offset_y) = (dx, dy);
+
+        for (x, y) in canvas.uiter_buffer() {
+            // Source coordinates of pixel.
+            // x, y
+
+            // Destination coordinates of pixel.
+            let (dx, dy) = (offset_x + to_i32!(x), offset_y + to_i32!(y));
+            if !self.check_screen_bounds(dx, dy) {
+                continue;
+            }
+            // Here we are safe. If a pixel is within the screen bounds,
+            // it can safely be converted to buffer coordinates in
+            // `usize`. And, we can also safely convert it to output
+            // coordinates (`x / 2`, `y / 4`) later.
+            let (dx, dy) = (to_usize!(dx), to_usize!(dy));
+
+            // Pixels.
+            let pixel = canvas.buffer[y][x];
+            // In merge mode, only draw if pixel is on, treating off
+            // pixels as transparent.
+            if !merge_pixels || pixel == ON {
+                self.buffer[dy][dx] = pixel;
+
+                if self.is_colorized() && canvas.is_colorized() {
+                    let color = canvas.color_buffer[y / 4][x / 2].clone();
+                    self.set_cell_color(dx / 2, dy / 4, color);
+                }
+            }
+
+            // Text.
+            if canvas.is_textual() {
+                // Text buffer has color embedded into the String.
+                let text = canvas.text_buffer[y / 4][x / 2].clone();
+
+                if !merge_text || !text.is_empty() {
+                    self.text_buffer[dy / 4][dx / 2] = text;
+                }
+            }
+        }
+    }
+}
+
+impl TextCanvas {
+    /// Get a bounded view onto a region of the canvas.
+    ///
+    /// The view offsets coordinates by `(x, y)` and clips drawing to
+    /// the `width`×`height` region (in screen pixels). Drawing goes
+    /// directly into the canvas' buffers, so unlike
+    /// [`draw_canvas()`](TextCanvas::draw_canvas), there is no
+    /// intermediate canvas to copy.
+    ///
+    /// Note: Text is positioned in output coordinates. For text to
+    /// line up with the region, `x` should be a multiple of 2, and `y`
+    /// a multiple of 4.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use textcanvas::TextCanvas;
+    ///
+    /// let mut canvas = TextCanvas::new(15, 5);
+    ///
+    /// let mut view = canvas.view(8, 4, 14, 12);
+    /// view.stroke_line(0, 0, view.w(), view.h());
+    /// view.stroke_line(0, view.h(), view.w(), 0);
+    /// view.frame();
+    ///
+    /// assert_eq!(
+    ///     canvas.to_string(),
+    ///     "\
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⡟⢍⡉⠉⢉⡩⢻⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⡇⠀⢈⠶⡁⠀⢸⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⣧⣊⣁⣀⣈⣑⣼⠀⠀⠀⠀
+    /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+    /// "
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If width and height of view are < 1×1.
+    pub fn view(&mut self, x: i32, y: i32, width: i32, height: i32) -> CanvasView<'_> {
+        assert!(width > 0 && height > 0, "CanvasView's minimal size is 1×1.");
+
+        CanvasView {
+            canvas: self,
+            region: Region {
+                x,
+                y,
+                width,
+                height,
+            },
+        }
+    }
+}
+
+/// Bounded view onto a region of a [`TextCanvas`].
+///
+/// Created with [`TextCanvas::view()`]. Coordinates are relative to
+/// the top-left corner of the region, and anything drawn outside of it
+/// is clipped. Drawing writes through to the parent canvas.
+///
+/// The view provides the basic drawing primitives. Their behaviour is
+/// that of their [`TextCanvas`] counterpart.
+#[derive(Debug)]
+pub struct CanvasView<'a> {
+    canvas: &'a mut TextCanvas,
+    region: Region,
+}
+
+impl CanvasView<'_> {
+    /// Shortcut for width of view.
+    #[must_use]
+    pub fn w(&self) -> i32 {
+        self.region.width - 1
+    }
+
+    /// Shortcut for height of view.
+    #[must_use]
+    pub fn h(&self) -> i32 {
+        self.region.height - 1
+    }
+
+    /// Shortcut for center-X of view.
+    #[must_use]
+    pub fn cx(&self) -> i32 {
+        self.region.width / 2
+    }
+
+    /// Shortcut for center-Y of view.
+    #[must_use]
+    pub fn cy(&self) -> i32 {
+        self.region.height / 2
+    }
+
+    /// Offset view coordinates to canvas coordinates.
+    fn offset(&self, x: i32, y: i32) -> (i32, i32) {
+        (x + self.region.x, y + self.region.y)
+    }
+
+    /// Offset view coordinates to canvas output coordinates.
+    fn offset_output(&self, x: i32, y: i32) -> (i32, i32) {
+        (
+            x + self.region.x.div_euclid(2),
+            y + self.region.y.div_euclid(4),
+        )
+    }
+
+    /// Run drawing code on the parent canvas, clipped to the region.
+    fn clipped(&mut self, draw: impl FnOnce(&mut TextCanvas)) {
+        self.canvas.clip = Some(self.region);
+        draw(self.canvas);
+        self.canvas.clip = None;
+    }
+
+    /// See [`TextCanvas::set_color()`].
+    pub fn set_color(&mut self, color: &Color) {
+        self.canvas.set_color(color);
+    }
+
+    /// See [`TextCanvas::get_pixel()`].
+    #[must_use]
+    pub fn get_pixel(&self, x: i32, y: i32) -> Option<bool> {
+        let (x, y) = self.offset(x, y);
+        if !self.region.contains(x, y) {
+            return None;
+        }
+        self.canvas.get_pixel(x, y)
+    }
+
+    /// See [`TextCanvas::set_pixel()`].
+    pub fn set_pixel(&mut self, x: i32, y: i32, state: bool) {
+        let (x, y) = self.offset(x, y);
+        self.clipped(|canvas| canvas.set_pixel(x, y, state));
+    }
+
+    /// See [`TextCanvas::draw_text()`].
+    pub fn draw_text(&mut self, text: &str, x: i32, y: i32) {
+        let (x, y) = self.offset_output(x, y);
+        self.clipped(|canvas| canvas.draw_text(text, x, y));
+    }
+
+    /// See [`TextCanvas::merge_text()`].
+    pub fn merge_text(&mut self, text: &str, x: i32, y: i32) {
+        let (x, y) = self.offset_output(x, y);
+        self.clipped(|canvas| canvas.merge_text(text, x, y));
+    }
+
+    /// See [`TextCanvas::stroke_line()`].
+    pub fn stroke_line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) {
+        let (x1, y1) = self.offset(x1, y1);
+        let (x2, y2) = self.offset(x2, y2);
+        self.clipped(|canvas| canvas.stroke_line(x1, y1, x2, y2));
+    }
+
+    /// See [`TextCanvas::stroke_rect()`].
+    pub fn stroke_rect(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        let (x, y) = self.offset(x, y);
+        self.clipped(|canvas| canvas.stroke_rect(x, y, width, height));
+    }
+
+    /// Draw a border around the view.
+    ///
+    /// See [`TextCanvas::frame()`].
+    pub fn frame(&mut self) {
+        self.stroke_rect(0, 0, self.region.width, self.region.height);
+    }
+
+    /// See [`TextCanvas::fill_rect()`].
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        let (x, y) = self.offset(x, y);
+        self.clipped(|canvas| canvas.fill_rect(x, y, width, height));
+    }
+
+    /// See [`TextCanvas::stroke_triangle()`].
+    pub fn stroke_triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32) {
+        let (x1, y1) = self.offset(x1, y1);
+        let (x2, y2) = self.offset(x2, y2);
+        let (x3, y3) = self.offset(x3, y3);
+        self.clipped(|canvas| canvas.stroke_triangle(x1, y1, x2, y2, x3, y3));
+    }
+
+    /// See [`TextCanvas::fill_triangle()`].
+    pub fn fill_triangle(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, x3: i32, y3: i32) {
+        let (x1, y1) = self.offset(x1, y1);
+        let (x2, y2) = self.offset(x2, y2);
+        let (x3, y3) = self.offset(x3, y3);
+        self.clipped(|canvas| canvas.fill_triangle(x1, y1, x2, y2, x3, y3));
+    }
+
+    /// See [`TextCanvas::stroke_circle()`].
+    pub fn stroke_circle(&mut self, x: i32, y: i32, radius: i32) {
+        let (x, y) = self.offset(x, y);
+        self.clipped(|canvas| canvas.stroke_circle(x, y, radius));
+    }
+
+    /// See [`TextCanvas::fill_circle()`].
+    pub fn fill_circle(&mut self, x: i32, y: i32, radius: i32) {
+        let (x, y) = self.offset(x, y);
+        self.clipped(|canvas| canvas.fill_circle(x, y, radius));
+    }
+}
+
+/// Builder for [`TextCanvas`].
+///
+/// This is useful when a canvas needs configuring before it is drawn
+/// on. Options that are not set keep the same defaults as with
+/// [`TextCanvas::new()`], and size defaults to
+/// [`TextCanvas::get_default_size()`].
+///
+/// Create one with [`TextCanvas::builder()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::{Color, TextCanvas};
+///
+/// let mut canvas = TextCanvas::builder()
+///     .size(2, 1)
+///     .background(Color::new().bg_blue())
+///     .build();
+///
+/// canvas.set_pixel(0, 0, true);
+///
+/// assert_eq!(
+///     canvas.to_string(),
+///     "\x1b[0;44m⠁⠀\x1b[0m\n"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct TextCanvasBuilder {
+    width: i32,
+    height: i32,
+    is_inverted: bool,
+    background: Color,
+    with_color: bool,
+}
+
+impl TextCanvasBuilder {
+    fn new() -> Self {
+        let (width, height) = TextCanvas::get_default_size();
+        Self {
+            width,
+            height,
+            is_inverted: false,
+            background: Color::new(),
+            with_color: true,
+        }
+    }
+
+    /// Size of the canvas, in output characters.
+    pub fn size(&mut self, width: i32, height: i32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Start in inverted drawing mode (see [`TextCanvas::invert()`]).
+    pub fn inverted(&mut self, is_inverted: bool) -> &mut Self {
+        self.is_inverted = is_inverted;
+        self
+    }
+
+    /// Background color of the pixel layer (see
+    /// [`TextCanvas::set_background()`]).
+    pub fn background(&mut self, color: &Color) -> &mut Self {
+        self.background = color.clone();
+        self
+    }
+
+    /// Whether the canvas supports color. If `false`, color is disabled
+    /// (see [`TextCanvas::disable_color()`]), and the background is
+    /// ignored.
+    pub fn with_color(&mut self, with_color: bool) -> &mut Self {
+        self.with_color = with_color;
+        self
+    }
+
+    /// Create the configured canvas.
+    ///
+    /// # Panics
+    ///
+    /// If the size is invalid, like [`TextCanvas::new()`].
+    #[must_use]
+    pub fn build(&self) -> TextCanvas {
+        let mut canvas = TextCanvas::new(self.width, self.height);
+        canvas.is_inverted = self.is_inverted;
+        if self.with_color {
+            canvas.set_background(&self.background);
+        } else {
+            canvas.disable_color();
+        }
+        canvas
+    }
+}
+
+/// Render successive frames, emitting only what changed.
+///
+/// This is the same as [`TextCanvas::render_delta()`], but the
+/// previous frame is kept internally, so there is no need to hold on to
+/// the previous canvas.
+///
+/// The first frame (and any frame whose size differs from the previous
+/// one) is rendered in full. The following frames only contain the
+/// changed cells, each prefixed with a cursor move escape sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::{DeltaRenderer, TextCanvas};
+///
+/// let mut renderer = DeltaRenderer::new();
+/// let mut canvas = TextCanvas::new(3, 2);
+///
+/// assert_eq!(renderer.render(&canvas), "⠀⠀⠀\n⠀⠀⠀\n");
+///
+/// canvas.set_pixel(2, 4, true);
+/// assert_eq!(renderer.render(&canvas), "\x1b[2;2H⠁");
+///
+/// // Nothing changed.
+/// assert_eq!(renderer.render(&canvas), "");
+/// ```
+#[derive(Debug, Default)]
+pub struct DeltaRenderer {
+    previous: Option<Vec<Vec<String>>>,
+}
+
+impl DeltaRenderer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { previous: None }
+    }
+
+    /// Render the cells of `canvas` that changed since the last call.
+    pub fn render(&mut self, canvas: &TextCanvas) -> String {
+        let cells = canvas.render_cells();
+
+        let mut res = String::new();
+        match &self.previous {
+            Some(previous)
+                if previous.len() == cells.len() && previous[0].len() == cells[0].len() =>
+            {
+                for (y, (row, previous_row)) in cells.iter().zip(previous).enumerate() {
+                    for (x, (cell, previous_cell)) in row.iter().zip(previous_row).enumerate() {
+                        if cell == previous_cell {
+                            continue;
+                        }
+                        let (row, column) = (y + 1, x + 1);
+                        write!(res, "\x1b[{row};{column}H{cell}").unwrap_or(());
+                    }
+                }
+            }
+            _ => canvas.render_into(&mut res),
+        }
+
+        self.previous = Some(cells);
+        res
+    }
+
+    /// Forget the previous frame, so the next one is rendered in full.
+    ///
+    /// Use this if the screen was cleared or written over.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl Default for TextCanvas {
+    fn default() -> Self {
+        let (width, heigt) = Self::get_default_size();
+        Self::new(width, heigt)
+    }
+}
+
+impl fmt::Display for TextCanvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// Two canvases are equal if they look the same.
+///
+/// That is, if they have the same size, the same pixels, and render to
+/// the same string. Colors and text are part of equality, since they
+/// are part of the render. But text hides the pixels underneath, so
+/// pixels are also compared directly.
+///
+/// Drawing settings (inverted mode, context color, etc.) are not part
+/// of equality, they only affect future drawing.
+///
+/// # Examples
+///
+/// ```rust
+/// use textcanvas::TextCanvas;
+///
+/// let mut a = TextCanvas::new(3, 1);
+/// let mut b = TextCanvas::new(3, 1);
+///
+/// a.stroke_line(0, 0, a.w(), 0);
+/// b.draw_text("   ", 0, 0); // Spaces do nothing visible.
+/// b.invert();
+/// b.invert();
+/// b.stroke_line(0, 0, b.w(), 0);
+///
+/// assert_eq!(a, b);
+/// ```
+impl PartialEq for TextCanvas {
+    fn eq(&self, other: &Self) -> bool {
+        self.output == other.output
+            && self.buffer == other.buffer
+            && self.render() == other.render()
+    }
+}
+
+impl Eq for TextCanvas {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::explicit_counter_loop)]
+    fn stroke_line_accros_canvas(canvas: &mut TextCanvas) {
+        let mut y = 0;
+        for x in 0..canvas.screen.width() {
+            canvas.set_pixel(x, y, true);
+            y += 1;
+        }
+    }
+
+    // Errors.
+
+    #[test]
+    fn textcanvaserror_format() {
+        let error = TextCanvasError("an error has occurred");
+
+        assert_eq!(error.to_string(), "an error has occurred");
+    }
+
+    // Surface.
+
+    #[test]
+    fn size() {
+        let surface = Surface {
+            width: 15,
+            height: 9,
+        };
+
+        assert_eq!(surface.width(), 15);
+        assert_eq!(surface.height(), 9);
+    }
+
+    #[test]
+    fn display() {
+        let surface = Surface {
+            width: 15,
+            height: 9,
+        };
+
+        assert_eq!(format!("{surface}"), "15×9");
+    }
+
+    #[test]
+    fn size_unsigned() {
+        let surface = Surface {
+            width: 15,
+            height: 9,
+        };
+
+        assert_eq!(surface.uwidth(), 15);
+        assert_eq!(surface.uheight(), 9);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn size_float() {
+        let surface = Surface {
+            width: 15,
+            height: 9,
+        };
+
+        assert_eq!(surface.fwidth(), 15.0);
+        assert_eq!(surface.fheight(), 9.0);
+    }
+
+    // Canvas.
+
+    #[test]
+    fn output_size() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert_eq!(canvas.output.width, 7, "Incorrect output width.");
+        assert_eq!(canvas.output.height, 4, "Incorrect output height.");
+    }
+
+    #[test]
+    fn screen_size() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert_eq!(canvas.screen.width, 7 * 2, "Incorrect output width.");
+        assert_eq!(canvas.screen.height, 4 * 4, "Incorrect output height.");
+    }
+
+    #[test]
+    fn buffer_size() {
+        let canvas = TextCanvas::new(7, 4);
+        let buffer_width = canvas.buffer[0].len();
+        let buffer_height = canvas.buffer.len();
+
+        assert_eq!(buffer_width, 7 * 2, "Incorrect number of rows in buffer.");
+        assert_eq!(
+            buffer_height,
+            4 * 4,
+            "Incorrect number of columns in buffer."
+        );
+    }
+
+    #[test]
+    fn default_size() {
+        let canvas = TextCanvas::default();
+
+        assert_eq!(canvas.output.width, 80, "Incorrect default width.");
+        assert_eq!(canvas.output.height, 24, "Incorrect default height.");
+    }
+
+    #[test]
+    fn get_default_size() {
+        let (width, height) = TextCanvas::get_default_size();
+
+        assert_eq!(width, 80, "Incorrect default width.");
+        assert_eq!(height, 24, "Incorrect default height.");
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_zero_panics_for_width() {
+        let _ = TextCanvas::new(0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_zero_panics_for_height() {
+        let _ = TextCanvas::new(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_zero_panics_for_width_and_height() {
+        let _ = TextCanvas::new(0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_negative_panics_for_width() {
+        let _ = TextCanvas::new(-1, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_negative_panics_for_height() {
+        let _ = TextCanvas::new(1, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_negative_panics_for_width_and_height() {
+        let _ = TextCanvas::new(-1, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_too_big_panics_for_width() {
+        let _ = TextCanvas::new(100_000, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_too_big_panics_for_height() {
+        let _ = TextCanvas::new(1, 100_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn size_too_big_panics_for_width_and_height() {
+        let _ = TextCanvas::new(100_000, 100_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn max_i32_does_not_overflow_width() {
+        // There was an error in the bounds checking condition:
+        //
+        //     if width * 2 <= MAX_RESOLUTION
+        //
+        // This panics if `size * 2` > `i32::MAX`, with `attempt to
+        // multiply with overflow`. The solution is to divide instead:
+        //
+        //     if width <= MAX_RESOLUTION / 2
+        let _ = TextCanvas::new(i32::MAX, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn max_i32_does_not_overflow_height() {
+        // There was an error in the bounds checking condition:
+        //
+        //     if height * 4 <= MAX_RESOLUTION
+        //
+        // This panics if `size * 4` > `i32::MAX`, with `attempt to
+        // multiply with overflow`. The solution is to divide instead:
+        //
+        //     if height <= MAX_RESOLUTION / 4
+        let _ = TextCanvas::new(1, i32::MAX);
+    }
+
+    #[test]
+    fn for_aspect() {
+        for (width, aspect) in [(80, 1.0), (80, 16.0 / 9.0), (60, 4.0 / 3.0), (30, 0.5)] {
+            let canvas = TextCanvas::for_aspect(width, aspect);
+
+            let screen_aspect =
+                f64::from(canvas.screen.width()) / f64::from(canvas.screen.height());
+
+            assert_eq!(canvas.output.width(), width);
+            assert!(
+                (screen_aspect - aspect).abs() / aspect < 0.05,
+                "Screen aspect {screen_aspect} is too far from {aspect}."
+            );
+        }
+    }
+
+    #[test]
+    fn for_aspect_square() {
+        let canvas = TextCanvas::for_aspect(10, 1.0);
+
+        assert_eq!(canvas.output.height(), 5);
+        assert_eq!(canvas.screen.width(), canvas.screen.height());
+    }
+
+    #[test]
+    fn for_aspect_height_is_at_least_one() {
+        let canvas = TextCanvas::for_aspect(1, 100.0);
+
+        assert_eq!(canvas.output.height(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Aspect ratio must be positive, but 0 was given.")]
+    fn for_aspect_zero_panics() {
+        let _ = TextCanvas::for_aspect(10, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Aspect ratio must be positive, but NaN was given.")]
+    fn for_aspect_nan_panics() {
+        let _ = TextCanvas::for_aspect(10, f64::NAN);
+    }
+
+    #[test]
+    #[cfg(feature = "terminal")]
+    fn new_fullscreen() {
+        // Size depends on the environment.
+        let canvas = TextCanvas::new_fullscreen();
+
+        assert!(canvas.output.width() >= 1);
+        assert!(canvas.output.height() >= 1);
+    }
+
+    #[test]
+    #[cfg(feature = "terminal")]
+    fn get_terminal_size() {
+        if let Ok((width, height)) = TextCanvas::get_terminal_size() {
+            assert!(width >= 1);
+            assert!(height >= 1);
+        }
+    }
+
+    #[test]
+    fn auto_size() {
+        // This is fine, as long as this is the only test that modifies
+        // the environment.
+        env::remove_var("WIDTH");
+        env::remove_var("HEIGHT");
+
+        assert!(
+            TextCanvas::new_auto().is_err(),
+            "`WIDTH` and `HEIGHT` don't exist."
+        );
+        assert!(TextCanvas::get_auto_size().is_err());
+
+        env::set_var("WIDTH", "1");
+        env::set_var("HEIGHT", "2147483648");
+
+        assert!(
+            TextCanvas::new_auto().is_err(),
+            "`HEIGHT` is too large for an `i32`."
+        );
+        assert!(TextCanvas::get_auto_size().is_err());
+
+        env::set_var("WIDTH", "abc");
+        env::set_var("HEIGHT", "1");
+
+        assert!(TextCanvas::new_auto().is_err(), "`WIDTH` is not a number.");
+        assert!(TextCanvas::get_auto_size().is_err());
+
+        env::set_var("WIDTH", "1");
+        env::set_var("HEIGHT", "abc");
+
+        assert!(TextCanvas::new_auto().is_err(), "`HEIGHT` is not a number.");
+        assert!(TextCanvas::get_auto_size().is_err());
+
+        env::set_var("WIDTH", "12");
+        env::set_var("HEIGHT", "5");
+
+        let canvas = TextCanvas::new_auto().unwrap();
+
+        assert_eq!(canvas.output.width, 12, "Incorrect auto width.");
+        assert_eq!(canvas.output.height, 5, "Incorrect auto height.");
+        assert_eq!(TextCanvas::get_auto_size().unwrap(), (12, 5));
+    }
+
+    #[test]
+    fn builder_defaults() {
+        let canvas = TextCanvas::builder().build();
+        let default = TextCanvas::default();
+
+        assert_eq!(canvas.output.width(), default.output.width());
+        assert_eq!(canvas.output.height(), default.output.height());
+        assert!(!canvas.is_inverted);
+        assert!(!canvas.is_color_disabled);
+        assert!(canvas.background.is_empty());
+    }
+
+    #[test]
+    fn builder_configured() {
+        let canvas = TextCanvas::builder()
+            .size(7, 4)
+            .inverted(true)
+            .background(Color::new().bg_blue())
+            .build();
+
+        assert_eq!(canvas.output.width(), 7);
+        assert_eq!(canvas.output.height(), 4);
+        assert_eq!(canvas.screen.width(), 14);
+        assert_eq!(canvas.screen.height(), 16);
+        assert!(canvas.is_inverted);
+        assert_eq!(canvas.background, Color::new().bg_blue().fix());
+    }
+
+    #[test]
+    fn builder_without_color() {
+        let mut canvas = TextCanvas::builder()
+            .size(3, 1)
+            .background(Color::new().bg_blue())
+            .with_color(false)
+            .build();
+
+        canvas.set_color(Color::new().red());
+        canvas.draw_text("foo", 0, 0);
+
+        assert!(canvas.is_color_disabled);
+        assert!(canvas.background.is_empty());
+        assert_eq!(canvas.to_string(), "foo\n");
+    }
+
+    #[test]
+    fn builder_is_reusable() {
+        let mut builder = TextCanvas::builder();
+        builder.size(2, 1);
+
+        let a = builder.build();
+        let b = builder.inverted(true).build();
+
+        assert!(!a.is_inverted);
+        assert!(b.is_inverted);
+        assert_eq!(a.output.width(), b.output.width());
+    }
+
+    #[test]
+    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
+    fn builder_invalid_size_panics() {
+        let _ = TextCanvas::builder().size(0, 0).build();
+    }
+
+    #[test]
+    fn string_representation() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert_eq!(
+            canvas.to_string(),
+            format!("{canvas}"),
+            "Incorrect string representation."
+        );
+
+        assert_eq!(
+            canvas.repr(),
+            "Canvas(output=(7×4), screen=(14×16)))",
+            "Incorrect string representation.",
+        );
+    }
+
+    #[test]
+    fn shortcuts() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert_eq!(canvas.w(), 13, "Incorrect screen width.");
+        assert_eq!(canvas.h(), 15, "Incorrect screen height.");
+        assert_eq!(canvas.cx(), 7, "Incorrect screen center-X.");
+        assert_eq!(canvas.cy(), 8, "Incorrect screen center-Y.");
+    }
+
+    #[test]
+    fn shortcuts_unsigned() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert_eq!(canvas.uw(), 13, "Incorrect screen width.");
+        assert_eq!(canvas.uh(), 15, "Incorrect screen height.");
+        assert_eq!(canvas.ucx(), 7, "Incorrect screen center-X.");
+        assert_eq!(canvas.ucy(), 8, "Incorrect screen center-Y.");
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn shortcuts_float() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert_eq!(canvas.fw(), 13.0, "Incorrect screen width.");
+        assert_eq!(canvas.fh(), 15.0, "Incorrect screen height.");
+        assert_eq!(canvas.fcx(), 7.0, "Incorrect screen center-X.");
+        assert_eq!(canvas.fcy(), 8.0, "Incorrect screen center-Y.");
+    }
+
+    #[test]
+    fn screen_to_output() {
+        let canvas = TextCanvas::new(15, 5);
+
+        assert_eq!(canvas.screen_to_output(0, 0), (0, 0));
+        assert_eq!(canvas.screen_to_output(1, 3), (0, 0));
+        assert_eq!(canvas.screen_to_output(2, 4), (1, 1));
+        assert_eq!(canvas.screen_to_output(canvas.w(), canvas.h()), (14, 4));
+    }
+
+    #[test]
+    fn screen_to_output_negative_rounds_down() {
+        let canvas = TextCanvas::new(15, 5);
+
+        assert_eq!(canvas.screen_to_output(-1, -1), (-1, -1));
+        assert_eq!(canvas.screen_to_output(-2, -4), (-1, -1));
+        assert_eq!(canvas.screen_to_output(-3, -5), (-2, -2));
+    }
+
+    #[test]
+    fn output_to_screen() {
+        let canvas = TextCanvas::new(15, 5);
+
+        assert_eq!(canvas.output_to_screen(0, 0), (0, 0));
+        assert_eq!(canvas.output_to_screen(3, 2), (6, 8));
+        assert_eq!(canvas.output_to_screen(-1, -1), (-2, -4));
+    }
+
+    #[test]
+    fn screen_to_output_round_trip() {
+        let canvas = TextCanvas::new(15, 5);
+
+        for (x, y) in [(0, 0), (5, 3), (14, 4), (-3, -2)] {
+            let (sx, sy) = canvas.output_to_screen(x, y);
+            assert_eq!(canvas.screen_to_output(sx, sy), (x, y));
+            assert_eq!(canvas.screen_to_output(sx + 1, sy + 3), (x, y));
+        }
+    }
+
+    #[test]
+    fn min_output_size_empty_canvas() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert_eq!(canvas.min_output_size(), (0, 0));
+    }
+
+    #[test]
+    fn min_output_size_partially_drawn() {
+        let mut canvas = TextCanvas::new(7, 4);
+
+        canvas.set_pixel(3, 5, true);
+        assert_eq!(canvas.min_output_size(), (1, 1));
+
+        canvas.set_pixel(4, 5, true);
+        assert_eq!(canvas.min_output_size(), (1, 1));
+
+        canvas.set_pixel(5, 9, true);
+        assert_eq!(canvas.min_output_size(), (2, 2));
+    }
+
+    #[test]
+    fn min_output_size_full_canvas() {
+        let mut canvas = TextCanvas::new(7, 4);
+
+        canvas.fill();
+
+        assert_eq!(canvas.min_output_size(), (7, 4));
+    }
+
+    #[test]
+    fn min_output_size_ignores_text() {
+        let mut canvas = TextCanvas::new(7, 4);
+
+        canvas.draw_text("foo", 2, 2);
+
+        assert_eq!(canvas.min_output_size(), (0, 0));
+    }
+
+    #[test]
+    fn check_output_bounds() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert!(canvas.check_output_bounds(0, 0));
+        assert!(canvas.check_output_bounds(6, 0));
+        assert!(canvas.check_output_bounds(6, 3));
+        assert!(canvas.check_output_bounds(0, 3));
+
+        assert!(!canvas.check_output_bounds(0, -1));
+        assert!(!canvas.check_output_bounds(7, 0));
+        assert!(!canvas.check_output_bounds(6, 4));
+        assert!(!canvas.check_output_bounds(-1, 3));
+    }
+
+    #[test]
+    fn check_screen_bounds() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert!(canvas.check_screen_bounds(0, 0));
+        assert!(canvas.check_screen_bounds(13, 0));
+        assert!(canvas.check_screen_bounds(13, 15));
+        assert!(canvas.check_screen_bounds(0, 15));
+
+        assert!(!canvas.check_screen_bounds(0, -1));
+        assert!(!canvas.check_screen_bounds(14, 0));
+        assert!(!canvas.check_screen_bounds(13, 16));
+        assert!(!canvas.check_screen_bounds(-1, 15));
+    }
+
+    #[test]
+    fn turn_all_pixels_on() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        for x in 0..canvas.screen.width() {
+            for y in 0..canvas.screen.height() {
+                canvas.set_pixel(x, y, true);
+            }
+        }
+
+        assert_eq!(canvas.to_string(), "⣿⣿\n⣿⣿\n", "Output not fully on.");
+    }
+
+    #[test]
+    fn get_pixel() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        assert_eq!(
+            canvas.get_pixel(3, 2),
+            Some(false),
+            "Pixel should be turned off."
+        );
+
+        canvas.set_pixel(3, 2, true);
+
+        assert_eq!(
+            canvas.get_pixel(3, 2),
+            Some(true),
+            "Pixel should be turned on."
+        );
+    }
+
+    #[test]
+    fn get_pixel_with_overflow() {
+        let canvas = TextCanvas::new(1, 1);
+
+        assert_eq!(canvas.get_pixel(-1, 0), None, "Overflow should be None.");
+        assert_eq!(canvas.get_pixel(0, -1), None, "Overflow should be None.");
+        assert_eq!(canvas.get_pixel(-1, -1), None, "Overflow should be None.");
+
+        assert_eq!(
+            canvas.get_pixel(canvas.screen.width(), 0),
+            None,
+            "Overflow should be None."
+        );
+        assert_eq!(
+            canvas.get_pixel(0, canvas.screen.height()),
+            None,
+            "Overflow should be None."
+        );
+        assert_eq!(
+            canvas.get_pixel(canvas.screen.width(), canvas.screen.height()),
+            None,
+            "Overflow should be None.",
+        );
+    }
+
+    #[test]
+    fn has_text_at_with_mixed_text_and_pixels() {
+        let mut canvas = TextCanvas::new(4, 2);
+
+        canvas.fill();
+        canvas.draw_text("a b", 0, 1);
+
+        assert!(!canvas.has_text_at(0, 0), "Pixels are not text.");
+        assert!(canvas.has_text_at(0, 1));
+        assert!(!canvas.has_text_at(1, 1), "Spaces are not text.");
+        assert!(canvas.has_text_at(2, 1));
+        assert!(!canvas.has_text_at(3, 1));
+    }
+
+    #[test]
+    fn has_text_at_not_textual() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.fill();
+
+        assert!(!canvas.is_textual());
+        assert!(!canvas.has_text_at(0, 0));
+    }
+
+    #[test]
+    fn has_text_at_with_overflow() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.draw_text("a", 0, 0);
+
+        assert!(!canvas.has_text_at(-1, 0));
+        assert!(!canvas.has_text_at(0, -1));
+        assert!(!canvas.has_text_at(1, 0));
+        assert!(!canvas.has_text_at(0, 1));
+    }
+
+    #[test]
+    fn get_pixel_on_boundaries() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.buffer = vec![
+            vec![true, false],
+            vec![false, false],
+            vec![false, false],
+            vec![false, true],
+        ];
+
+        assert_eq!(canvas.get_pixel(0, 0), Some(true), "Incorrect pixel value.");
+        assert_eq!(
+            canvas.get_pixel(canvas.screen.width() - 1, canvas.screen.height() - 1),
+            Some(true),
+            "Incorrect pixel value.",
+        );
+    }
+
+    #[test]
+    fn count_neighbors() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.buffer = vec![
+            vec![true, true, false, false],
+            vec![false, true, false, true],
+            vec![true, false, false, true],
+            vec![false, false, false, true],
+        ];
+
+        assert_eq!(canvas.count_neighbors(1, 1), 3);
+        assert_eq!(canvas.count_neighbors(2, 2), 4);
+        assert_eq!(canvas.count_neighbors(0, 0), 2);
+        assert_eq!(canvas.count_neighbors(3, 3), 1);
+    }
+
+    #[test]
+    fn count_neighbors_out_of_bounds() {
+        let mut canvas = TextCanvas::new(1, 1);
+        canvas.fill();
+
+        assert_eq!(canvas.count_neighbors(-1, -1), 1);
+        assert_eq!(canvas.count_neighbors(-1, 0), 2);
+        assert_eq!(canvas.count_neighbors(-5, -5), 0);
+        assert_eq!(canvas.count_neighbors(0, 1), 5);
+    }
+
+    #[test]
+    fn life_step_blinker_oscillates() {
+        let mut canvas = TextCanvas::new(2, 2);
+        canvas.stroke_line(2, 2, 2, 4);
+
+        canvas.life_step();
+
+        let mut expected = TextCanvas::new(2, 2);
+        expected.stroke_line(1, 3, 3, 3);
+        assert_eq!(canvas.buffer, expected.buffer);
+
+        canvas.life_step();
+
+        let mut expected = TextCanvas::new(2, 2);
+        expected.stroke_line(2, 2, 2, 4);
+        assert_eq!(canvas.buffer, expected.buffer);
+    }
+
+    #[test]
+    fn life_step_block_is_still() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.fill_rect(1, 1, 2, 2);
+        let before = canvas.buffer.clone();
+
+        canvas.life_step();
+
+        assert_eq!(canvas.buffer, before);
+    }
+
+    #[test]
+    fn life_step_edges_are_dead() {
+        let mut canvas = TextCanvas::new(1, 1);
+        // Would be a blinker, if cells outside the canvas existed.
+        canvas.stroke_line(0, 0, 0, 2);
+
+        canvas.life_step();
+
+        assert_eq!(
+            canvas.buffer,
+            [[false, false], [true, true], [false, false], [false, false],]
+        );
+    }
+
+    #[test]
+    fn life_step_keeps_color_and_text() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_color(Color::new().red());
+        canvas.fill_rect(1, 1, 2, 2);
+        canvas.draw_text("a", 1, 0);
+        let before = canvas.to_string();
+
+        canvas.life_step();
+
+        assert_eq!(canvas.to_string(), before);
+    }
+
+    #[test]
+    fn set_pixel() {
+        let mut canvas = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut canvas);
+
+        assert_eq!(
+            canvas.buffer,
+            [
+                [true, false, false, false, false, false],
+                [false, true, false, false, false, false],
+                [false, false, true, false, false, false],
+                [false, false, false, true, false, false],
+                [false, false, false, false, true, false],
+                [false, false, false, false, false, true],
+                [false, false, false, false, false, false],
+                [false, false, false, false, false, false],
+            ],
+            "Incorrect buffer content.",
+        );
+    }
+
+    #[test]
+    fn set_pixel_with_overflow() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.set_pixel(-1, 0, true);
+        canvas.set_pixel(0, -1, true);
+        canvas.set_pixel(-1, -1, true);
+
+        canvas.set_pixel(canvas.screen.width(), 0, true);
+        canvas.set_pixel(0, canvas.screen.height(), true);
+        canvas.set_pixel(canvas.screen.width(), canvas.screen.height(), true);
+
+        assert_eq!(
+            canvas.buffer,
+            [
+                [false, false],
+                [false, false],
+                [false, false],
+                [false, false],
+            ],
+            "No pixel should be turned on.",
+        );
+    }
+
+    #[test]
+    fn set_pixel_on_boundaries() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.set_pixel(0, 0, true);
+        canvas.set_pixel(canvas.screen.width() - 1, canvas.screen.height() - 1, true);
+
+        assert_eq!(
+            canvas.buffer,
+            [[true, false], [false, false], [false, false], [false, true],],
+            "Incorrect buffer content.",
+        );
+    }
+
+    #[test]
+    fn set_pixels_matches_set_pixel() {
+        let points = [(0, 0), (3, 2), (5, 7), (-1, 3), (6, 8), (2, 5), (3, 2)];
+
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_color(Color::new().red());
+        for &(x, y) in &points {
+            canvas.set_pixel(x, y, true);
+        }
+        canvas.set_color(Color::new().green());
+        canvas.set_pixel(5, 7, false);
+
+        let mut batch = TextCanvas::new(3, 2);
+        batch.set_color(Color::new().red());
+        batch.set_pixels(&points, true);
+        batch.set_color(Color::new().green());
+        batch.set_pixels(&[(5, 7)], false);
+
+        assert_eq!(batch.buffer, canvas.buffer);
+        assert_eq!(batch.color_buffer, canvas.color_buffer);
+    }
+
+    #[test]
+    fn set_pixels_inverted_matches_set_pixel() {
+        let points = [(0, 0), (1, 1), (2, 2), (3, 3)];
+
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_color(Color::new().red());
+        canvas.fill();
+        canvas.invert();
+        for &(x, y) in &points {
+            canvas.set_pixel(x, y, true);
+        }
+
+        let mut batch = TextCanvas::new(2, 1);
+        batch.set_color(Color::new().red());
+        batch.fill();
+        batch.invert();
+        batch.set_pixels(&points, true);
+
+        assert_eq!(batch.buffer, canvas.buffer);
+        assert_eq!(batch.color_buffer, canvas.color_buffer);
+        assert_eq!(batch.to_string(), "⣮⡻\n");
+    }
+
+    #[test]
+    fn set_pixels_empty() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.set_pixels(&[], true);
+
+        assert_eq!(canvas.to_string(), "⠀⠀\n");
+    }
+
+    #[test]
+    fn get_as_string() {
+        let mut canvas = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut canvas);
+
+        assert_eq!(canvas.to_string(), "⠑⢄⠀\n⠀⠀⠑\n", "Incorrect output string.");
+    }
+
+    #[test]
+    fn render_into() {
+        let mut canvas = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut canvas);
+
+        let mut buffer = String::new();
+        canvas.render_into(&mut buffer);
+
+        assert_eq!(buffer, canvas.to_string());
+    }
+
+    #[test]
+    fn render_into_reuses_buffer() {
+        let mut canvas = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut canvas);
+
+        let mut buffer = String::from("previous frame");
+        canvas.render_into(&mut buffer);
+        let first = buffer.clone();
+        let capacity = buffer.capacity();
+        canvas.render_into(&mut buffer);
+
+        assert_eq!(first, "⠑⢄⠀\n⠀⠀⠑\n");
+        assert_eq!(buffer, first);
+        assert_eq!(buffer.capacity(), capacity);
+    }
+
+    #[test]
+    fn render_is_cached() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_render_cache(true);
+        stroke_line_accros_canvas(&mut canvas);
+
+        assert!(canvas.render_cache.get().is_none());
+
+        let first = canvas.to_string();
+        let cached = canvas.render_cache.get().unwrap().as_ptr();
+        let second = canvas.to_string();
+
+        assert_eq!(first, second);
+        assert_eq!(canvas.render_cache.get().unwrap().as_ptr(), cached);
+    }
+
+    #[test]
+    fn eq() {
+        let mut a = TextCanvas::new(3, 2);
+        let mut b = TextCanvas::new(3, 2);
+
+        assert_eq!(a, b);
+
+        stroke_line_accros_canvas(&mut a);
+        assert_ne!(a, b);
+
+        stroke_line_accros_canvas(&mut b);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn eq_different_sizes() {
+        assert_ne!(TextCanvas::new(3, 2), TextCanvas::new(2, 3));
+        assert_ne!(TextCanvas::new(3, 2), TextCanvas::new(3, 1));
+    }
+
+    #[test]
+    fn eq_color_is_part_of_equality() {
+        let mut a = TextCanvas::new(3, 1);
+        let mut b = TextCanvas::new(3, 1);
+
+        a.set_color(Color::new().red());
+        a.set_pixel(0, 0, true);
+        b.set_color(Color::new().green());
+        b.set_pixel(0, 0, true);
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn eq_unused_color_buffer_is_not_part_of_equality() {
+        let a = TextCanvas::new(3, 1);
+        let mut b = TextCanvas::new(3, 1);
+
+        b.set_color(Color::new().red());
+
+        assert!(b.is_colorized());
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn eq_text_is_part_of_equality() {
+        let mut a = TextCanvas::new(3, 1);
+        let mut b = TextCanvas::new(3, 1);
+
+        a.draw_text("a", 0, 0);
+        b.draw_text("b", 0, 0);
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn eq_pixels_under_text_are_part_of_equality() {
+        let mut a = TextCanvas::new(3, 1);
+        let mut b = TextCanvas::new(3, 1);
+
+        a.draw_text("abc", 0, 0);
+        b.draw_text("abc", 0, 0);
+        b.fill();
+
+        assert_eq!(a.to_string(), b.to_string());
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn eq_settings_are_not_part_of_equality() {
+        let a = TextCanvas::new(3, 1);
+        let mut b = TextCanvas::new(3, 1);
+
+        b.invert();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn render_cache_is_invalidated_on_mutation() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_render_cache(true);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n⠀⠀⠀\n");
+
+        canvas.set_pixel(0, 0, true);
+        assert!(canvas.render_cache.get().is_none());
+        assert_eq!(canvas.to_string(), "⠁⠀⠀\n⠀⠀⠀\n");
+
+        canvas.draw_text("a", 1, 0);
+        assert_eq!(canvas.to_string(), "⠁a⠀\n⠀⠀⠀\n");
+
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(5, 7, true);
+        assert_eq!(canvas.to_string(), "⠁a⠀\n⠀⠀\x1b[0;31m⢀\x1b[0m\n");
+
+        canvas.clear();
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn render_cache_out_of_bounds_does_not_invalidate() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_render_cache(true);
+
+        let _ = canvas.to_string();
+        canvas.set_pixel(-1, 0, true);
+
+        assert!(canvas.render_cache.get().is_some());
+    }
+
+    #[test]
+    fn render_cache_set_color_does_not_invalidate() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_render_cache(true);
+
+        let _ = canvas.to_string();
+        canvas.set_color(Color::new().red());
+
+        assert!(canvas.render_cache.get().is_some());
+    }
+
+    #[test]
+    fn render_cache_is_disabled_by_default() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n⠀⠀⠀\n");
+
+        canvas.buffer[0][0] = true;
+        canvas.text_buffer = vec![vec![String::new(), String::from("a"), String::new()]; 2];
+
+        assert!(canvas.render_cache.get().is_none());
+        assert_eq!(canvas.to_string(), "⠁a⠀\n⠀a⠀\n");
+    }
+
+    #[test]
+    fn render_cache_disable_drops_cache() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_render_cache(true);
+
+        let _ = canvas.to_string();
+        canvas.set_render_cache(false);
+
+        assert!(canvas.render_cache.get().is_none());
+        let _ = canvas.to_string();
+        assert!(canvas.render_cache.get().is_none());
+    }
+
+    #[test]
+    fn render_into_uses_cache() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_render_cache(true);
+        stroke_line_accros_canvas(&mut canvas);
+
+        let rendered = canvas.to_string();
+        let mut buffer = String::new();
+        canvas.render_into(&mut buffer);
+
+        assert_eq!(buffer, rendered);
+    }
+
+    #[test]
+    fn pixels_to_string() {
+        let mut canvas = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut canvas);
+
+        canvas.draw_text("abc", 0, 0);
+        canvas.draw_text("def", 0, 1);
+
+        assert_eq!(canvas.to_string(), "abc\ndef\n");
+        assert_eq!(canvas.pixels_to_string(), "⠑⢄⠀\n⠀⠀⠑\n");
+    }
+
+    #[test]
+    fn pixels_to_string_with_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(0, 0, true);
+
+        canvas.set_color(Color::new().green());
+        canvas.draw_text("ab", 0, 0);
+
+        assert_eq!(canvas.pixels_to_string(), "\x1b[0;31m⠁\x1b[0m⠀\n");
+    }
+
+    #[test]
+    fn text_to_string() {
+        let mut canvas = TextCanvas::new(7, 3);
+        stroke_line_accros_canvas(&mut canvas);
+
+        canvas.draw_text("x=0", 0, 0);
+        canvas.set_color(Color::new().red());
+        canvas.draw_text("y", 6, 2);
+
+        assert_eq!(
+            canvas.text_to_string(),
+            "x=0    \n       \n      \x1b[0;31my\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn text_to_string_non_textual() {
+        let mut canvas = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut canvas);
+
+        assert!(!canvas.is_textual());
+        assert_eq!(canvas.text_to_string(), "   \n   \n");
+    }
+
+    #[test]
+    fn to_ascii_preview_empty() {
+        let canvas = TextCanvas::new(3, 2);
+
+        assert_eq!(canvas.to_ascii_preview(), "   \n   \n");
+    }
+
+    #[test]
+    fn to_ascii_preview_filled() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.fill();
+
+        assert_eq!(canvas.to_ascii_preview(), "@@@\n@@@\n");
+    }
+
+    #[test]
+    fn to_ascii_preview_density() {
+        let mut canvas = TextCanvas::new(9, 1);
+        // Cell `n` has `n` pixels turned on.
+        for n in 0..9 {
+            for pixel in 0..n {
+                canvas.set_pixel(n * 2 + pixel % 2, pixel / 2, true);
+            }
+        }
+
+        assert_eq!(canvas.to_ascii_preview(), " .:-=+*#@\n");
+    }
+
+    #[test]
+    fn to_ascii_preview_ignores_color_and_text() {
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.set_color(Color::new().red());
+        canvas.fill_rect(0, 0, 2, 4);
+        canvas.draw_text("a", 1, 0);
+
+        assert_eq!(canvas.to_ascii_preview(), "@  \n");
+    }
+
+    #[test]
+    fn to_plain_string_lines_have_output_width() {
+        let mut canvas = TextCanvas::new(12, 4);
+
+        canvas.set_color(Color::new().red());
+        canvas.fill_circle(canvas.cx(), canvas.cy(), 6);
+        canvas.set_color(Color::new().bg_blue());
+        canvas.draw_text("foo", 0, 0);
+        canvas.set_color(Color::new().rgb(1, 2, 3));
+        canvas.draw_text_boxed("bar", 2, 1, Color::new().bg_rgb(4, 5, 6));
+        canvas.draw_hyperlink("link", "https://example.com", 8, 3);
+
+        let plain = canvas.to_plain_string();
+
+        assert!(!plain.contains('\x1b'));
+        assert_eq!(plain.lines().count(), 4);
+        for line in plain.lines() {
+            assert_eq!(line.chars().count(), 12);
+        }
+    }
+
+    #[test]
+    fn to_plain_string_same_as_uncolored_render() {
+        let mut canvas = TextCanvas::new(7, 3);
+        canvas.set_color(Color::new().green());
+        canvas.stroke_line(0, 0, canvas.w(), canvas.h());
+        canvas.draw_text("abc", 2, 1);
+
+        let mut uncolored = TextCanvas::new(7, 3);
+        uncolored.stroke_line(0, 0, uncolored.w(), uncolored.h());
+        uncolored.draw_text("abc", 2, 1);
+
+        assert_eq!(canvas.to_plain_string(), uncolored.to_string());
+    }
+
+    #[test]
+    fn strip_escape_sequences() {
+        assert_eq!(TextCanvas::strip_escape_sequences("foo"), "foo");
+        assert_eq!(
+            TextCanvas::strip_escape_sequences("\x1b[0;31mf\x1b[0m\x1b[38;2;1;2;3mo\x1b[0m"),
+            "fo"
+        );
+        assert_eq!(
+            TextCanvas::strip_escape_sequences("\x1b]8;;url\x1b\\a\x1b]8;;\x1b\\"),
+            "a"
+        );
+        assert_eq!(TextCanvas::strip_escape_sequences("\x1b]0;title\x07b"), "b");
+    }
+
+    #[test]
+    fn to_code_points_matches_plain_render() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        canvas.set_color(Color::new().green());
+        canvas.stroke_circle(canvas.cx(), canvas.cy(), 7);
+        canvas.set_color(Color::new().red());
+        canvas.draw_text("foo", 6, 2);
+        canvas.draw_hyperlink("bar", "url", 0, 4);
+
+        let code_points = canvas.to_code_points();
+
+        assert_eq!(
+            code_points.len(),
+            to_usize!(canvas.output.width() * canvas.output.height())
+        );
+
+        let lines: Vec<String> = code_points
+            .chunks(canvas.output.uwidth())
+            .map(|row| {
+                row.iter()
+                    .map(|&code_point| char::from_u32(code_point).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(lines.join("\n") + "\n", canvas.to_plain_string());
+    }
+
+    #[test]
+    fn packed_bytes_round_trip() {
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.stroke_circle(canvas.cx(), canvas.cy(), 7);
+        canvas.fill_rect(3, 2, 9, 5);
+        canvas.stroke_line(0, 19, 29, 0);
+
+        let bytes = canvas.to_packed_bytes();
+
+        assert_eq!(bytes.len(), 4 + 15 * 5);
+
+        let restored = TextCanvas::from_packed_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.output.width(), 15);
+        assert_eq!(restored.output.height(), 5);
+        assert_eq!(restored.buffer, canvas.buffer);
+    }
+
+    #[test]
+    fn packed_bytes_ignore_color_and_text() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_color(Color::new().red());
+        canvas.fill();
+        canvas.draw_text("a", 0, 0);
+
+        let restored = TextCanvas::from_packed_bytes(&canvas.to_packed_bytes()).unwrap();
+
+        assert_eq!(restored.to_string(), "⣿⣿\n");
+    }
+
+    #[test]
+    fn from_packed_bytes_invalid() {
+        let error = |bytes: &[u8]| TextCanvas::from_packed_bytes(bytes).unwrap_err().0;
+
+        assert_eq!(error(&[1, 0, 1]), "packed bytes are missing the header");
+        assert_eq!(error(&[0, 0, 1, 0]), "invalid canvas size in packed bytes");
+        assert_eq!(
+            error(&[0xFF, 0xFF, 1, 0]),
+            "invalid canvas size in packed bytes"
+        );
+        assert_eq!(
+            error(&[2, 0, 1, 0, 0]),
+            "packed bytes do not match canvas size"
+        );
+    }
+
+    #[test]
+    fn render_trimmed_small_centered_shape() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        canvas.stroke_circle(canvas.cx(), canvas.cy(), 3);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⣀⡀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⡎⠀⠈⡆⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠈⠒⠊⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"
+        );
+        assert_eq!(
+            canvas.render_trimmed(),
+            "\
+⠀⣀⡀⠀
+⡎⠀⠈⡆
+⠈⠒⠊⠀
+"
+        );
+    }
+
+    #[test]
+    fn render_trimmed_includes_text_and_color() {
+        let mut canvas = TextCanvas::new(10, 4);
+
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(4, 4, true);
+        canvas.draw_text("ab", 4, 2);
+
+        assert_eq!(
+            canvas.render_trimmed(),
+            "\x1b[0;31m⠁\x1b[0m⠀⠀⠀\n⠀⠀\x1b[0;31mab\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn render_trimmed_empty_canvas() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        assert_eq!(canvas.render_trimmed(), "");
+
+        canvas.draw_text(" ", 0, 0); // Spaces are not content.
+
+        assert_eq!(canvas.render_trimmed(), "");
+    }
+
+    #[test]
+    fn render_trimmed_full_canvas_is_full_render() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        canvas.fill();
+
+        assert_eq!(canvas.render_trimmed(), canvas.to_string());
+    }
+
+    #[test]
+    fn delta_renderer_first_frame_is_full() {
+        let mut renderer = DeltaRenderer::new();
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.draw_text("foo", 0, 0);
+
+        assert_eq!(renderer.render(&canvas), "foo\n");
+    }
+
+    #[test]
+    fn delta_renderer_second_frame_is_delta() {
+        let mut renderer = DeltaRenderer::new();
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.draw_text("foo", 0, 0);
+
+        let _ = renderer.render(&canvas);
+
+        canvas.draw_text("b", 0, 0);
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(5, 7, true);
+
+        assert_eq!(
+            renderer.render(&canvas),
+            "\x1b[1;1Hb\x1b[2;3H\x1b[0;31m⢀\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn delta_renderer_new_size_is_full() {
+        let mut renderer = DeltaRenderer::new();
+
+        let _ = renderer.render(&TextCanvas::new(3, 1));
+
+        assert_eq!(renderer.render(&TextCanvas::new(2, 1)), "⠀⠀\n");
+    }
+
+    #[test]
+    fn delta_renderer_reset() {
+        let mut renderer = DeltaRenderer::new();
+        let canvas = TextCanvas::new(2, 1);
+
+        let _ = renderer.render(&canvas);
+        assert_eq!(renderer.render(&canvas), "");
+
+        renderer.reset();
+        assert_eq!(renderer.render(&canvas), "⠀⠀\n");
+    }
+
+    #[test]
+    fn render_fullscreen() {
+        let mut canvas = TextCanvas::new(7, 3);
+        canvas.set_color(Color::new().red());
+        canvas.stroke_line(0, 0, canvas.w(), canvas.h());
+        canvas.draw_text("foo", 2, 1);
+
+        let render = canvas.render_fullscreen();
+
+        assert!(render.starts_with("\x1b[2J\x1b[H"));
+        assert_eq!(
+            render.strip_prefix("\x1b[2J\x1b[H"),
+            Some(canvas.to_string().as_str())
+        );
+    }
+
+    #[test]
+    fn iter_cells_yields_all_output_cells() {
+        let canvas = TextCanvas::new(7, 4);
+
+        let count = canvas.iter_cells().count();
+
+        assert_eq!(
+            count,
+            to_usize!(canvas.output.width() * canvas.output.height())
+        );
+    }
+
+    #[test]
+    fn iter_cells_resolves_text_and_color() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(2, 4, true);
+        canvas.draw_text("x", 2, 1);
+
+        let cells: Vec<(i32, i32, String)> = canvas.iter_cells().collect();
+
+        assert_eq!(cells[4], (1, 1, "\x1b[0;31m⠁\x1b[0m".to_string()));
+        assert_eq!(cells[5], (2, 1, "\x1b[0;31mx\x1b[0m".to_string()));
+        assert_eq!(cells[0], (0, 0, "⠀".to_string()));
+    }
+
+    #[test]
+    fn render_delta_single_pixel() {
+        let previous = TextCanvas::new(3, 2);
+
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_pixel(5, 7, true);
+
+        let delta = canvas.render_delta(&previous);
+
+        assert_eq!(delta, "\x1b[2;3H⢀");
+        assert_eq!(delta.matches("\x1b[").count(), 1);
+    }
+
+    #[test]
+    fn render_delta_no_changes() {
+        let mut previous = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut previous);
+
+        let mut canvas = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut canvas);
+
+        assert_eq!(canvas.render_delta(&previous), "");
+    }
+
+    #[test]
+    fn render_delta_pixel_turned_off() {
+        let mut previous = TextCanvas::new(3, 2);
+        previous.set_pixel(0, 0, true);
+
+        let canvas = TextCanvas::new(3, 2);
+
+        assert_eq!(canvas.render_delta(&previous), "\x1b[1;1H⠀");
+    }
+
+    #[test]
+    fn render_delta_with_text_and_color() {
+        let previous = TextCanvas::new(3, 2);
+
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_color(Color::new().red());
+        canvas.draw_text("a", 1, 0);
+        canvas.set_pixel(0, 4, true);
+
+        assert_eq!(
+            canvas.render_delta(&previous),
+            "\x1b[1;2H\x1b[0;31ma\x1b[0m\x1b[2;1H\x1b[0;31m⠁\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn render_delta_color_change_only() {
+        let mut previous = TextCanvas::new(3, 2);
+        previous.set_pixel(0, 0, true);
+
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(0, 0, true);
+
+        assert_eq!(
+            canvas.render_delta(&previous),
+            "\x1b[1;1H\x1b[0;31m⠁\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn render_delta_size_mismatch_falls_back_to_full_render() {
+        let previous = TextCanvas::new(2, 2);
+
+        let mut canvas = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut canvas);
+
+        assert_eq!(canvas.render_delta(&previous), canvas.to_string());
+    }
+
+    #[test]
+    fn clear() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.fill();
+
+        canvas.clear();
+
+        assert_eq!(canvas.to_string(), "⠀⠀\n⠀⠀\n", "Output not empty.");
+    }
+
+    #[test]
+    fn clear_edits_buffer_in_place() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        let buffer = canvas.buffer.as_ptr();
+        let row_0 = canvas.buffer[0].as_ptr();
+        let row_1 = canvas.buffer[1].as_ptr();
+        let row_2 = canvas.buffer[2].as_ptr();
+        let row_3 = canvas.buffer[3].as_ptr();
+
+        canvas.clear();
+
+        assert_eq!(
+            buffer,
+            canvas.buffer.as_ptr(),
+            "Container should be the same as before."
+        );
+        assert_eq!(
+            row_0,
+            canvas.buffer[0].as_ptr(),
+            "Container should be the same as before."
+        );
+        assert_eq!(
+            row_1,
+            canvas.buffer[1].as_ptr(),
+            "Container should be the same as before."
+        );
+        assert_eq!(
+            row_2,
+            canvas.buffer[2].as_ptr(),
+            "Container should be the same as before."
+        );
+        assert_eq!(
+            row_3,
+            canvas.buffer[3].as_ptr(),
+            "Container should be the same as before."
+        );
+    }
+
+    #[test]
+    fn fill() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.fill();
+
+        assert_eq!(canvas.to_string(), "⣿⣿\n⣿⣿\n", "Output not full.");
+    }
+
+    #[test]
+    fn map_pixels_dilation() {
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.set_pixel(2, 0, true);
+        canvas.set_pixel(4, 3, true);
+
+        // Horizontal-neighbor OR.
+        let buffer = canvas.buffer.clone();
+        canvas.map_pixels(|x, y, state| {
+            let (ux, uy) = (to_usize!(x), to_usize!(y));
+            let left = ux > 0 && buffer[uy][ux - 1];
+            let right = ux + 1 < buffer[uy].len() && buffer[uy][ux + 1];
+            state || left || right
+        });
+
+        assert_eq!(canvas.to_string(), "⠈⢉⣀\n");
+    }
+
+    #[test]
+    fn map_pixels_receives_state() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.fill_rect(0, 0, 2, 4);
+
+        canvas.map_pixels(|_, _, state| !state);
+
+        assert_eq!(canvas.to_string(), "⠀⣿\n");
+    }
+
+    #[test]
+    fn map_pixels_is_not_affected_by_inverted_mode() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.invert();
+
+        canvas.map_pixels(|x, _, _| x < 2);
+
+        assert_eq!(canvas.to_string(), "⣿⠀\n");
+    }
+
+    #[test]
+    fn map_pixels_does_not_affect_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(0, 0, true);
+
+        canvas.map_pixels(|_, _, _| true);
+
+        assert_eq!(canvas.to_string(), "\x1b[0;31m⣿\x1b[0m⣿\n");
+    }
+
+    #[test]
+    fn invert() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        canvas.fill_rect(6, 3, 20, 15);
+
+        assert!(!canvas.is_inverted);
+
+        canvas.invert();
+        canvas.fill_rect(9, 6, 14, 9);
+
+        assert!(canvas.is_inverted);
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⠀⠀
+⠀⠀⠀⣿⡟⠛⠛⠛⠛⠛⠛⢻⣿⠀⠀
+⠀⠀⠀⣿⡇⠀⠀⠀⠀⠀⠀⢸⣿⠀⠀
+⠀⠀⠀⣿⣇⣀⣀⣀⣀⣀⣀⣸⣿⠀⠀
+⠀⠀⠀⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠀⠀
+"
+        );
+    }
+
+    #[test]
+    fn reset_state() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.invert();
+        canvas.set_color(Color::new().red());
+        canvas.clip = Some(Region {
+            x: 0,
+            y: 0,
+            width: 2,
+            height: 4,
+        });
+
+        canvas.reset_state();
+
+        assert!(!canvas.is_inverted);
+        assert_eq!(canvas.color, Color::new());
+        assert!(canvas.clip.is_none());
+
+        canvas.fill_rect(0, 0, 6, 4);
+
+        assert_eq!(canvas.to_string(), "⣿⣿⣿\n");
+    }
+
+    #[test]
+    fn reset_state_does_not_touch_buffers() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.set_color(Color::new().red());
+        canvas.stroke_line(0, 0, 5, 0);
+        canvas.draw_text("a", 0, 0);
+
+        canvas.reset_state();
+
+        assert_eq!(canvas.to_string(), "\x1b[0;31ma⠉⠉\x1b[0m\n");
+    }
+
+    #[test]
+    fn with_inversion_forced_off() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.fill();
+        canvas.invert();
+
+        canvas.with_inversion(false, |canvas| {
+            assert!(!canvas.is_inverted);
+            canvas.stroke_line(0, 0, 5, 0);
+            canvas.invert();
+        });
+
+        assert!(canvas.is_inverted);
+
+        canvas.clear();
+        canvas.with_inversion(false, |canvas| canvas.stroke_line(0, 0, 5, 0));
+
+        assert_eq!(canvas.to_string(), "⠉⠉⠉\n");
+    }
+
+    #[test]
+    fn with_inversion_forced_on() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.fill();
+        canvas.with_inversion(true, |canvas| canvas.stroke_line(0, 0, 5, 0));
+
+        assert!(!canvas.is_inverted);
+        assert_eq!(canvas.to_string(), "⣶⣶⣶\n");
+    }
+
+    #[test]
+    fn with_inversion_restores_state_on_panic() {
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.invert();
+
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            canvas.with_inversion(false, |canvas| {
+                canvas.stroke_line(0, 0, 5, 0);
+                panic!("oops");
+            });
+        }));
+
+        let payload = res.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"oops"));
+        assert!(canvas.is_inverted);
+        assert_eq!(canvas.to_string(), "⠉⠉⠉\n");
+    }
+
+    #[test]
+    fn double_invert() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        assert!(!canvas.is_inverted);
+
+        canvas.invert();
+        assert!(canvas.is_inverted);
+
+        canvas.invert();
+        assert!(!canvas.is_inverted);
+    }
+
+    #[test]
+    fn clear_not_affected_by_invert() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.invert();
+        canvas.clear();
+
+        assert_eq!(canvas.to_string(), "⠀⠀\n⠀⠀\n", "Output not empty.");
+    }
+
+    #[test]
+    fn fill_not_affected_by_invert() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.invert();
+        canvas.fill();
+
+        assert_eq!(canvas.to_string(), "⣿⣿\n⣿⣿\n", "Output not full.");
+    }
+
+    #[test]
+    fn to_braille_codes_empty() {
+        let canvas = TextCanvas::new(3, 2);
+
+        assert_eq!(canvas.to_braille_codes(), [[0x2800; 3]; 2]);
+    }
+
+    #[test]
+    fn to_braille_codes_full_cell() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.fill_rect(2, 4, 2, 4);
+
+        assert_eq!(
+            canvas.to_braille_codes(),
+            [[0x2800, 0x2800], [0x2800, 0x28FF]]
+        );
+    }
+
+    #[test]
+    fn to_braille_codes_ignores_text_and_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(0, 0, true);
+        canvas.draw_text("x", 1, 0);
+
+        assert_eq!(canvas.to_braille_codes(), [[0x2801, 0x2800]]);
+    }
+
+    #[test]
+    fn iter_buffer_by_blocks_lrtb() {
+        // This tests a private method, but this method is at the core
+        // of the output generation. Testing it helps ensure stability.
+        let mut canvas = TextCanvas::new(3, 2);
+        stroke_line_accros_canvas(&mut canvas);
+
+        assert_eq!(
+            canvas.iter_buffer_by_blocks_lrtb().collect::<Vec<_>>(),
+            [
+                [[true, false], [false, true], [false, false], [false, false],],
+                [[false, false], [false, false], [true, false], [false, true],],
+                [
+                    [false, false],
+                    [false, false],
+                    [false, false],
+                    [false, false],
+                ],
+                [
+                    [false, false],
+                    [false, false],
+                    [false, false],
+                    [false, false],
+                ],
+                [
+                    [false, false],
+                    [false, false],
+                    [false, false],
+                    [false, false],
+                ],
+                [[true, false], [false, true], [false, false], [false, false],],
+            ],
+            "Incorrect list of blocks.",
+        );
+    }
+
+    #[test]
+    fn iter_buffer() {
+        let canvas = TextCanvas::new(3, 2);
+
+        #[rustfmt::skip]
+        assert_eq!(canvas.iter_buffer().collect::<Vec<_>>(), [
+            (0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (5, 0),
+            (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1),
+            (0, 2), (1, 2), (2, 2), (3, 2), (4, 2), (5, 2),
+            (0, 3), (1, 3), (2, 3), (3, 3), (4, 3), (5, 3),
+            (0, 4), (1, 4), (2, 4), (3, 4), (4, 4), (5, 4),
+            (0, 5), (1, 5), (2, 5), (3, 5), (4, 5), (5, 5),
+            (0, 6), (1, 6), (2, 6), (3, 6), (4, 6), (5, 6),
+            (0, 7), (1, 7), (2, 7), (3, 7), (4, 7), (5, 7),
+        ], "Incorrect X and Y pairs, or in wrong order.");
+    }
+
+    #[test]
+    fn uiter_buffer() {
+        let canvas = TextCanvas::new(3, 2);
+
+        #[rustfmt::skip]
+        assert_eq!(canvas.uiter_buffer().collect::<Vec<_>>(), [
+            (0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (5, 0),
+            (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1),
+            (0, 2), (1, 2), (2, 2), (3, 2), (4, 2), (5, 2),
+            (0, 3), (1, 3), (2, 3), (3, 3), (4, 3), (5, 3),
+            (0, 4), (1, 4), (2, 4), (3, 4), (4, 4), (5, 4),
+            (0, 5), (1, 5), (2, 5), (3, 5), (4, 5), (5, 5),
+            (0, 6), (1, 6), (2, 6), (3, 6), (4, 6), (5, 6),
+            (0, 7), (1, 7), (2, 7), (3, 7), (4, 7), (5, 7),
+        ], "Incorrect X and Y pairs, or in wrong order.");
+    }
+
+    // Color.
+
+    #[test]
+    fn default_pixel_color() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        canvas.set_default_pixel_color(Color::new().green());
+        stroke_line_accros_canvas(&mut canvas);
+
+        assert!(!canvas.is_colorized());
+        assert_eq!(
+            canvas.to_string(),
+            "\
+\x1b[0;32m⠑⢄\x1b[0m⠀
+⠀⠀\x1b[0;32m⠑\x1b[0m
+"
+        );
+    }
+
+    #[test]
+    fn default_pixel_color_does_not_override_cell_colors() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        canvas.set_default_pixel_color(Color::new().green());
+        stroke_line_accros_canvas(&mut canvas);
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(5, 7, true);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+\x1b[0;32m⠑⢄\x1b[0m⠀
+⠀⠀\x1b[0;31m⢑\x1b[0m
+"
+        );
+    }
+
+    #[test]
+    fn default_pixel_color_does_not_affect_text() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.set_default_pixel_color(Color::new().green());
+        canvas.draw_text("ab", 1, 0);
+
+        assert_eq!(canvas.to_string(), "⠀ab\n");
+    }
+
+    #[test]
+    fn background() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.set_background(Color::new().bg_red());
+        canvas.set_pixel(3, 0, true);
+
+        assert_eq!(canvas.to_string(), "\x1b[0;41m⠀⠈\x1b[0m\n");
+    }
+
+    #[test]
+    fn background_keeps_cell_foreground() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.set_background(Color::new().bg_red());
+        canvas.set_color(Color::new().green());
+        canvas.set_pixel(0, 0, true);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[0;32;41m⠁\x1b[0m\x1b[0;41m⠀\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn background_does_not_override_cell_background() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.set_background(Color::new().bg_red());
+        canvas.set_color(Color::new().bg_blue());
+        canvas.set_pixel(0, 0, true);
+
+        assert_eq!(canvas.to_string(), "\x1b[0;44m⠁\x1b[0m\n");
+    }
+
+    #[test]
+    fn background_does_not_affect_text() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.set_background(Color::new().bg_red());
+        canvas.draw_text("a", 1, 0);
+
+        assert_eq!(canvas.to_string(), "\x1b[0;41m⠀\x1b[0ma\n");
+    }
+
+    #[test]
+    fn background_with_color_disabled() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.set_background(Color::new().bg_red());
+        canvas.disable_color();
+        canvas.set_background(Color::new().bg_red());
+
+        assert_eq!(canvas.to_string(), "⠀\n");
+    }
+
+    #[test]
+    fn color_buffer_size_at_init() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert!(
+            canvas.color_buffer.is_empty(),
+            "Color buffer should be empty."
+        );
+    }
+
+    #[test]
+    fn color_buffer_size_with_color() {
+        let mut canvas = TextCanvas::new(7, 4);
+
+        canvas.set_color(Color::new().bg_bright_blue());
+
+        let buffer_width = canvas.color_buffer[0].len();
+        let buffer_height = canvas.color_buffer.len();
+
+        assert_eq!(
+            buffer_width, 7,
+            "Color buffer width should match output buffer width."
+        );
+        assert_eq!(
+            buffer_height, 4,
+            "Color buffer height should match output buffer height."
+        );
+    }
+
+    #[test]
+    fn is_colorized() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        assert!(
+            !canvas.is_colorized(),
+            "Canvas should not be colorized by default."
+        );
+
+        canvas.set_color(Color::new().bg_bright_blue());
+
+        assert!(
+            canvas.is_colorized(),
+            "Canvas should be colorized after a color is set."
+        );
+    }
+
+    #[test]
+    fn disable_color() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.disable_color();
+        canvas.set_color(Color::new().bg_bright_blue());
+
+        assert!(
+            !canvas.is_colorized(),
+            "Canvas should not be colorized once color is disabled."
+        );
+        assert!(canvas.color_buffer.is_empty());
+    }
+
+    #[test]
+    fn disable_color_after_set_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_color(Color::new().red());
+        canvas.set_pixel(0, 0, true);
+
+        canvas.disable_color();
+        canvas.set_pixel(2, 0, true);
+        canvas.draw_text("a", 1, 0);
+
+        assert!(!canvas.is_colorized());
+        assert_eq!(canvas.to_string(), "⠁a\n");
+    }
+
+    #[test]
+    fn disable_color_draw_colored_canvas() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.disable_color();
+
+        let mut overlay = TextCanvas::new(2, 1);
+        overlay.set_color(Color::new().red());
+        overlay.fill_rect(0, 0, 2, 4);
+
+        canvas.draw_canvas(&overlay, 0, 0);
+        canvas.merge_canvas(&overlay, 2, 0);
+
+        assert!(!canvas.is_colorized());
+        assert_eq!(canvas.to_string(), "⣿⣿\n");
+    }
+
+    #[test]
+    fn set_row_color() {
+        let mut canvas = TextCanvas::new(2, 3);
+        let blue = Color::new().bg_blue().fix();
+
+        canvas.set_row_color(1, &blue);
+
+        assert!(canvas.is_colorized());
+        assert_eq!(
+            canvas.color_buffer,
+            [
+                [Color::new(), Color::new()],
+                [blue.clone(), blue.clone()],
+                [Color::new(), Color::new()],
+            ],
+            "Incorrect color buffer.",
+        );
+        assert_eq!(canvas.to_string(), "⠀⠀\n\x1b[0;44m⠀⠀\x1b[0m\n⠀⠀\n");
+    }
+
+    #[test]
+    fn set_col_color() {
+        let mut canvas = TextCanvas::new(3, 2);
+        let red = Color::new().red().fix();
+
+        canvas.set_col_color(2, &red);
+
+        assert!(canvas.is_colorized());
+        assert_eq!(
+            canvas.color_buffer,
+            [
+                [Color::new(), Color::new(), red.clone()],
+                [Color::new(), Color::new(), red.clone()],
+            ],
+            "Incorrect color buffer.",
+        );
+    }
+
+    #[test]
+    fn set_row_color_takes_precedence_over_col_color() {
+        let mut canvas = TextCanvas::new(2, 2);
+        let red = Color::new().red().fix();
+        let green = Color::new().green().fix();
+
+        canvas.set_row_color(0, &red);
+        canvas.set_col_color(1, &green);
+
+        assert_eq!(
+            canvas.color_buffer,
+            [[red.clone(), red.clone()], [Color::new(), green.clone()],],
+            "Incorrect color buffer.",
+        );
+
+        canvas.set_row_color(0, &Color::new());
+
+        assert_eq!(
+            canvas.color_buffer,
+            [[Color::new(), green.clone()], [Color::new(), green.clone()],],
+            "Incorrect color buffer.",
+        );
+    }
+
+    #[test]
+    fn set_row_color_colors_existing_pixels() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.set_color(Color::new().blue());
+        canvas.fill_rect(0, 0, 4, 8);
+        canvas.set_row_color(0, Color::new().red());
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[0;31m⣿⣿\x1b[0m\n\x1b[0;34m⣿⣿\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn set_row_and_col_color_colors_future_pixels() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.set_row_color(0, Color::new().red());
+        canvas.set_col_color(1, Color::new().green());
+        canvas.set_color(Color::new().blue());
+        canvas.stroke_line(0, 0, 3, 0);
+        canvas.set_pixel(2, 4, true);
+        canvas.set_pixel(0, 4, true);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[0;31m⠉⠉\x1b[0m\n\x1b[0;34m⠁\x1b[0m\x1b[0;32m⠁\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn set_row_color_survives_erasing_pixels() {
+        let mut canvas = TextCanvas::new(1, 1);
+        canvas.set_row_color(0, Color::new().bg_red());
+        canvas.set_color(Color::new().blue());
+
+        canvas.set_pixel(0, 0, true);
+        canvas.set_pixel(0, 0, false);
+
+        assert_eq!(canvas.to_string(), "\x1b[0;41m⠀\x1b[0m\n");
+    }
+
+    #[test]
+    fn set_row_color_remove() {
+        let mut canvas = TextCanvas::new(1, 1);
+        canvas.set_pixel(0, 0, true);
+
+        canvas.set_row_color(0, Color::new().red());
+        canvas.set_row_color(0, &Color::new());
+
+        assert_eq!(canvas.to_string(), "⠁\n");
+    }
+
+    #[test]
+    fn set_row_and_col_color_ignore_clip() {
+        let mut canvas = TextCanvas::new(2, 2);
+        canvas.clip = Some(Region {
+            x: 0,
+            y: 0,
+            width: 2,
+            height: 4,
+        });
+
+        canvas.set_row_color(1, Color::new().red());
+        canvas.set_col_color(1, Color::new().red());
+
+        assert_eq!(canvas.color_buffer[1][0], *Color::new().red());
+        assert_eq!(canvas.color_buffer[0][1], *Color::new().red());
+    }
+
+    #[test]
+    fn set_row_color_is_cleared() {
+        let mut canvas = TextCanvas::new(1, 1);
+        canvas.set_row_color(0, Color::new().red());
+
+        canvas.clear();
+        canvas.set_pixel(0, 0, true);
+
+        assert_eq!(canvas.color_buffer, [[Color::new()]]);
+        assert_eq!(canvas.to_string(), "⠁\n");
+    }
+
+    #[test]
+    fn set_row_color_is_kept_by_draw_canvas() {
+        let mut canvas = TextCanvas::new(2, 1);
+        let mut overlay = TextCanvas::new(2, 1);
+        overlay.set_row_color(0, Color::new().red());
+        overlay.fill_rect(0, 0, 4, 4);
+
+        canvas.draw_canvas(&overlay, 0, 0);
+
+        assert_eq!(
+            canvas.color_buffer,
+            [[Color::new().red().fix(), Color::new().red().fix()]]
+        );
+        assert_eq!(canvas.to_string(), "\x1b[0;31m⣿⣿\x1b[0m\n");
+    }
+
+    #[test]
+    fn set_row_and_col_color_out_of_bounds() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.set_row_color(-1, Color::new().red());
+        canvas.set_row_color(2, Color::new().red());
+        canvas.set_col_color(-1, Color::new().red());
+        canvas.set_col_color(2, Color::new().red());
+
+        assert!(!canvas.is_colorized());
+        assert!(canvas.row_colors.is_empty());
+        assert!(canvas.col_colors.is_empty());
+    }
+
+    #[test]
+    fn set_row_color_with_color_disabled() {
+        let mut canvas = TextCanvas::new(2, 2);
+        canvas.set_row_color(0, Color::new().red());
+        canvas.disable_color();
+
+        canvas.set_row_color(0, Color::new().red());
+        canvas.set_col_color(0, Color::new().red());
+        canvas.fill();
+
+        assert!(canvas.row_colors.is_empty());
+        assert!(canvas.col_colors.is_empty());
+        assert_eq!(canvas.to_string(), "⣿⣿\n⣿⣿\n");
+    }
+
+    #[test]
+    fn set_color() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.set_color(Color::new().bg_bright_blue());
+        canvas.set_pixel(3, 3, true);
+
+        assert_eq!(
+            canvas.color_buffer,
+            [
+                [Color::new(), Color::new().bg_bright_blue().fix()],
+                [Color::new(), Color::new()],
+            ],
+            "Incorrect color buffer.",
+        );
+    }
+
+    #[test]
+    fn set_color_multiple() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.set_color(Color::new().bg_bright_blue());
+        canvas.set_pixel(3, 3, true);
+        canvas.set_pixel(1, 5, true);
+
+        assert_eq!(
+            canvas.color_buffer,
+            [
+                [Color::new(), Color::new().bg_bright_blue().fix()],
+                [Color::new().bg_bright_blue().fix(), Color::new()],
+            ],
+            "Incorrect color buffer.",
+        );
+    }
+
+    #[test]
+    fn set_color_override() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.set_color(Color::new().bg_bright_blue());
+        canvas.set_pixel(3, 3, true);
+        canvas.set_pixel(1, 5, true);
+
+        canvas.set_color(Color::new().bg_bright_red());
+        canvas.set_pixel(3, 3, true);
+
+        assert_eq!(
+            canvas.color_buffer,
+            [
+                [Color::new(), Color::new().bg_bright_red().fix()],
+                [Color::new().bg_bright_blue().fix(), Color::new()],
+            ],
+            "Incorrect color buffer.",
+        );
+    }
+
+    #[test]
+    fn color_is_reset_if_pixel_turned_off() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        canvas.set_color(Color::new().bg_bright_blue());
+        canvas.set_pixel(3, 3, true);
+        canvas.set_pixel(1, 5, true);
+
+        canvas.set_pixel(3, 3, false);
+
+        assert_eq!(
+            canvas.color_buffer,
+            [
+                [Color::new(), Color::new()],
+                [Color::new().bg_bright_blue().fix(), Color::new()],
+            ],
+            "Incorrect color buffer.",
+        );
+    }
+
+    #[test]
+    fn render_coalesces_same_color_cells() {
+        let mut canvas = TextCanvas::new(6, 2);
+
+        canvas.set_color(Color::new().red());
+        canvas.fill_rect(0, 0, 8, 8);
+
+        // Per-cell escapes, as before coalescing.
+        let per_cell = canvas
+            .iter_cells()
+            .fold(String::new(), |mut acc, (x, _, cell)| {
+                acc.push_str(&cell);
+                if x == canvas.output.width() - 1 {
+                    acc.push('\n');
+                }
+                acc
+            });
+        let rendered = canvas.to_string();
+
+        assert_eq!(
+            rendered,
+            "\x1b[0;31m⣿⣿⣿⣿\x1b[0m⠀⠀\n\x1b[0;31m⣿⣿⣿⣿\x1b[0m⠀⠀\n"
+        );
+        assert_eq!(rendered.matches("\x1b[").count(), 4);
+        assert_eq!(per_cell.matches("\x1b[").count(), 16);
+        assert_eq!(
+            TextCanvas::strip_escape_sequences(&rendered),
+            TextCanvas::strip_escape_sequences(&per_cell)
+        );
+    }
+
+    #[test]
+    fn render_resets_between_different_colors() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.set_color(Color::new().bold());
+        canvas.draw_text("a", 0, 0);
+        canvas.set_color(Color::new().red());
+        canvas.draw_text("b", 1, 0);
+        canvas.draw_hyperlink("c", "url", 2, 0);
+
+        // Attributes must not leak from one color to the next.
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[1ma\x1b[0m\x1b[0;31mb\x1b[0m\x1b]8;;url\x1b\\\x1b[0;31mc\x1b[0m\x1b]8;;\x1b\\\n"
+        );
+    }
+
+    #[test]
+    fn get_as_string_colored() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.set_color(Color::new().bright_green());
+        stroke_line_accros_canvas(&mut canvas);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[0;92m⠑⢄\x1b[0m⠀\n⠀⠀\x1b[0;92m⠑\x1b[0m\n",
+            "Incorrect output string.",
+        );
+    }
+
+    #[test]
+    fn clear_clears_color_buffer() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        assert!(
+            canvas.color_buffer.is_empty(),
+            "Color buffer should be empty."
+        );
+
+        canvas.set_color(Color::new().bright_red());
+
+        assert_eq!(
+            canvas.color_buffer,
+            [[Color::new(), Color::new()]],
+            "Color buffer should be full of no-color.",
+        );
+
+        canvas.set_pixel(0, 0, true);
+
+        assert_eq!(
+            canvas.color_buffer,
+            [[Color::new().bright_red().fix(), Color::new()]],
+            "First pixel should be red.",
+        );
+
+        canvas.clear();
+
+        assert_eq!(
+            canvas.color_buffer,
+            [[Color::new(), Color::new()]],
+            "Color buffer should be full of no-color.",
+        );
+    }
+
+    #[test]
+    fn clear_edits_color_buffer_in_place() {
+        let mut canvas = TextCanvas::new(2, 2);
+        canvas.set_color(Color::new().bright_red());
+
+        let color_buffer = canvas.color_buffer.as_ptr();
+        let row_0 = canvas.color_buffer[0].as_ptr();
+        let row_1 = canvas.color_buffer[1].as_ptr();
+
+        canvas.clear();
+
+        assert_eq!(
+            color_buffer,
+            canvas.color_buffer.as_ptr(),
+            "Container should be the same as before."
+        );
+        assert_eq!(
+            row_0,
+            canvas.color_buffer[0].as_ptr(),
+            "Container should be the same as before."
+        );
+        assert_eq!(
+            row_1,
+            canvas.color_buffer[1].as_ptr(),
+            "Container should be the same as before."
+        );
+    }
+
+    // Text.
+
+    #[test]
+    fn text_buffer_size_at_init() {
+        let canvas = TextCanvas::new(7, 4);
+
+        assert!(
+            canvas.text_buffer.is_empty(),
+            "Text buffer should be empty."
+        );
+    }
+
+    #[test]
+    fn text_buffer_size_with_color() {
+        let mut canvas = TextCanvas::new(7, 4);
+
+        canvas.draw_text("foo", 0, 0);
+
+        let buffer_width = canvas.text_buffer[0].len();
+        let buffer_height = canvas.text_buffer.len();
+
+        assert_eq!(
+            buffer_width, 7,
+            "Text buffer width should match output buffer width."
+        );
+        assert_eq!(
+            buffer_height, 4,
+            "Text buffer height should match output buffer height."
+        );
+    }
+
+    #[test]
+    fn is_textual() {
+        let mut canvas = TextCanvas::new(2, 2);
+
+        assert!(
+            !canvas.is_colorized(),
+            "Canvas should not be textual by default."
+        );
+
+        canvas.draw_text("hi", 0, 0);
+
+        assert!(
+            canvas.is_textual(),
+            "Canvas should be textual after text is drawn."
+        );
+    }
+
+    #[test]
+    fn draw_text() {
+        let mut canvas = TextCanvas::new(5, 1);
+
+        canvas.draw_text("bar", 1, 0);
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["", "b", "a", "r", ""]],
+            "Incorrect text buffer."
+        );
+    }
+
+    #[test]
+    fn draw_text_aligned_left() {
+        let mut canvas = TextCanvas::new(7, 1);
+
+        canvas.draw_text_aligned("abc", 3, 0, Align::Left);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀abc⠀\n");
+    }
+
+    #[test]
+    fn draw_text_aligned_center() {
+        let mut canvas = TextCanvas::new(7, 2);
+
+        canvas.draw_text_aligned("abc", 3, 0, Align::Center);
+        canvas.draw_text_aligned("abcd", 3, 1, Align::Center);
+
+        assert_eq!(canvas.to_string(), "⠀⠀abc⠀⠀\n⠀abcd⠀⠀\n");
+    }
+
+    #[test]
+    fn draw_text_aligned_right() {
+        let mut canvas = TextCanvas::new(7, 1);
+
+        canvas.draw_text_aligned("abc", 3, 0, Align::Right);
+
+        assert_eq!(canvas.to_string(), "⠀abc⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn draw_text_aligned_counts_chars_not_bytes() {
+        let mut canvas = TextCanvas::new(7, 1);
+
+        canvas.draw_text_aligned("été", 6, 0, Align::Right);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀⠀été\n");
+    }
+
+    #[test]
+    fn draw_text_aligned_with_overflow() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.draw_text_aligned("abcde", 0, 0, Align::Center);
+
+        assert_eq!(canvas.to_string(), "cde\n");
+    }
+
+    #[test]
+    fn text_width_ascii() {
+        assert_eq!(text_width(""), 0);
+        assert_eq!(text_width("foo"), 3);
+        assert_eq!(text_width("hello, world"), 12);
+    }
+
+    #[test]
+    fn text_width_multiline() {
+        assert_eq!(text_width("foo\nbarbaz\nqux"), 6);
+        assert_eq!(text_width("foo\n"), 3);
+        assert_eq!(text_width("\nfoo"), 3);
+        assert_eq!(text_width("foo\r\nbar"), 3);
+    }
+
+    #[test]
+    fn text_width_multibyte() {
+        assert_eq!(text_width("µs"), 2);
+        assert_eq!(text_width("1.5µ"), 4);
+        assert_eq!(text_width("⣿⣿⣿"), 3);
+        assert_eq!(text_width("é\nàà"), 2);
+    }
+
+    #[test]
+    fn draw_ruler_is_dim() {
+        let mut canvas = TextCanvas::new(6, 1);
+
+        canvas.draw_ruler();
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[0;90m0\x1b[0m⠀⠀⠀⠀\x1b[0;90m·\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn draw_ruler_keeps_context_color() {
+        let mut canvas = TextCanvas::new(6, 1);
+        canvas.set_color(Color::new().red());
+
+        canvas.draw_ruler();
+        canvas.draw_text("a", 2, 0);
+
+        assert!(canvas.to_string().contains("\x1b[0;31ma\x1b[0m"));
+    }
+
+    #[test]
+    fn draw_ruler_only_replaces_covered_cells() {
+        let mut canvas = TextCanvas::builder().size(12, 6).with_color(false).build();
+        canvas.draw_text("ab cd", 0, 5);
+
+        canvas.draw_ruler();
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+0⠀⠀⠀⠀·⠀⠀⠀⠀10
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+5b⠀cd⠀⠀⠀⠀⠀⠀⠀
+"
+        );
+    }
+
+    #[test]
+    fn draw_swatch() {
+        let mut canvas = TextCanvas::new(5, 2);
+
+        canvas.draw_swatch(1, 1, 3, Color::new().green());
+
+        assert_eq!(canvas.text_buffer[1][0], "");
+        for x in 1..=3 {
+            assert_eq!(canvas.text_buffer[1][x], "\x1b[0;32m█\x1b[0m");
+        }
+        assert_eq!(canvas.text_buffer[1][4], "");
+        assert!(canvas.text_buffer[0].iter().all(String::is_empty));
+    }
+
+    #[test]
+    fn draw_swatch_with_overflow() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.draw_swatch(-1, 0, 2, Color::new().red());
+        canvas.draw_swatch(2, 0, 5, Color::new().red());
+        canvas.draw_swatch(0, 1, 3, Color::new().red());
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[0;31m█\x1b[0m⠀\x1b[0;31m█\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn draw_swatch_with_color_disabled() {
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.disable_color();
+
+        canvas.draw_swatch(0, 0, 2, Color::new().red());
+
+        assert_eq!(canvas.to_string(), "██⠀\n");
+    }
+
+    #[test]
+    fn draw_swatch_does_not_change_context_color() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.draw_swatch(0, 0, 1, Color::new().red());
+        canvas.draw_text("a", 1, 0);
+
+        assert_eq!(canvas.to_string(), "\x1b[0;31m█\x1b[0ma⠀\n");
+    }
+
+    #[test]
+    fn progress_bar_empty() {
+        let bar = TextCanvas::progress_bar(4, 0.0, &Color::new(), Color::new().red());
+
+        assert_eq!(bar, "\x1b[0;31m█\x1b[0m".repeat(4));
+    }
+
+    #[test]
+    fn progress_bar_half_with_partial_block() {
+        let bar = TextCanvas::progress_bar(3, 0.5, &Color::new(), &Color::new());
+
+        assert_eq!(bar, "█▌█");
+    }
+
+    #[test]
+    fn progress_bar_partial_blocks() {
+        let bars: Vec<String> = (0..=8)
+            .map(|i| TextCanvas::progress_bar(1, f64::from(i) / 8.0, &Color::new(), &Color::new()))
+            .collect();
+
+        assert_eq!(bars, ["█", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"]);
+    }
+
+    #[test]
+    fn progress_bar_full() {
+        let bar = TextCanvas::progress_bar(4, 1.0, Color::new().green(), &Color::new());
+
+        assert_eq!(bar, "\x1b[0;32m█\x1b[0m".repeat(4));
+    }
+
+    #[test]
+    fn progress_bar_fraction_out_of_bounds() {
+        let full = TextCanvas::progress_bar(2, 1.0, Color::new().green(), Color::new().red());
+        let empty = TextCanvas::progress_bar(2, 0.0, Color::new().green(), Color::new().red());
+
+        let bar = |fraction| {
+            TextCanvas::progress_bar(2, fraction, Color::new().green(), Color::new().red())
+        };
+
+        assert_eq!(bar(1.5), full);
+        assert_eq!(bar(-0.5), empty);
+        assert_eq!(bar(f64::NAN), empty);
+    }
+
+    #[test]
+    fn progress_bar_width_zero() {
+        assert_eq!(
+            TextCanvas::progress_bar(0, 0.5, &Color::new(), &Color::new()),
+            ""
+        );
+        assert_eq!(
+            TextCanvas::progress_bar(-1, 0.5, &Color::new(), &Color::new()),
+            ""
+        );
+    }
+
+    #[test]
+    fn fill_text() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        canvas.fill_text('·');
+
+        assert_eq!(canvas.text_buffer, [["·", "·", "·"], ["·", "·", "·"]]);
+    }
+
+    #[test]
+    fn fill_text_with_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.set_color(Color::new().red());
+        canvas.fill_text('x');
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["\x1b[0;31mx\x1b[0m", "\x1b[0;31mx\x1b[0m"]]
+        );
+    }
+
+    #[test]
+    fn fill_text_with_space_clears_text() {
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.fill();
+        canvas.draw_text("foo", 0, 0);
+
+        canvas.fill_text(' ');
+
+        assert_eq!(canvas.text_buffer, [["", "", ""]]);
+        assert_eq!(canvas.to_string(), "⣿⣿⣿\n");
+    }
 
     #[test]
-    fn size() {
-        let surface = Surface {
-            width: 15,
-            height: 9,
-        };
+    fn draw_text_boxed() {
+        let mut canvas = TextCanvas::new(5, 1);
+
+        canvas.draw_text_boxed("abc", 1, 0, Color::new().bg_red());
+
+        assert_eq!(canvas.to_string(), "⠀\x1b[0;41mabc\x1b[0m⠀\n");
+    }
+
+    #[test]
+    fn draw_text_boxed_spaces_are_not_transparent() {
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.fill();
+
+        canvas.draw_text_boxed(" a ", 0, 0, Color::new().bg_red());
+
+        assert_eq!(canvas.to_string(), "\x1b[0;41m a \x1b[0m\n");
+    }
+
+    #[test]
+    fn draw_text_boxed_with_context_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_color(Color::new().bold().green());
+
+        canvas.draw_text_boxed("ab", 0, 0, Color::new().bg_blue());
+
+        assert_eq!(canvas.to_string(), "\x1b[1;32;44mab\x1b[0m\n");
+    }
+
+    #[test]
+    fn draw_text_boxed_with_overflow() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.draw_text_boxed("abc", -1, 0, Color::new().bg_red());
+        canvas.draw_text_boxed("abc", 0, 1, Color::new().bg_red());
+
+        assert_eq!(canvas.to_string(), "\x1b[0;41mbc\x1b[0m\n");
+    }
+
+    #[test]
+    fn draw_text_auto_contrast_white_on_dark() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.draw_text_auto_contrast("a", 0, 0, Color::new().bg_rgb(30, 30, 30));
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[0;38;2;255;255;255m\x1b[48;2;30;30;30ma\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn draw_text_auto_contrast_black_on_light() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.draw_text_auto_contrast("a", 0, 0, Color::new().bg_rgb(220, 220, 220));
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b[0;38;2;0;0;0m\x1b[48;2;220;220;220ma\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn draw_text_auto_contrast_keeps_context_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.set_color(Color::new().red());
+        canvas.draw_text_auto_contrast("a", 0, 0, Color::new().bg_rgb(0, 0, 0));
+        canvas.draw_text("b", 1, 0);
+
+        assert!(canvas.to_string().ends_with("\x1b[0;31mb\x1b[0m\n"));
+    }
+
+    #[test]
+    fn draw_text_auto_contrast_non_rgb_background() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.draw_text_auto_contrast("a", 0, 0, Color::new().bg_blue());
+
+        assert_eq!(canvas.to_string(), "\x1b[0;44ma\x1b[0m\n");
+    }
+
+    #[test]
+    fn draw_hyperlink_osc_sequences_bracket_text() {
+        let mut canvas = TextCanvas::new(7, 1);
+
+        canvas.draw_hyperlink("a b", "https://example.com", 2, 0);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀\
+\x1b]8;;https://example.com\x1b\\a\x1b]8;;\x1b\\\
+\x1b]8;;https://example.com\x1b\\ \x1b]8;;\x1b\\\
+\x1b]8;;https://example.com\x1b\\b\x1b]8;;\x1b\\\
+⠀⠀\n"
+        );
+    }
+
+    #[test]
+    fn draw_hyperlink_single_char() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.draw_hyperlink("x", "url", 0, 0);
+
+        assert_eq!(canvas.to_string(), "\x1b]8;;url\x1b\\x\x1b]8;;\x1b\\\n");
+    }
+
+    #[test]
+    fn draw_hyperlink_with_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        canvas.set_color(Color::new().red());
+        canvas.draw_hyperlink("ab", "url", 0, 0);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+\x1b]8;;url\x1b\\\x1b[0;31ma\x1b[0m\x1b]8;;\x1b\\\
+\x1b]8;;url\x1b\\\x1b[0;31mb\x1b[0m\x1b]8;;\x1b\\\n"
+        );
+    }
+
+    #[test]
+    fn draw_hyperlink_with_overflow() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        canvas.draw_hyperlink("abcd", "url", -1, 0);
+        canvas.draw_hyperlink("foo", "url", 0, 1);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+\x1b]8;;url\x1b\\b\x1b]8;;\x1b\\\
+\x1b]8;;url\x1b\\c\x1b]8;;\x1b\\\
+\x1b]8;;url\x1b\\d\x1b]8;;\x1b\\\n"
+        );
+    }
+
+    #[test]
+    fn draw_hyperlink_overwritten_cells_do_not_leak_link() {
+        let mut canvas = TextCanvas::new(4, 1);
+
+        canvas.draw_hyperlink("abc", "url", 0, 0);
+        canvas.draw_text("xy", 2, 0);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+\x1b]8;;url\x1b\\a\x1b]8;;\x1b\\\
+\x1b]8;;url\x1b\\b\x1b]8;;\x1b\\\
+xy\n"
+        );
+    }
+
+    #[test]
+    fn draw_hyperlink_escapes_url() {
+        let mut canvas = TextCanvas::new(1, 1);
+
+        canvas.draw_hyperlink("x", "a\x1b]0;pwned\x07 é%", 0, 0);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\x1b]8;;a%1B]0;pwned%07 %C3%A9%\x1b\\x\x1b]8;;\x1b\\\n"
+        );
+    }
+
+    #[test]
+    fn stroke_polyline_thick_straight_line() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        canvas.stroke_polyline_thick(&[(2, 10), (27, 10)], 3, LineJoin::Miter);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"
+        );
+    }
+
+    #[test]
+    fn stroke_polyline_thick_thin_and_empty() {
+        let points = [(0, 0), (11, 7), (20, 2)];
+        let mut canvas = TextCanvas::new(15, 5);
+        let mut expected = TextCanvas::new(15, 5);
+
+        canvas.stroke_polyline_thick(&points, 1, LineJoin::Round);
+        canvas.stroke_polyline_thick(&points, 0, LineJoin::Round);
+        canvas.stroke_polyline_thick(&[(5, 5)], 3, LineJoin::Round);
+        expected.stroke_line(0, 0, 11, 7);
+        expected.stroke_line(11, 7, 20, 2);
+
+        assert_eq!(canvas.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn stroke_polyline_thick_miter_right_angle() {
+        let points = [(3, 3), (20, 3), (20, 16)];
+        let mut miter = TextCanvas::new(12, 5);
+        let mut bevel = TextCanvas::new(12, 5);
+
+        miter.stroke_polyline_thick(&points, 3, LineJoin::Miter);
+        bevel.stroke_polyline_thick(&points, 3, LineJoin::Bevel);
+
+        // Square corner.
+        assert_eq!(miter.get_pixel(21, 2), Some(true));
+        assert_eq!(bevel.get_pixel(21, 2), Some(false));
+    }
+
+    #[test]
+    fn stroke_polyline_thick_sharp_miter_falls_back_to_bevel() {
+        let points = [(2, 13), (20, 10), (2, 7)];
+        let mut miter = TextCanvas::new(15, 5);
+        let mut bevel = TextCanvas::new(15, 5);
+
+        miter.stroke_polyline_thick(&points, 5, LineJoin::Miter);
+        bevel.stroke_polyline_thick(&points, 5, LineJoin::Bevel);
+
+        assert_eq!(miter.to_string(), bevel.to_string());
+    }
+
+    #[test]
+    fn draw_crosshair_out_of_bounds() {
+        let mut canvas = TextCanvas::new(3, 2);
+
+        canvas.draw_crosshair(-1, 2);
+        canvas.draw_crosshair(4, 100);
+
+        assert_eq!(canvas.to_string(), "⠤⠤⡧\n⠀⠀⡇\n");
+    }
+
+    #[test]
+    fn draw_crosshair_inverted() {
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.fill();
+        canvas.invert();
+
+        canvas.draw_crosshair(1, 5);
+
+        assert_eq!(canvas.to_string(), "⡇⣿⣿\n⡅⣭⣭\n");
+    }
+
+    #[test]
+    fn draw_text_vertical() {
+        let mut canvas = TextCanvas::new(1, 5);
+
+        assert!(!canvas.is_textual());
+
+        canvas.draw_text_vertical("bar", 0, 1);
+
+        assert!(canvas.is_textual());
+
+        assert_eq!(
+            canvas.text_buffer,
+            [[""], ["b"], ["a"], ["r"], [""],],
+            "Incorrect text buffer."
+        );
+    }
+
+    #[test]
+    fn draw_text_vertical_rotated_box_drawing() {
+        let mut canvas = TextCanvas::new(1, 8);
+
+        canvas.draw_text_vertical_rotated("┌─┬─┐│└┘", 0, 0);
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["┐"], ["│"], ["┤"], ["│"], ["┘"], ["─"], ["┌"], ["└"]],
+            "Incorrect text buffer."
+        );
+    }
+
+    #[test]
+    fn draw_text_vertical_rotated_keeps_letters_upright() {
+        let mut canvas = TextCanvas::new(1, 4);
+
+        canvas.draw_text_vertical_rotated("y→0", 0, 1);
+
+        assert_eq!(
+            canvas.text_buffer,
+            [[""], ["y"], ["↓"], ["0"]],
+            "Incorrect text buffer."
+        );
+    }
+
+    #[test]
+    fn draw_text_vertical_rotated_four_times_is_identity() {
+        for char in "─│━┃═║┄┆┈┊┌┐┘└┏┓┛┗╔╗╝╚╭╮╯╰├┬┤┴┣┳┫┻╠╦╣╩→↓←↑⇒⇓⇐⇑".chars()
+        {
+            let mut rotated = char;
+            for _ in 0..4 {
+                rotated = TextCanvas::rotate_char_clockwise(rotated);
+            }
+            assert_eq!(rotated, char);
+            assert_ne!(TextCanvas::rotate_char_clockwise(char), char);
+        }
+    }
+
+    #[test]
+    fn draw_text_over_text() {
+        let mut canvas = TextCanvas::new(5, 1);
+
+        canvas.draw_text("bar", 1, 0);
+        canvas.draw_text("foo", 2, 0);
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["", "b", "f", "o", "o"]],
+            "Incorrect text buffer."
+        );
+    }
+
+    #[test]
+    fn draw_text_space_is_transparent() {
+        let mut canvas = TextCanvas::new(9, 1);
+
+        canvas.draw_text("foo bar", 1, 0);
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["", "f", "o", "o", "", "b", "a", "r", ""]],
+            "Incorrect text buffer.",
+        );
+    }
+
+    #[test]
+    fn draw_text_space_clears_text() {
+        let mut canvas = TextCanvas::new(5, 1);
+
+        canvas.draw_text("bar", 1, 0);
+        canvas.draw_text("  ", 2, 0);
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["", "b", "", "", ""]],
+            "Incorrect text buffer.",
+        );
+    }
+
+    #[test]
+    fn draw_text_with_overflow() {
+        let mut canvas = TextCanvas::new(5, 2);
+
+        // Show partially.
+        canvas.draw_text("foo", -1, 0);
+        canvas.draw_text("bar", 3, 1);
+
+        // Completely out of bounds.
+        canvas.draw_text("baz1", -10, -1);
+        canvas.draw_text("baz2", 10, -1);
+        canvas.draw_text("baz3", -10, 2);
+        canvas.draw_text("baz4", 10, 2);
 
-        assert_eq!(surface.width(), 15);
-        assert_eq!(surface.height(), 9);
+        assert_eq!(
+            canvas.text_buffer,
+            [["o", "o", "", "", ""], ["", "", "", "b", "a"],],
+            "Incorrect text buffer.",
+        );
     }
 
     #[test]
-    fn size_unsigned() {
-        let surface = Surface {
-            width: 15,
-            height: 9,
-        };
+    fn draw_text_on_boundaries() {
+        let mut canvas = TextCanvas::new(3, 3);
 
-        assert_eq!(surface.uwidth(), 15);
-        assert_eq!(surface.uheight(), 9);
+        canvas.draw_text("a", 0, 1);
+        canvas.draw_text("b", 1, 0);
+        canvas.draw_text("c", 2, 1);
+        canvas.draw_text("d", 1, 2);
+
+        assert_eq!(
+            canvas.to_string(),
+            "⠀b⠀\na⠀c\n⠀d⠀\n",
+            "Incorrect text output.",
+        );
     }
 
     #[test]
-    #[allow(clippy::float_cmp)]
-    fn size_float() {
-        let surface = Surface {
-            width: 15,
-            height: 9,
-        };
+    fn draw_text_with_color() {
+        let mut canvas = TextCanvas::new(3, 1);
 
-        assert_eq!(surface.fwidth(), 15.0);
-        assert_eq!(surface.fheight(), 9.0);
-    }
+        assert!(
+            canvas.text_buffer.is_empty(),
+            "Text buffer should be empty."
+        );
 
-    // Canvas.
+        canvas.draw_text("hi!", 0, 0);
 
-    #[test]
-    fn output_size() {
-        let canvas = TextCanvas::new(7, 4);
+        assert_eq!(
+            canvas.text_buffer,
+            [["h", "i", "!"]],
+            "Text should not be colorized.",
+        );
 
-        assert_eq!(canvas.output.width, 7, "Incorrect output width.");
-        assert_eq!(canvas.output.height, 4, "Incorrect output height.");
+        canvas.set_color(Color::new().bright_red());
+        canvas.draw_text("o!", 1, 0);
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["h", "\x1b[0;91mo\x1b[0m", "\x1b[0;91m!\x1b[0m"]],
+            "'o!' should be red.",
+        );
     }
 
     #[test]
-    fn screen_size() {
-        let canvas = TextCanvas::new(7, 4);
+    fn merge_text_space_does_not_clear_text() {
+        let mut canvas = TextCanvas::new(5, 1);
 
-        assert_eq!(canvas.screen.width, 7 * 2, "Incorrect output width.");
-        assert_eq!(canvas.screen.height, 4 * 4, "Incorrect output height.");
+        canvas.merge_text("bar", 1, 0);
+        canvas.merge_text(" z", 2, 0);
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["", "b", "a", "z", ""]],
+            "Incorrect text buffer.",
+        );
     }
 
     #[test]
-    fn buffer_size() {
-        let canvas = TextCanvas::new(7, 4);
-        let buffer_width = canvas.buffer[0].len();
-        let buffer_height = canvas.buffer.len();
+    fn merge_text_vertical() {
+        let mut canvas = TextCanvas::new(1, 5);
+
+        assert!(!canvas.is_textual());
+
+        canvas.merge_text_vertical("bar", 0, 1);
+        canvas.merge_text_vertical(" z", 0, 2);
+
+        assert!(canvas.is_textual());
 
-        assert_eq!(buffer_width, 7 * 2, "Incorrect number of rows in buffer.");
         assert_eq!(
-            buffer_height,
-            4 * 4,
-            "Incorrect number of columns in buffer."
+            canvas.text_buffer,
+            [[""], ["b"], ["a"], ["z"], [""],],
+            "Incorrect text buffer."
         );
     }
 
     #[test]
-    fn default_size() {
-        let canvas = TextCanvas::default();
+    fn get_text_as_string() {
+        let mut canvas = TextCanvas::new(5, 3);
 
-        assert_eq!(canvas.output.width, 80, "Incorrect default width.");
-        assert_eq!(canvas.output.height, 24, "Incorrect default height.");
+        canvas.draw_text("foo", 1, 1);
+
+        assert_eq!(
+            canvas.to_string(),
+            "⠀⠀⠀⠀⠀\n⠀foo⠀\n⠀⠀⠀⠀⠀\n",
+            "Incorrect output string."
+        );
     }
 
     #[test]
-    fn get_default_size() {
-        let (width, height) = TextCanvas::get_default_size();
+    fn get_text_as_string_colored() {
+        let mut canvas = TextCanvas::new(5, 3);
 
-        assert_eq!(width, 80, "Incorrect default width.");
-        assert_eq!(height, 24, "Incorrect default height.");
+        canvas.set_color(Color::new().bright_green());
+        canvas.draw_text("foo", 1, 1);
+
+        assert_eq!(
+            canvas.to_string(),
+            "⠀⠀⠀⠀⠀\n⠀\x1b[0;92mfoo\x1b[0m⠀\n⠀⠀⠀⠀⠀\n",
+            "Incorrect output string.",
+        );
     }
 
     #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn size_zero_panics_for_width() {
-        let _ = TextCanvas::new(0, 1);
+    fn clear_clears_text_buffer() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        assert!(
+            canvas.text_buffer.is_empty(),
+            "Text buffer should be empty."
+        );
+
+        canvas.set_color(Color::new().bright_red());
+        canvas.draw_text("hi", 0, 0);
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["\x1b[0;91mh\x1b[0m", "\x1b[0;91mi\x1b[0m"]],
+            "Text should be colorized.",
+        );
+
+        canvas.clear();
+
+        assert_eq!(
+            canvas.text_buffer,
+            [["", ""]],
+            "Text buffer should be full of no-colored empty chars.",
+        );
     }
 
     #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn size_zero_panics_for_height() {
-        let _ = TextCanvas::new(1, 0);
+    fn clear_edits_text_buffer_in_place() {
+        let mut canvas = TextCanvas::new(2, 2);
+        canvas.draw_text("hi", 0, 0);
+
+        let text_buffer = canvas.text_buffer.as_ptr();
+        let row_0 = canvas.text_buffer[0].as_ptr();
+        let row_1 = canvas.text_buffer[1].as_ptr();
+
+        canvas.clear();
+
+        assert_eq!(
+            text_buffer,
+            canvas.text_buffer.as_ptr(),
+            "Container should be the same as before."
+        );
+        assert_eq!(
+            row_0,
+            canvas.text_buffer[0].as_ptr(),
+            "Container should be the same as before."
+        );
+        assert_eq!(
+            row_1,
+            canvas.text_buffer[1].as_ptr(),
+            "Container should be the same as before."
+        );
     }
 
+    // Drawing primitives.
+
     #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn size_zero_panics_for_width_and_height() {
-        let _ = TextCanvas::new(0, 0);
+    fn stroke_line() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let top_left = (0, 0);
+        let top_right = (canvas.w(), 0);
+        let bottom_right = (canvas.w(), canvas.h());
+        let bottom_left = (0, canvas.h());
+        let center = (canvas.cx(), canvas.cy());
+        let center_top = (canvas.cx(), 0);
+        let center_right = (canvas.w(), canvas.cy());
+        let center_bottom = (canvas.cx(), canvas.h());
+        let center_left = (0, canvas.cy());
+
+        canvas.stroke_line(center.0, center.1, top_left.0, top_left.1);
+        canvas.stroke_line(center.0, center.1, top_right.0, top_right.1);
+        canvas.stroke_line(center.0, center.1, bottom_right.0, bottom_right.1);
+        canvas.stroke_line(center.0, center.1, bottom_left.0, bottom_left.1);
+        canvas.stroke_line(center.0, center.1, center_top.0, center_top.1);
+        canvas.stroke_line(center.0, center.1, center_right.0, center_right.1);
+        canvas.stroke_line(center.0, center.1, center_bottom.0, center_bottom.1);
+        canvas.stroke_line(center.0, center.1, center_left.0, center_left.1);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠑⠢⣀⠀⠀⠀⠀⢸⠀⠀⠀⠀⢀⠔⠊
+⠀⠀⠀⠑⠢⣀⠀⢸⠀⢀⠤⠊⠁⠀⠀
+⠤⠤⠤⠤⠤⠤⢵⣾⣶⠥⠤⠤⠤⠤⠤
+⠀⠀⠀⣀⠤⠊⠁⢸⠀⠑⠢⣀⠀⠀⠀
+⡠⠔⠊⠀⠀⠀⠀⢸⠀⠀⠀⠀⠉⠢⢄
+",
+            "Lines not drawn correctly.",
+        );
     }
 
     #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn size_negative_panics_for_width() {
-        let _ = TextCanvas::new(-1, 1);
+    fn stroke_line_from_outside_to_outside() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        canvas.stroke_line(-10, -10, canvas.w() + 10, canvas.h() + 10);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠉⠢⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠈⠑⢄⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠉⠢⣀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠑⢄⡀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠢⣀⠀
+",
+            "Line not drawn correctly.",
+        );
     }
 
     #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn size_negative_panics_for_height() {
-        let _ = TextCanvas::new(1, -1);
+    fn erase_line() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        canvas.fill();
+
+        let top_left = (0, 0);
+        let bottom_right = (canvas.w(), canvas.h());
+
+        canvas.invert();
+        canvas.stroke_line(top_left.0, top_left.1, bottom_right.0, bottom_right.1);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⣮⣝⠿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
+⣿⣿⣿⣮⣝⠿⣿⣿⣿⣿⣿⣿⣿⣿⣿
+⣿⣿⣿⣿⣿⣿⣮⣝⡻⣿⣿⣿⣿⣿⣿
+⣿⣿⣿⣿⣿⣿⣿⣿⣿⣶⣝⡻⣿⣿⣿
+⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣶⣝⡻
+",
+            "Line not erased correctly.",
+        );
     }
 
     #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn size_negative_panics_for_width_and_height() {
-        let _ = TextCanvas::new(-1, -1);
-    }
+    fn stroke_line_smooth_straight_lines_match_stroke_line() {
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.stroke_line(3, 2, 25, 2);
+        canvas.stroke_line(7, 0, 7, 19);
 
-    #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn size_too_big_panics_for_width() {
-        let _ = TextCanvas::new(100_000, 1);
-    }
+        let mut smooth = TextCanvas::new(15, 5);
+        smooth.stroke_line_smooth(3, 2, 25, 2);
+        smooth.stroke_line_smooth(7, 0, 7, 19);
 
-    #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn size_too_big_panics_for_height() {
-        let _ = TextCanvas::new(1, 100_000);
+        assert_eq!(smooth.buffer, canvas.buffer);
     }
 
     #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn size_too_big_panics_for_width_and_height() {
-        let _ = TextCanvas::new(100_000, 100_000);
+    fn stroke_line_smooth_diagonal_matches_stroke_line() {
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.stroke_line(0, 0, 19, 19);
+
+        let mut smooth = TextCanvas::new(15, 5);
+        smooth.stroke_line_smooth(0, 0, 19, 19);
+
+        assert_eq!(smooth.buffer, canvas.buffer);
     }
 
     #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn max_i32_does_not_overflow_width() {
-        // There was an error in the bounds checking condition:
-        //
-        //     if width * 2 <= MAX_RESOLUTION
-        //
-        // This panics if `size * 2` > `i32::MAX`, with `attempt to
-        // multiply with overflow`. The solution is to divide instead:
-        //
-        //     if width <= MAX_RESOLUTION / 2
-        let _ = TextCanvas::new(i32::MAX, 1);
+    fn stroke_line_smooth_direction_does_not_matter() {
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.stroke_line_smooth(2, 3, 27, 17);
+
+        let mut reversed = TextCanvas::new(15, 5);
+        reversed.stroke_line_smooth(27, 17, 2, 3);
+
+        assert_eq!(reversed.buffer, canvas.buffer);
     }
 
     #[test]
-    #[should_panic(expected = "TextCanvas' minimal size is 1×1.")]
-    fn max_i32_does_not_overflow_height() {
-        // There was an error in the bounds checking condition:
-        //
-        //     if height * 4 <= MAX_RESOLUTION
-        //
-        // This panics if `size * 4` > `i32::MAX`, with `attempt to
-        // multiply with overflow`. The solution is to divide instead:
-        //
-        //     if height <= MAX_RESOLUTION / 4
-        let _ = TextCanvas::new(1, i32::MAX);
+    fn stroke_line_smooth_steep() {
+        let mut canvas = TextCanvas::new(2, 4);
+
+        canvas.stroke_line_smooth(0, 0, 3, 15);
+
+        assert_eq!(canvas.to_string(), "⣧⠀\n⢸⡀\n⠈⡇\n⠀⢻\n");
     }
 
     #[test]
-    fn auto_size() {
-        // This is fine, as long as this is the only test that modifies
-        // the environment.
-        env::remove_var("WIDTH");
-        env::remove_var("HEIGHT");
+    fn stroke_line_smooth_single_point() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-        assert!(
-            TextCanvas::new_auto().is_err(),
-            "`WIDTH` and `HEIGHT` don't exist."
-        );
-        assert!(TextCanvas::get_auto_size().is_err());
+        canvas.stroke_line_smooth(1, 1, 1, 1);
 
-        env::set_var("WIDTH", "1");
-        env::set_var("HEIGHT", "2147483648");
+        assert_eq!(canvas.to_string(), "⠐⠀\n");
+    }
 
-        assert!(
-            TextCanvas::new_auto().is_err(),
-            "`HEIGHT` is too large for an `i32`."
-        );
-        assert!(TextCanvas::get_auto_size().is_err());
+    #[test]
+    fn stroke_line_colored() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-        env::set_var("WIDTH", "abc");
-        env::set_var("HEIGHT", "1");
+        canvas.stroke_line_colored(0, 0, canvas.w(), 0, |t| {
+            if t < 0.5 {
+                Color::new().red().fix()
+            } else {
+                Color::new().blue().fix()
+            }
+        });
 
-        assert!(TextCanvas::new_auto().is_err(), "`WIDTH` is not a number.");
-        assert!(TextCanvas::get_auto_size().is_err());
+        assert_eq!(canvas.to_string(), "\x1b[0;31m⠉\x1b[0m\x1b[0;34m⠉\x1b[0m\n");
+    }
 
-        env::set_var("WIDTH", "1");
-        env::set_var("HEIGHT", "abc");
+    #[test]
+    fn stroke_line_colored_progress_goes_from_start_to_end() {
+        let mut canvas = TextCanvas::new(3, 3);
+        let progress = std::cell::RefCell::new(Vec::new());
 
-        assert!(TextCanvas::new_auto().is_err(), "`HEIGHT` is not a number.");
-        assert!(TextCanvas::get_auto_size().is_err());
+        canvas.stroke_line_colored(canvas.w(), canvas.h(), 0, 0, |t| {
+            progress.borrow_mut().push(t);
+            Color::new()
+        });
 
-        env::set_var("WIDTH", "12");
-        env::set_var("HEIGHT", "5");
+        let progress = progress.into_inner();
+        assert_eq!(progress.first(), Some(&0.0));
+        assert_eq!(progress.last(), Some(&1.0));
+        assert!(progress.windows(2).all(|w| w[0] < w[1]));
+        // Same pixels as a regular line.
+        let mut expected = TextCanvas::new(3, 3);
+        expected.stroke_line(expected.w(), expected.h(), 0, 0);
+        assert_eq!(canvas.buffer, expected.buffer);
+    }
 
-        let canvas = TextCanvas::new_auto().unwrap();
+    #[test]
+    fn stroke_line_colored_single_point() {
+        let mut canvas = TextCanvas::new(1, 1);
+        let progress = std::cell::RefCell::new(Vec::new());
 
-        assert_eq!(canvas.output.width, 12, "Incorrect auto width.");
-        assert_eq!(canvas.output.height, 5, "Incorrect auto height.");
-        assert_eq!(TextCanvas::get_auto_size().unwrap(), (12, 5));
+        canvas.stroke_line_colored(0, 0, 0, 0, |t| {
+            progress.borrow_mut().push(t);
+            Color::new()
+        });
+
+        assert_eq!(progress.into_inner(), [0.0]);
+        assert_eq!(canvas.get_pixel(0, 0), Some(true));
     }
 
     #[test]
-    fn string_representation() {
-        let canvas = TextCanvas::new(7, 4);
+    fn stroke_line_colored_restores_context_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_color(Color::new().green());
 
-        assert_eq!(
-            canvas.to_string(),
-            format!("{canvas}"),
-            "Incorrect string representation."
-        );
+        canvas.stroke_line_colored(0, 0, 1, 0, |_| Color::new().red().fix());
+        canvas.set_pixel(3, 0, true);
 
         assert_eq!(
-            canvas.repr(),
-            "Canvas(output=(7×4), screen=(14×16)))",
-            "Incorrect string representation.",
+            canvas.color_buffer,
+            [[Color::new().red().fix(), Color::new().green().fix()]],
         );
     }
 
     #[test]
-    fn shortcuts() {
-        let canvas = TextCanvas::new(7, 4);
+    fn stroke_line_dashed() {
+        let mut canvas = TextCanvas::new(1, 3);
 
-        assert_eq!(canvas.w(), 13, "Incorrect screen width.");
-        assert_eq!(canvas.h(), 15, "Incorrect screen height.");
-        assert_eq!(canvas.cx(), 7, "Incorrect screen center-X.");
-        assert_eq!(canvas.cy(), 8, "Incorrect screen center-Y.");
+        canvas.stroke_line_dashed(0, 0, 0, 11, 2, 1);
+
+        assert_eq!(canvas.to_string(), "⡃\n⡅\n⠆\n");
     }
 
     #[test]
-    fn shortcuts_unsigned() {
-        let canvas = TextCanvas::new(7, 4);
+    fn stroke_line_dashed_without_gap_is_solid() {
+        let mut canvas = TextCanvas::new(3, 1);
 
-        assert_eq!(canvas.uw(), 13, "Incorrect screen width.");
-        assert_eq!(canvas.uh(), 15, "Incorrect screen height.");
-        assert_eq!(canvas.ucx(), 7, "Incorrect screen center-X.");
-        assert_eq!(canvas.ucy(), 8, "Incorrect screen center-Y.");
+        canvas.stroke_line_dashed(0, 0, canvas.w(), 0, 1, 0);
+        canvas.stroke_line_dashed(0, 3, canvas.w(), 3, 1, -1);
+
+        assert_eq!(canvas.to_string(), "⣉⣉⣉\n");
     }
 
     #[test]
-    #[allow(clippy::float_cmp)]
-    fn shortcuts_float() {
-        let canvas = TextCanvas::new(7, 4);
+    fn stroke_line_dashed_without_dash_is_empty() {
+        let mut canvas = TextCanvas::new(3, 1);
 
-        assert_eq!(canvas.fw(), 13.0, "Incorrect screen width.");
-        assert_eq!(canvas.fh(), 15.0, "Incorrect screen height.");
-        assert_eq!(canvas.fcx(), 7.0, "Incorrect screen center-X.");
-        assert_eq!(canvas.fcy(), 8.0, "Incorrect screen center-Y.");
+        canvas.stroke_line_dashed(0, 0, canvas.w(), 0, 0, 2);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n");
     }
 
     #[test]
-    fn check_output_bounds() {
-        let canvas = TextCanvas::new(7, 4);
+    fn stroke_rect_dashed_without_gap_is_solid() {
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.stroke_rect_dashed(5, 5, 20, 10, 1, 0);
 
-        assert!(canvas.check_output_bounds(0, 0));
-        assert!(canvas.check_output_bounds(6, 0));
-        assert!(canvas.check_output_bounds(6, 3));
-        assert!(canvas.check_output_bounds(0, 3));
+        let mut expected = TextCanvas::new(15, 5);
+        expected.stroke_rect(5, 5, 20, 10);
 
-        assert!(!canvas.check_output_bounds(0, -1));
-        assert!(!canvas.check_output_bounds(7, 0));
-        assert!(!canvas.check_output_bounds(6, 4));
-        assert!(!canvas.check_output_bounds(-1, 3));
+        assert_eq!(canvas.to_string(), expected.to_string());
     }
 
     #[test]
-    fn check_screen_bounds() {
-        let canvas = TextCanvas::new(7, 4);
+    fn stroke_rect_dashed_pattern_continues_across_corners() {
+        let mut canvas = TextCanvas::new(3, 1);
 
-        assert!(canvas.check_screen_bounds(0, 0));
-        assert!(canvas.check_screen_bounds(13, 0));
-        assert!(canvas.check_screen_bounds(13, 15));
-        assert!(canvas.check_screen_bounds(0, 15));
+        canvas.stroke_rect_dashed(0, 0, 6, 4, 1, 1);
 
-        assert!(!canvas.check_screen_bounds(0, -1));
-        assert!(!canvas.check_screen_bounds(14, 0));
-        assert!(!canvas.check_screen_bounds(13, 16));
-        assert!(!canvas.check_screen_bounds(-1, 15));
+        assert_eq!(canvas.to_string(), "⢅⢁⢑\n");
     }
 
     #[test]
-    fn turn_all_pixels_on() {
-        let mut canvas = TextCanvas::new(2, 2);
+    fn stroke_rect_dashed_without_dash_is_empty() {
+        let mut canvas = TextCanvas::new(3, 1);
 
-        for x in 0..canvas.screen.width() {
-            for y in 0..canvas.screen.height() {
-                canvas.set_pixel(x, y, true);
-            }
-        }
+        canvas.stroke_rect_dashed(0, 0, 6, 4, 0, 1);
 
-        assert_eq!(canvas.to_string(), "⣿⣿\n⣿⣿\n", "Output not fully on.");
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n");
     }
 
     #[test]
-    fn get_pixel() {
-        let mut canvas = TextCanvas::new(2, 2);
+    fn stroke_circle_dashed_without_gap_is_solid() {
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.stroke_circle_dashed(canvas.cx(), canvas.cy(), 7, 1, 0);
 
-        assert_eq!(
-            canvas.get_pixel(3, 2),
-            Some(false),
-            "Pixel should be turned off."
-        );
+        let mut expected = TextCanvas::new(15, 5);
+        expected.stroke_circle(expected.cx(), expected.cy(), 7);
 
-        canvas.set_pixel(3, 2, true);
+        assert_eq!(canvas.to_string(), expected.to_string());
+    }
 
-        assert_eq!(
-            canvas.get_pixel(3, 2),
-            Some(true),
-            "Pixel should be turned on."
-        );
+    #[test]
+    fn fill_circle_gradient_same_shape_as_fill_circle_exact() {
+        let white = Color::new().rgb(255, 255, 255).fix();
+        let blue = Color::new().rgb(0, 0, 255).fix();
+
+        for radius in [-1, 0, 1, 2, 3, 7, 9] {
+            let mut gradient = TextCanvas::new(15, 5);
+            gradient.fill_circle_gradient(gradient.cx(), gradient.cy(), radius, &white, &blue);
+
+            let mut plain = TextCanvas::new(15, 5);
+            plain.fill_circle_exact(plain.cx(), plain.cy(), radius);
+
+            assert_eq!(gradient.buffer, plain.buffer, "radius {radius}");
+        }
     }
 
     #[test]
-    fn get_pixel_with_overflow() {
-        let canvas = TextCanvas::new(1, 1);
+    fn fill_circle_gradient_fades_from_center_to_edge() {
+        let mut canvas = TextCanvas::new(15, 5);
+        let white = Color::new().rgb(255, 255, 255).fix();
+        let blue = Color::new().rgb(0, 0, 255).fix();
 
-        assert_eq!(canvas.get_pixel(-1, 0), None, "Overflow should be None.");
-        assert_eq!(canvas.get_pixel(0, -1), None, "Overflow should be None.");
-        assert_eq!(canvas.get_pixel(-1, -1), None, "Overflow should be None.");
+        canvas.fill_circle_gradient(canvas.cx(), canvas.cy(), 7, &white, &blue);
 
+        // Outermost cells are the edge color.
+        assert_eq!(canvas.color_buffer[0][7], blue);
+        assert_eq!(canvas.color_buffer[4][6], blue);
+        assert_eq!(canvas.color_buffer[2][11], blue);
+        // Closer to the center, closer to the center color.
         assert_eq!(
-            canvas.get_pixel(canvas.screen.width(), 0),
-            None,
-            "Overflow should be None."
+            canvas.color_buffer[2][5],
+            Color::new().rgb(90, 90, 255).fix()
         );
         assert_eq!(
-            canvas.get_pixel(0, canvas.screen.height()),
-            None,
-            "Overflow should be None."
+            canvas.color_buffer[2][6],
+            Color::new().rgb(162, 162, 255).fix()
         );
         assert_eq!(
-            canvas.get_pixel(canvas.screen.width(), canvas.screen.height()),
-            None,
-            "Overflow should be None.",
+            canvas.color_buffer[2][7],
+            Color::new().rgb(229, 229, 255).fix()
         );
     }
 
     #[test]
-    fn get_pixel_on_boundaries() {
-        let mut canvas = TextCanvas::new(1, 1);
-
-        canvas.buffer = vec![
-            vec![true, false],
-            vec![false, false],
-            vec![false, false],
-            vec![false, true],
-        ];
+    fn fill_circle_gradient_inverted() {
+        let mut canvas = TextCanvas::new(15, 5);
+        let white = Color::new().rgb(255, 255, 255).fix();
+        let blue = Color::new().rgb(0, 0, 255).fix();
 
-        assert_eq!(canvas.get_pixel(0, 0), Some(true), "Incorrect pixel value.");
-        assert_eq!(
-            canvas.get_pixel(canvas.screen.width() - 1, canvas.screen.height() - 1),
-            Some(true),
-            "Incorrect pixel value.",
-        );
-    }
+        canvas.fill();
+        canvas.invert();
+        canvas.fill_circle_gradient(canvas.cx(), canvas.cy(), 7, &white, &blue);
 
-    #[test]
-    fn set_pixel() {
-        let mut canvas = TextCanvas::new(3, 2);
-        stroke_line_accros_canvas(&mut canvas);
+        let mut expected = TextCanvas::new(15, 5);
+        expected.fill();
+        expected.invert();
+        expected.fill_circle_exact(expected.cx(), expected.cy(), 7);
 
-        assert_eq!(
-            canvas.buffer,
-            [
-                [true, false, false, false, false, false],
-                [false, true, false, false, false, false],
-                [false, false, true, false, false, false],
-                [false, false, false, true, false, false],
-                [false, false, false, false, true, false],
-                [false, false, false, false, false, true],
-                [false, false, false, false, false, false],
-                [false, false, false, false, false, false],
-            ],
-            "Incorrect buffer content.",
-        );
+        assert_eq!(canvas, expected);
     }
 
     #[test]
-    fn set_pixel_with_overflow() {
-        let mut canvas = TextCanvas::new(1, 1);
-
-        canvas.set_pixel(-1, 0, true);
-        canvas.set_pixel(0, -1, true);
-        canvas.set_pixel(-1, -1, true);
-
-        canvas.set_pixel(canvas.screen.width(), 0, true);
-        canvas.set_pixel(0, canvas.screen.height(), true);
-        canvas.set_pixel(canvas.screen.width(), canvas.screen.height(), true);
+    fn fill_circle_gradient_respects_clip() {
+        let mut canvas = TextCanvas::new(15, 5);
+        let white = Color::new().rgb(255, 255, 255).fix();
+        let blue = Color::new().rgb(0, 0, 255).fix();
 
-        assert_eq!(
-            canvas.buffer,
-            [
-                [false, false],
-                [false, false],
-                [false, false],
-                [false, false],
-            ],
-            "No pixel should be turned on.",
-        );
+        canvas.clip = Some(Region {
+            x: 0,
+            y: 0,
+            width: 14,
+            height: 20,
+        });
+        canvas.fill_circle_gradient(15, 10, 7, &white, &blue);
+
+        assert!(canvas.color_buffer[2][7].to_hex().is_none());
+        assert!(canvas.color_buffer[2][6].to_hex().is_some());
     }
 
     #[test]
-    fn set_pixel_on_boundaries() {
-        let mut canvas = TextCanvas::new(1, 1);
+    fn fill_circle_gradient_restores_context_color() {
+        let mut canvas = TextCanvas::new(15, 5);
+        let white = Color::new().rgb(255, 255, 255).fix();
+        let blue = Color::new().rgb(0, 0, 255).fix();
 
+        canvas.set_color(Color::new().red());
+        canvas.fill_circle_gradient(canvas.cx(), canvas.cy(), 7, &white, &blue);
         canvas.set_pixel(0, 0, true);
-        canvas.set_pixel(canvas.screen.width() - 1, canvas.screen.height() - 1, true);
 
-        assert_eq!(
-            canvas.buffer,
-            [[true, false], [false, false], [false, false], [false, true],],
-            "Incorrect buffer content.",
-        );
+        assert_eq!(canvas.color_buffer[0][0], *Color::new().red());
     }
 
     #[test]
-    fn get_as_string() {
-        let mut canvas = TextCanvas::new(3, 2);
-        stroke_line_accros_canvas(&mut canvas);
+    fn fill_circle_exact_is_within_radius() {
+        let mut canvas = TextCanvas::new(15, 5);
+        let (cx, cy, radius) = (canvas.cx(), canvas.cy(), 6);
 
-        assert_eq!(canvas.to_string(), "⠑⢄⠀\n⠀⠀⠑\n", "Incorrect output string.");
+        canvas.fill_circle_exact(cx, cy, radius);
+
+        for (x, y) in canvas.iter_buffer() {
+            let (dx, dy) = (x - cx, y - cy);
+            let is_inside = dx * dx + dy * dy <= radius * radius;
+            assert_eq!(canvas.get_pixel(x, y), Some(is_inside), "({x}, {y})");
+        }
     }
 
     #[test]
-    fn clear() {
-        let mut canvas = TextCanvas::new(2, 2);
-
-        canvas.fill();
+    fn fill_circle_exact_radius_zero_is_single_pixel() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-        canvas.clear();
+        canvas.fill_circle_exact(1, 1, 0);
+        canvas.fill_circle_exact(3, 1, -1);
 
-        assert_eq!(canvas.to_string(), "⠀⠀\n⠀⠀\n", "Output not empty.");
+        assert_eq!(canvas.to_string(), "⠐⠀\n");
     }
 
     #[test]
-    fn clear_edits_buffer_in_place() {
-        let mut canvas = TextCanvas::new(1, 1);
-
-        let buffer = canvas.buffer.as_ptr();
-        let row_0 = canvas.buffer[0].as_ptr();
-        let row_1 = canvas.buffer[1].as_ptr();
-        let row_2 = canvas.buffer[2].as_ptr();
-        let row_3 = canvas.buffer[3].as_ptr();
+    fn fill_circle_exact_partially_off_screen() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-        canvas.clear();
+        canvas.fill_circle_exact(-1, -1, 2);
 
-        assert_eq!(
-            buffer,
-            canvas.buffer.as_ptr(),
-            "Container should be the same as before."
-        );
-        assert_eq!(
-            row_0,
-            canvas.buffer[0].as_ptr(),
-            "Container should be the same as before."
-        );
-        assert_eq!(
-            row_1,
-            canvas.buffer[1].as_ptr(),
-            "Container should be the same as before."
-        );
-        assert_eq!(
-            row_2,
-            canvas.buffer[2].as_ptr(),
-            "Container should be the same as before."
-        );
-        assert_eq!(
-            row_3,
-            canvas.buffer[3].as_ptr(),
-            "Container should be the same as before."
-        );
+        assert_eq!(canvas.to_string(), "⠁⠀\n");
     }
 
     #[test]
-    fn fill() {
-        let mut canvas = TextCanvas::new(2, 2);
+    fn compute_bresenham_circle_points_are_ordered_clockwise() {
+        let points = TextCanvas::compute_bresenham_circle_points(10, 10, 2);
+
+        assert_eq!(points.first(), Some(&(12, 10)));
+        // Each point touches the next.
+        for pair in points.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert!((a.0 - b.0).abs() <= 1 && (a.1 - b.1).abs() <= 1);
+        }
+        assert_eq!(points[points.len() / 4], (10, 12)); // Bottom.
+    }
 
-        canvas.fill();
+    #[test]
+    fn compute_bresenham_circle_points_match_stroke_circle() {
+        for radius in 0..20 {
+            let mut stroked = TextCanvas::new(15, 10);
+            stroked.stroke_circle(stroked.cx(), stroked.cy(), radius);
+
+            let mut points = TextCanvas::new(15, 10);
+            for (x, y) in
+                TextCanvas::compute_bresenham_circle_points(points.cx(), points.cy(), radius)
+            {
+                points.set_pixel(x, y, true);
+            }
 
-        assert_eq!(canvas.to_string(), "⣿⣿\n⣿⣿\n", "Output not full.");
+            assert_eq!(stroked, points, "radius: {radius}");
+        }
     }
 
     #[test]
-    fn invert() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn compute_bresenham_circle_octant() {
+        let octant: Vec<(i32, i32)> = TextCanvas::compute_bresenham_circle_octant(5).collect();
 
-        canvas.fill_rect(6, 3, 20, 15);
+        assert_eq!(octant, [(5, 0), (5, 1), (5, 2), (4, 3)]);
+        assert_eq!(TextCanvas::compute_bresenham_circle_octant(0).count(), 1);
+        assert_eq!(TextCanvas::compute_bresenham_circle_octant(-1).count(), 0);
+    }
 
-        assert!(!canvas.is_inverted);
+    #[test]
+    fn stroke_ticks_horizontal() {
+        let mut canvas = TextCanvas::new(15, 3);
 
-        canvas.invert();
-        canvas.fill_rect(9, 6, 14, 9);
+        canvas.stroke_line(2, 5, 26, 5);
+        canvas.stroke_ticks(2, 5, 26, 5, 7, 5);
 
-        assert!(canvas.is_inverted);
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⠀⠀
-⠀⠀⠀⣿⡟⠛⠛⠛⠛⠛⠛⢻⣿⠀⠀
-⠀⠀⠀⣿⡇⠀⠀⠀⠀⠀⠀⢸⣿⠀⠀
-⠀⠀⠀⣿⣇⣀⣀⣀⣀⣀⣀⣸⣿⠀⠀
-⠀⠀⠀⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠀⠀
+⠀⡀⠀⡀⠀⡀⠀⡀⠀⡀⠀⡀⠀⡀⠀
+⠀⡗⠒⡗⠒⡗⠒⡗⠒⡗⠒⡗⠒⡇⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
     #[test]
-    fn double_invert() {
-        let mut canvas = TextCanvas::new(15, 5);
-
-        assert!(!canvas.is_inverted);
+    fn stroke_ticks_single_tick_is_in_the_middle() {
+        let mut canvas = TextCanvas::new(15, 3);
 
-        canvas.invert();
-        assert!(canvas.is_inverted);
+        canvas.stroke_ticks(2, 5, 26, 5, 1, 3);
 
-        canvas.invert();
-        assert!(!canvas.is_inverted);
+        assert!(canvas.get_pixel(14, 4).unwrap());
+        assert!(canvas.get_pixel(14, 5).unwrap());
+        assert!(canvas.get_pixel(14, 6).unwrap());
+        assert_eq!(canvas.buffer.iter().flatten().filter(|&&p| p).count(), 3);
     }
 
     #[test]
-    fn clear_not_affected_by_invert() {
-        let mut canvas = TextCanvas::new(2, 2);
+    fn stroke_ticks_nothing_to_draw() {
+        let mut canvas = TextCanvas::new(15, 3);
 
-        canvas.invert();
-        canvas.clear();
+        canvas.stroke_ticks(2, 5, 26, 5, 0, 5);
+        canvas.stroke_ticks(2, 5, 26, 5, 5, 0);
 
-        assert_eq!(canvas.to_string(), "⠀⠀\n⠀⠀\n", "Output not empty.");
+        assert!(canvas.buffer.iter().flatten().all(|&p| !p));
     }
 
     #[test]
-    fn fill_not_affected_by_invert() {
-        let mut canvas = TextCanvas::new(2, 2);
+    fn stroke_arrow_vertical() {
+        let mut canvas = TextCanvas::new(3, 3);
 
-        canvas.invert();
-        canvas.fill();
+        canvas.stroke_arrow(3, 1, 3, 10);
 
-        assert_eq!(canvas.to_string(), "⣿⣿\n⣿⣿\n", "Output not full.");
+        assert_eq!(canvas.to_string(), "⠀⢰⠀\n⠀⣸⡀\n⠀⠹⠁\n");
     }
 
     #[test]
-    fn iter_buffer_by_blocks_lrtb() {
-        // This tests a private method, but this method is at the core
-        // of the output generation. Testing it helps ensure stability.
-        let mut canvas = TextCanvas::new(3, 2);
-        stroke_line_accros_canvas(&mut canvas);
-
-        assert_eq!(
-            canvas.iter_buffer_by_blocks_lrtb().collect::<Vec<_>>(),
-            [
-                [[true, false], [false, true], [false, false], [false, false],],
-                [[false, false], [false, false], [true, false], [false, true],],
-                [
-                    [false, false],
-                    [false, false],
-                    [false, false],
-                    [false, false],
-                ],
-                [
-                    [false, false],
-                    [false, false],
-                    [false, false],
-                    [false, false],
-                ],
-                [
-                    [false, false],
-                    [false, false],
-                    [false, false],
-                    [false, false],
-                ],
-                [[true, false], [false, true], [false, false], [false, false],],
-            ],
-            "Incorrect list of blocks.",
-        );
-    }
+    fn stroke_arrow_short() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-    #[test]
-    fn iter_buffer() {
-        let canvas = TextCanvas::new(3, 2);
+        // Head is too small to show.
+        canvas.stroke_arrow(0, 1, 2, 1);
 
-        #[rustfmt::skip]
-        assert_eq!(canvas.iter_buffer().collect::<Vec<_>>(), [
-            (0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (5, 0),
-            (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1),
-            (0, 2), (1, 2), (2, 2), (3, 2), (4, 2), (5, 2),
-            (0, 3), (1, 3), (2, 3), (3, 3), (4, 3), (5, 3),
-            (0, 4), (1, 4), (2, 4), (3, 4), (4, 4), (5, 4),
-            (0, 5), (1, 5), (2, 5), (3, 5), (4, 5), (5, 5),
-            (0, 6), (1, 6), (2, 6), (3, 6), (4, 6), (5, 6),
-            (0, 7), (1, 7), (2, 7), (3, 7), (4, 7), (5, 7),
-        ], "Incorrect X and Y pairs, or in wrong order.");
+        assert_eq!(canvas.to_string(), "⠒⠂\n");
     }
 
     #[test]
-    fn uiter_buffer() {
-        let canvas = TextCanvas::new(3, 2);
+    fn stroke_arrow_zero_length() {
+        let mut canvas = TextCanvas::new(1, 1);
 
-        #[rustfmt::skip]
-        assert_eq!(canvas.uiter_buffer().collect::<Vec<_>>(), [
-            (0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (5, 0),
-            (0, 1), (1, 1), (2, 1), (3, 1), (4, 1), (5, 1),
-            (0, 2), (1, 2), (2, 2), (3, 2), (4, 2), (5, 2),
-            (0, 3), (1, 3), (2, 3), (3, 3), (4, 3), (5, 3),
-            (0, 4), (1, 4), (2, 4), (3, 4), (4, 4), (5, 4),
-            (0, 5), (1, 5), (2, 5), (3, 5), (4, 5), (5, 5),
-            (0, 6), (1, 6), (2, 6), (3, 6), (4, 6), (5, 6),
-            (0, 7), (1, 7), (2, 7), (3, 7), (4, 7), (5, 7),
-        ], "Incorrect X and Y pairs, or in wrong order.");
-    }
+        canvas.stroke_arrow(1, 1, 1, 1);
 
-    // Color.
+        assert_eq!(canvas.to_string(), "⠐\n");
+    }
 
     #[test]
-    fn color_buffer_size_at_init() {
-        let canvas = TextCanvas::new(7, 4);
+    fn stroke_rect() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        assert!(
-            canvas.color_buffer.is_empty(),
-            "Color buffer should be empty."
+        canvas.stroke_rect(6, 3, 20, 15);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠀⠀⠀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⠀⠀
+⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀
+⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀
+⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀
+⠀⠀⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀⠀
+",
         );
     }
 
     #[test]
-    fn color_buffer_size_with_color() {
-        let mut canvas = TextCanvas::new(7, 4);
-
-        canvas.set_color(Color::new().bg_bright_blue());
+    fn frame() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        let buffer_width = canvas.color_buffer[0].len();
-        let buffer_height = canvas.color_buffer.len();
+        canvas.frame();
 
         assert_eq!(
-            buffer_width, 7,
-            "Color buffer width should match output buffer width."
-        );
-        assert_eq!(
-            buffer_height, 4,
-            "Color buffer height should match output buffer height."
+            canvas.to_string(),
+            "\
+⡏⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⢹
+⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+⣇⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣸
+",
         );
     }
 
     #[test]
-    fn is_colorized() {
-        let mut canvas = TextCanvas::new(2, 2);
-
-        assert!(
-            !canvas.is_colorized(),
-            "Canvas should not be colorized by default."
-        );
+    fn frame_with_text_border_single() {
+        let mut canvas = TextCanvas::new(4, 3);
 
-        canvas.set_color(Color::new().bg_bright_blue());
+        canvas.frame_with_text_border(BorderStyle::Single);
 
-        assert!(
-            canvas.is_colorized(),
-            "Canvas should be colorized after a color is set."
-        );
+        assert_eq!(canvas.to_string(), "┌──┐\n│⠀⠀│\n└──┘\n");
     }
 
     #[test]
-    fn set_color() {
-        let mut canvas = TextCanvas::new(2, 2);
+    fn frame_with_text_border_double() {
+        let mut canvas = TextCanvas::new(4, 3);
 
-        canvas.set_color(Color::new().bg_bright_blue());
-        canvas.set_pixel(3, 3, true);
+        canvas.frame_with_text_border(BorderStyle::Double);
 
-        assert_eq!(
-            canvas.color_buffer,
-            [
-                [Color::new(), Color::new().bg_bright_blue().fix()],
-                [Color::new(), Color::new()],
-            ],
-            "Incorrect color buffer.",
-        );
+        assert_eq!(canvas.to_string(), "╔══╗\n║⠀⠀║\n╚══╝\n");
     }
 
     #[test]
-    fn set_color_multiple() {
-        let mut canvas = TextCanvas::new(2, 2);
+    fn frame_with_text_border_rounded() {
+        let mut canvas = TextCanvas::new(4, 3);
 
-        canvas.set_color(Color::new().bg_bright_blue());
-        canvas.set_pixel(3, 3, true);
-        canvas.set_pixel(1, 5, true);
+        canvas.frame_with_text_border(BorderStyle::Rounded);
 
-        assert_eq!(
-            canvas.color_buffer,
-            [
-                [Color::new(), Color::new().bg_bright_blue().fix()],
-                [Color::new().bg_bright_blue().fix(), Color::new()],
-            ],
-            "Incorrect color buffer.",
-        );
+        assert_eq!(canvas.to_string(), "╭──╮\n│⠀⠀│\n╰──╯\n");
     }
 
     #[test]
-    fn set_color_override() {
-        let mut canvas = TextCanvas::new(2, 2);
+    fn frame_with_text_border_ascii() {
+        let mut canvas = TextCanvas::new(4, 3);
 
-        canvas.set_color(Color::new().bg_bright_blue());
-        canvas.set_pixel(3, 3, true);
-        canvas.set_pixel(1, 5, true);
+        canvas.frame_with_text_border(BorderStyle::Ascii);
+
+        assert_eq!(canvas.to_string(), "+--+\n|⠀⠀|\n+--+\n");
+    }
+
+    #[test]
+    fn frame_with_text_border_smallest_canvas() {
+        let mut canvas = TextCanvas::new(1, 1);
 
-        canvas.set_color(Color::new().bg_bright_red());
-        canvas.set_pixel(3, 3, true);
+        canvas.frame_with_text_border(BorderStyle::Single);
 
-        assert_eq!(
-            canvas.color_buffer,
-            [
-                [Color::new(), Color::new().bg_bright_red().fix()],
-                [Color::new().bg_bright_blue().fix(), Color::new()],
-            ],
-            "Incorrect color buffer.",
-        );
+        assert_eq!(canvas.to_string(), "┘\n");
     }
 
     #[test]
-    fn color_is_reset_if_pixel_turned_off() {
+    fn frame_with_text_border_with_color() {
         let mut canvas = TextCanvas::new(2, 2);
+        canvas.set_color(Color::new().red());
 
-        canvas.set_color(Color::new().bg_bright_blue());
-        canvas.set_pixel(3, 3, true);
-        canvas.set_pixel(1, 5, true);
-
-        canvas.set_pixel(3, 3, false);
+        canvas.frame_with_text_border(BorderStyle::Ascii);
 
         assert_eq!(
-            canvas.color_buffer,
-            [
-                [Color::new(), Color::new()],
-                [Color::new().bg_bright_blue().fix(), Color::new()],
-            ],
-            "Incorrect color buffer.",
+            canvas.to_string(),
+            "\x1b[0;31m++\x1b[0m\n\x1b[0;31m++\x1b[0m\n"
         );
     }
 
     #[test]
-    fn get_as_string_colored() {
-        let mut canvas = TextCanvas::new(3, 2);
-        canvas.set_color(Color::new().bright_green());
-        stroke_line_accros_canvas(&mut canvas);
+    fn fill_rect() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        canvas.fill_rect(6, 3, 20, 15);
 
         assert_eq!(
             canvas.to_string(),
-            "\x1b[0;92m⠑\x1b[0m\x1b[0;92m⢄\x1b[0m⠀\n⠀⠀\x1b[0;92m⠑\x1b[0m\n",
-            "Incorrect output string.",
+            "\
+⠀⠀⠀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⠀⠀
+⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠀⠀
+⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠀⠀
+⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠀⠀
+⠀⠀⠀⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠀⠀
+",
         );
     }
 
     #[test]
-    fn clear_clears_color_buffer() {
+    fn fill_rect_stipple_leaves_off_pixels_untouched() {
         let mut canvas = TextCanvas::new(2, 1);
+        canvas.fill();
 
-        assert!(
-            canvas.color_buffer.is_empty(),
-            "Color buffer should be empty."
-        );
+        canvas.invert();
+        canvas.fill_rect_stipple(0, 0, 4, 4, &[[true, false]]);
 
-        canvas.set_color(Color::new().bright_red());
+        assert_eq!(canvas.to_string(), "⢸⢸\n");
+    }
 
-        assert_eq!(
-            canvas.color_buffer,
-            [[Color::new(), Color::new()]],
-            "Color buffer should be full of no-color.",
-        );
+    #[test]
+    fn fill_rect_stipple_pattern_is_anchored_to_rect() {
+        let mut canvas = TextCanvas::new(3, 1);
 
-        canvas.set_pixel(0, 0, true);
+        canvas.fill_rect_stipple(1, 1, 4, 3, &[[true, false, false]; 1]);
 
-        assert_eq!(
-            canvas.color_buffer,
-            [[Color::new().bright_red().fix(), Color::new()]],
-            "First pixel should be red.",
-        );
+        assert_eq!(canvas.to_string(), "⢰⠀⡆\n");
+    }
 
-        canvas.clear();
+    #[test]
+    fn fill_rect_stipple_empty_pattern() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-        assert_eq!(
-            canvas.color_buffer,
-            [[Color::new(), Color::new()]],
-            "Color buffer should be full of no-color.",
-        );
+        canvas.fill_rect_stipple(0, 0, 4, 4, &[[true; 0]; 2]);
+        canvas.fill_rect_stipple(0, 0, 4, 4, &[[true; 2]; 0]);
+
+        assert_eq!(canvas.to_string(), "⠀⠀\n");
     }
 
     #[test]
-    fn clear_edits_color_buffer_in_place() {
-        let mut canvas = TextCanvas::new(2, 2);
-        canvas.set_color(Color::new().bright_red());
-
-        let color_buffer = canvas.color_buffer.as_ptr();
-        let row_0 = canvas.color_buffer[0].as_ptr();
-        let row_1 = canvas.color_buffer[1].as_ptr();
+    fn fill_rect_stipple_negative_size() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-        canvas.clear();
+        canvas.fill_rect_stipple(2, 2, -2, -2, &[[true]]);
 
-        assert_eq!(
-            color_buffer,
-            canvas.color_buffer.as_ptr(),
-            "Container should be the same as before."
-        );
-        assert_eq!(
-            row_0,
-            canvas.color_buffer[0].as_ptr(),
-            "Container should be the same as before."
-        );
-        assert_eq!(
-            row_1,
-            canvas.color_buffer[1].as_ptr(),
-            "Container should be the same as before."
-        );
+        assert_eq!(canvas.to_string(), "⠀⠀\n");
     }
 
-    // Text.
-
     #[test]
-    fn text_buffer_size_at_init() {
-        let canvas = TextCanvas::new(7, 4);
+    fn fill_rect_gradient_horizontal() {
+        let mut canvas = TextCanvas::new(3, 2);
+        let from = Color::new().rgb(0, 0, 0).fix();
+        let to = Color::new().rgb(200, 200, 200).fix();
 
-        assert!(
-            canvas.text_buffer.is_empty(),
-            "Text buffer should be empty."
+        canvas.fill_rect_gradient(0, 0, 6, 8, &from, &to, GradientDir::Horizontal);
+
+        let middle = Color::new().rgb(100, 100, 100).fix();
+        assert_eq!(
+            canvas.color_buffer,
+            [
+                [from.clone(), middle.clone(), to.clone()],
+                [from, middle, to],
+            ],
         );
+        assert_eq!(canvas.buffer, vec![vec![true; 6]; 8]);
     }
 
     #[test]
-    fn text_buffer_size_with_color() {
-        let mut canvas = TextCanvas::new(7, 4);
+    fn fill_rect_gradient_vertical() {
+        let mut canvas = TextCanvas::new(2, 3);
+        let from = Color::new().rgb(0, 0, 0).fix();
+        let to = Color::new().rgb(200, 200, 200).fix();
 
-        canvas.draw_text("foo", 0, 0);
-
-        let buffer_width = canvas.text_buffer[0].len();
-        let buffer_height = canvas.text_buffer.len();
+        canvas.fill_rect_gradient(0, 0, 4, 12, &from, &to, GradientDir::Vertical);
 
+        let middle = Color::new().rgb(100, 100, 100).fix();
         assert_eq!(
-            buffer_width, 7,
-            "Text buffer width should match output buffer width."
-        );
-        assert_eq!(
-            buffer_height, 4,
-            "Text buffer height should match output buffer height."
+            canvas.color_buffer,
+            [
+                [from.clone(), from],
+                [middle.clone(), middle],
+                [to.clone(), to],
+            ],
         );
     }
 
     #[test]
-    fn is_textual() {
-        let mut canvas = TextCanvas::new(2, 2);
-
-        assert!(
-            !canvas.is_colorized(),
-            "Canvas should not be textual by default."
-        );
+    fn fill_rect_gradient_single_cell() {
+        let mut canvas = TextCanvas::new(2, 1);
+        let from = Color::new().rgb(0, 0, 0).fix();
+        let to = Color::new().rgb(200, 200, 200).fix();
 
-        canvas.draw_text("hi", 0, 0);
+        canvas.fill_rect_gradient(0, 0, 2, 4, &from, &to, GradientDir::Horizontal);
 
-        assert!(
-            canvas.is_textual(),
-            "Canvas should be textual after text is drawn."
-        );
+        assert_eq!(canvas.color_buffer, [[from, Color::new()]]);
     }
 
     #[test]
-    fn draw_text() {
-        let mut canvas = TextCanvas::new(5, 1);
+    fn fill_rect_gradient_with_overflow() {
+        let mut canvas = TextCanvas::new(2, 1);
+        let from = Color::new().rgb(0, 0, 0).fix();
+        let to = Color::new().rgb(200, 200, 200).fix();
 
-        canvas.draw_text("bar", 1, 0);
+        // Cells -1 to 2, only 0 and 1 are visible.
+        canvas.fill_rect_gradient(-2, 0, 8, 4, &from, &to, GradientDir::Horizontal);
 
         assert_eq!(
-            canvas.text_buffer,
-            [["", "b", "a", "r", ""]],
-            "Incorrect text buffer."
+            canvas.color_buffer,
+            [[
+                Color::new().rgb(67, 67, 67).fix(),
+                Color::new().rgb(133, 133, 133).fix()
+            ]],
         );
     }
 
     #[test]
-    fn draw_text_vertical() {
-        let mut canvas = TextCanvas::new(1, 5);
+    fn fill_rect_gradient_restores_context_color() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.set_color(Color::new().green());
+        let from = Color::new().rgb(0, 0, 0).fix();
 
-        assert!(!canvas.is_textual());
+        canvas.fill_rect_gradient(0, 0, 2, 4, &from, &from, GradientDir::Horizontal);
+        canvas.set_pixel(3, 0, true);
 
-        canvas.draw_text_vertical("bar", 0, 1);
+        assert_eq!(canvas.color_buffer, [[from, Color::new().green().fix()]]);
+    }
 
-        assert!(canvas.is_textual());
+    #[test]
+    fn stroke_triangle() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        canvas.stroke_triangle(6, 3, 20, 2, 23, 18);
 
         assert_eq!(
-            canvas.text_buffer,
-            [[""], ["b"], ["a"], ["r"], [""],],
-            "Incorrect text buffer."
+            canvas.to_string(),
+            "\
+⠀⠀⠀⣀⣀⣀⡠⠤⠤⠤⡄⠀⠀⠀⠀
+⠀⠀⠀⠈⠢⣀⠀⠀⠀⠀⢱⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠑⢄⠀⠀⠘⡄⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠑⠤⡀⡇⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠺⠀⠀⠀
+",
         );
     }
 
     #[test]
-    fn draw_text_over_text() {
-        let mut canvas = TextCanvas::new(5, 1);
+    fn fill_triangle() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        canvas.draw_text("bar", 1, 0);
-        canvas.draw_text("foo", 2, 0);
+        canvas.fill_triangle(6, 3, 20, 2, 23, 18);
 
         assert_eq!(
-            canvas.text_buffer,
-            [["", "b", "f", "o", "o"]],
-            "Incorrect text buffer."
+            canvas.to_string(),
+            "\
+⠀⠀⠀⣀⣀⣀⣠⣤⣤⣤⡄⠀⠀⠀⠀
+⠀⠀⠀⠈⠻⣿⣿⣿⣿⣿⣷⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠙⢿⣿⣿⣿⡄⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠙⠿⣿⡇⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠻⠀⠀⠀
+",
         );
     }
 
     #[test]
-    fn draw_text_space_is_transparent() {
-        let mut canvas = TextCanvas::new(9, 1);
+    fn stroke_circle() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        canvas.draw_text("foo bar", 1, 0);
+        canvas.stroke_circle(15, 10, 7);
 
         assert_eq!(
-            canvas.text_buffer,
-            [["", "f", "o", "o", "", "b", "a", "r", ""]],
-            "Incorrect text buffer.",
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⡠⠊⠀⠀⠀⠈⠢⡀⠀⠀⠀
+⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀
+⠀⠀⠀⠀⠣⡀⠀⠀⠀⠀⡠⠃⠀⠀⠀
+⠀⠀⠀⠀⠀⠈⠒⠒⠒⠊⠀⠀⠀⠀⠀
+",
         );
     }
 
     #[test]
-    fn draw_text_space_clears_text() {
-        let mut canvas = TextCanvas::new(5, 1);
+    fn fill_circle() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        canvas.draw_text("bar", 1, 0);
-        canvas.draw_text("  ", 2, 0);
+        canvas.fill_circle(15, 10, 7);
 
         assert_eq!(
-            canvas.text_buffer,
-            [["", "b", "", "", ""]],
-            "Incorrect text buffer.",
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⣠⣾⣿⣿⣿⣿⣦⡀⠀⠀⠀
+⠀⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⡇⠀⠀⠀
+⠀⠀⠀⠀⠻⣿⣿⣿⣿⣿⡿⠃⠀⠀⠀
+⠀⠀⠀⠀⠀⠈⠛⠛⠛⠋⠀⠀⠀⠀⠀
+",
         );
     }
 
     #[test]
-    fn draw_text_with_overflow() {
-        let mut canvas = TextCanvas::new(5, 2);
-
-        // Show partially.
-        canvas.draw_text("foo", -1, 0);
-        canvas.draw_text("bar", 3, 1);
+    fn stroke_ngon() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        // Completely out of bounds.
-        canvas.draw_text("baz1", -10, -1);
-        canvas.draw_text("baz2", 10, -1);
-        canvas.draw_text("baz3", -10, 2);
-        canvas.draw_text("baz4", 10, 2);
+        canvas.stroke_ngon(canvas.cx(), canvas.cy(), 7, 6, 0.0);
 
         assert_eq!(
-            canvas.text_buffer,
-            [["o", "o", "", "", ""], ["", "", "", "b", "a"],],
-            "Incorrect text buffer.",
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⡰⠉⠉⠉⠙⡄⠀⠀⠀⠀
+⠀⠀⠀⠀⢜⠀⠀⠀⠀⠀⢘⠄⠀⠀⠀
+⠀⠀⠀⠀⠈⢆⠀⠀⠀⢠⠊⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠈⠉⠉⠉⠁⠀⠀⠀⠀⠀
+",
         );
     }
 
     #[test]
-    fn draw_text_on_boundaries() {
-        let mut canvas = TextCanvas::new(3, 3);
-
-        canvas.draw_text("a", 0, 1);
-        canvas.draw_text("b", 1, 0);
-        canvas.draw_text("c", 2, 1);
-        canvas.draw_text("d", 1, 2);
+    fn stroke_ngon_at_angle() {
+        use std::f64::consts::PI;
+
+        let mut canvas = TextCanvas::new(15, 5);
+
+        canvas.stroke_ngon(canvas.cx(), canvas.cy(), 7, 6, PI / 2.0);
 
         assert_eq!(
             canvas.to_string(),
-            "⠀b⠀\na⠀c\n⠀d⠀\n",
-            "Incorrect text output.",
+            "\
+⠀⠀⠀⠀⠀⠀⠀⢀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⢠⠔⠊⠁⠉⠢⢄⠀⠀⠀⠀
+⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀
+⠀⠀⠀⠀⠘⠤⡀⠀⠀⣀⠼⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠈⠑⠉⠀⠀⠀⠀⠀⠀
+",
         );
     }
 
     #[test]
-    fn draw_text_with_color() {
-        let mut canvas = TextCanvas::new(3, 1);
-
-        assert!(
-            canvas.text_buffer.is_empty(),
-            "Text buffer should be empty."
-        );
+    fn stroke_ngon_radius_matches_circle() {
+        use std::f64::consts::PI;
 
-        canvas.draw_text("hi!", 0, 0);
+        let mut canvas = TextCanvas::new(15, 5);
 
-        assert_eq!(
-            canvas.text_buffer,
-            [["h", "i", "!"]],
-            "Text should not be colorized.",
-        );
+        canvas.stroke_ngon(canvas.cx(), canvas.cy(), 7, 3, PI / 2.0);
 
-        canvas.set_color(Color::new().bright_red());
-        canvas.draw_text("o!", 1, 0);
+        canvas.stroke_circle(15, 10, 7);
 
         assert_eq!(
-            canvas.text_buffer,
-            [["h", "\x1b[0;91mo\x1b[0m", "\x1b[0;91m!\x1b[0m"]],
-            "'o!' should be red.",
+            canvas.to_string(),
+            "\
+⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⡠⠊⢠⠃⢣⠈⠢⡀⠀⠀⠀
+⠀⠀⠀⠀⡇⡰⠁⠀⠀⢣⠀⡇⠀⠀⠀
+⠀⠀⠀⠀⠳⡓⠒⠢⠤⠤⡧⠃⠀⠀⠀
+⠀⠀⠀⠀⠀⠈⠒⠒⠒⠊⠀⠀⠀⠀⠀
+",
         );
     }
 
     #[test]
-    fn merge_text_space_does_not_clear_text() {
-        let mut canvas = TextCanvas::new(5, 1);
+    fn stroke_ngon_fit_inscribe_is_stroke_ngon() {
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.stroke_ngon_fit(canvas.cx(), canvas.cy(), 7, 5, 0.3, NgonFit::Inscribe);
 
-        canvas.merge_text("bar", 1, 0);
-        canvas.merge_text(" z", 2, 0);
+        let mut expected = TextCanvas::new(15, 5);
+        expected.stroke_ngon(expected.cx(), expected.cy(), 7, 5, 0.3);
 
-        assert_eq!(
-            canvas.text_buffer,
-            [["", "b", "a", "z", ""]],
-            "Incorrect text buffer.",
-        );
+        assert_eq!(canvas, expected);
     }
 
     #[test]
-    fn merge_text_vertical() {
-        let mut canvas = TextCanvas::new(1, 5);
-
-        assert!(!canvas.is_textual());
+    fn stroke_ngon_fit_circumscribe_square() {
+        use std::f64::consts::PI;
 
-        canvas.merge_text_vertical("bar", 0, 1);
-        canvas.merge_text_vertical(" z", 0, 2);
+        let mut canvas = TextCanvas::new(15, 5);
+        let (cx, cy) = (canvas.cx(), canvas.cy());
+        canvas.stroke_ngon_fit(cx, cy, 4, 4, PI / 4.0, NgonFit::Circumscribe);
 
-        assert!(canvas.is_textual());
+        // Sides are tangent to the circle, 4 pixels from the center.
+        let mut expected = TextCanvas::new(15, 5);
+        expected.stroke_line(cx + 4, cy - 4, cx - 4, cy - 4);
+        expected.stroke_line(cx - 4, cy - 4, cx - 4, cy + 4);
+        expected.stroke_line(cx - 4, cy + 4, cx + 4, cy + 4);
+        expected.stroke_line(cx + 4, cy + 4, cx + 4, cy - 4);
 
-        assert_eq!(
-            canvas.text_buffer,
-            [[""], ["b"], ["a"], ["z"], [""],],
-            "Incorrect text buffer."
-        );
+        assert_eq!(canvas, expected);
     }
 
     #[test]
-    fn get_text_as_string() {
-        let mut canvas = TextCanvas::new(5, 3);
-
-        canvas.draw_text("foo", 1, 1);
+    #[should_panic(expected = "Minimum 3 sides needed to draw an n-gon, but only 2 requested.")]
+    fn stroke_ngon_fit_not_enough_sides() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        assert_eq!(
-            canvas.to_string(),
-            "⠀⠀⠀⠀⠀\n⠀foo⠀\n⠀⠀⠀⠀⠀\n",
-            "Incorrect output string."
-        );
+        canvas.stroke_ngon_fit(canvas.cx(), canvas.cy(), 7, 2, 0.0, NgonFit::Circumscribe);
     }
 
     #[test]
-    fn get_text_as_string_colored() {
-        let mut canvas = TextCanvas::new(5, 3);
+    fn fill_ngon() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        canvas.set_color(Color::new().bright_green());
-        canvas.draw_text("foo", 1, 1);
+        canvas.fill_ngon(canvas.cx(), canvas.cy(), 7, 6, 0.0);
 
         assert_eq!(
             canvas.to_string(),
-            "⠀⠀⠀⠀⠀\n⠀\x1b[0;92mf\x1b[0m\x1b[0;92mo\x1b[0m\x1b[0;92mo\x1b[0m⠀\n⠀⠀⠀⠀⠀\n",
-            "Incorrect output string.",
+            "\
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⣰⣿⣿⣿⣿⡄⠀⠀⠀⠀
+⠀⠀⠀⠀⢼⣿⣿⣿⣿⣿⣿⠄⠀⠀⠀
+⠀⠀⠀⠀⠈⢿⣿⣿⣿⣿⠋⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠈⠉⠉⠉⠁⠀⠀⠀⠀⠀
+",
         );
     }
 
     #[test]
-    fn clear_clears_text_buffer() {
-        let mut canvas = TextCanvas::new(2, 1);
-
-        assert!(
-            canvas.text_buffer.is_empty(),
-            "Text buffer should be empty."
-        );
+    #[should_panic(expected = "Minimum 3 sides needed to draw an n-gon, but only 2 requested.")]
+    fn fill_ngon_not_enough_sides() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        canvas.set_color(Color::new().bright_red());
-        canvas.draw_text("hi", 0, 0);
+        canvas.fill_ngon(canvas.cx(), canvas.cy(), 7, 2, 0.0);
+    }
 
-        assert_eq!(
-            canvas.text_buffer,
-            [["\x1b[0;91mh\x1b[0m", "\x1b[0;91mi\x1b[0m"]],
-            "Text should be colorized.",
+    #[test]
+    fn fill_ngon_gradient_has_same_shape_as_fill_ngon() {
+        let (from, to) = (
+            Color::new().rgb(0, 0, 0).fix(),
+            Color::new().rgb(255, 255, 255).fix(),
         );
 
-        canvas.clear();
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.fill_ngon_gradient(canvas.cx(), canvas.cy(), 7, 6, 0.0, &from, &to);
 
-        assert_eq!(
-            canvas.text_buffer,
-            [["", ""]],
-            "Text buffer should be full of no-colored empty chars.",
-        );
+        let mut expected = TextCanvas::new(15, 5);
+        expected.fill_ngon(expected.cx(), expected.cy(), 7, 6, 0.0);
+
+        assert_eq!(canvas.buffer, expected.buffer);
     }
 
     #[test]
-    fn clear_edits_text_buffer_in_place() {
-        let mut canvas = TextCanvas::new(2, 2);
-        canvas.draw_text("hi", 0, 0);
+    fn fill_ngon_gradient_colors_slices() {
+        let (from, to) = (
+            Color::new().rgb(0, 0, 0).fix(),
+            Color::new().rgb(90, 90, 90).fix(),
+        );
 
-        let text_buffer = canvas.text_buffer.as_ptr();
-        let row_0 = canvas.text_buffer[0].as_ptr();
-        let row_1 = canvas.text_buffer[1].as_ptr();
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.fill_ngon_gradient(canvas.cx(), canvas.cy(), 9, 4, 0.0, &from, &to);
 
-        canvas.clear();
+        let output = canvas.to_string();
+        for level in [0, 30, 60, 90] {
+            let color = format!("\x1b[0;38;2;{level};{level};{level}m");
+            assert!(output.contains(&color), "Missing slice color {level}.");
+        }
+    }
 
-        assert_eq!(
-            text_buffer,
-            canvas.text_buffer.as_ptr(),
-            "Container should be the same as before."
-        );
-        assert_eq!(
-            row_0,
-            canvas.text_buffer[0].as_ptr(),
-            "Container should be the same as before."
-        );
-        assert_eq!(
-            row_1,
-            canvas.text_buffer[1].as_ptr(),
-            "Container should be the same as before."
+    #[test]
+    fn fill_ngon_gradient_keeps_context_color() {
+        let (from, to) = (
+            Color::new().rgb(0, 0, 0).fix(),
+            Color::new().rgb(255, 255, 255).fix(),
         );
-    }
 
-    // Drawing primitives.
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.set_color(Color::new().red());
+        canvas.fill_ngon_gradient(1, 1, 1, 3, 0.0, &from, &to);
+        canvas.draw_text("a", 2, 0);
+
+        assert!(canvas.to_string().contains("\x1b[0;31ma\x1b[0m"));
+    }
 
     #[test]
-    fn stroke_line() {
+    #[should_panic(expected = "Minimum 3 sides needed to draw an n-gon, but only 2 requested.")]
+    fn fill_ngon_gradient_not_enough_sides() {
         let mut canvas = TextCanvas::new(15, 5);
+        let color = Color::new();
 
-        let top_left = (0, 0);
-        let top_right = (canvas.w(), 0);
-        let bottom_right = (canvas.w(), canvas.h());
-        let bottom_left = (0, canvas.h());
-        let center = (canvas.cx(), canvas.cy());
-        let center_top = (canvas.cx(), 0);
-        let center_right = (canvas.w(), canvas.cy());
-        let center_bottom = (canvas.cx(), canvas.h());
-        let center_left = (0, canvas.cy());
+        canvas.fill_ngon_gradient(canvas.cx(), canvas.cy(), 7, 2, 0.0, &color, &color);
+    }
 
-        canvas.stroke_line(center.0, center.1, top_left.0, top_left.1);
-        canvas.stroke_line(center.0, center.1, top_right.0, top_right.1);
-        canvas.stroke_line(center.0, center.1, bottom_right.0, bottom_right.1);
-        canvas.stroke_line(center.0, center.1, bottom_left.0, bottom_left.1);
-        canvas.stroke_line(center.0, center.1, center_top.0, center_top.1);
-        canvas.stroke_line(center.0, center.1, center_right.0, center_right.1);
-        canvas.stroke_line(center.0, center.1, center_bottom.0, center_bottom.1);
-        canvas.stroke_line(center.0, center.1, center_left.0, center_left.1);
+    #[test]
+    fn stroke_bezier_path_with_aligned_control_points_is_a_line() {
+        let mut canvas = TextCanvas::new(15, 5);
+        canvas.stroke_line(0, 10, 29, 10);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠑⠢⣀⠀⠀⠀⠀⢸⠀⠀⠀⠀⢀⠔⠊
-⠀⠀⠀⠑⠢⣀⠀⢸⠀⢀⠤⠊⠁⠀⠀
-⠤⠤⠤⠤⠤⠤⢵⣾⣶⠥⠤⠤⠤⠤⠤
-⠀⠀⠀⣀⠤⠊⠁⢸⠀⠑⠢⣀⠀⠀⠀
-⡠⠔⠊⠀⠀⠀⠀⢸⠀⠀⠀⠀⠉⠢⢄
-",
-            "Lines not drawn correctly.",
-        );
+        let mut bezier = TextCanvas::new(15, 5);
+        bezier.stroke_bezier_path(&[(0, 10), (10, 10), (20, 10), (29, 10)]);
+
+        assert_eq!(bezier.buffer, canvas.buffer);
     }
 
     #[test]
-    fn stroke_line_from_outside_to_outside() {
+    fn stroke_bezier_path_segments_are_chained() {
         let mut canvas = TextCanvas::new(15, 5);
+        canvas.stroke_bezier_path(&[(0, 0), (0, 0), (14, 19), (14, 19)]);
+        canvas.stroke_bezier_path(&[(14, 19), (14, 19), (29, 0), (29, 0)]);
 
-        canvas.stroke_line(-10, -10, canvas.w() + 10, canvas.h() + 10);
+        let mut path = TextCanvas::new(15, 5);
+        path.stroke_bezier_path(&[
+            (0, 0),
+            (0, 0),
+            (14, 19),
+            (14, 19),
+            (14, 19),
+            (29, 0),
+            (29, 0),
+        ]);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠉⠢⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠈⠑⢄⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠉⠢⣀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠑⢄⡀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠢⣀⠀
-",
-            "Line not drawn correctly.",
-        );
+        assert_eq!(path.buffer, canvas.buffer);
     }
 
     #[test]
-    fn erase_line() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn stroke_bezier_path_single_point() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-        canvas.fill();
+        canvas.stroke_bezier_path(&[(1, 1)]);
 
-        let top_left = (0, 0);
-        let bottom_right = (canvas.w(), canvas.h());
+        assert_eq!(canvas.to_string(), "⠐⠀\n");
+    }
 
-        canvas.invert();
-        canvas.stroke_line(top_left.0, top_left.1, bottom_right.0, bottom_right.1);
+    #[test]
+    #[should_panic(expected = "A Bézier path needs 1 + 3k points, but 3 were given.")]
+    fn stroke_bezier_path_invalid_number_of_points() {
+        let mut canvas = TextCanvas::new(15, 5);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⣮⣝⠿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
-⣿⣿⣿⣮⣝⠿⣿⣿⣿⣿⣿⣿⣿⣿⣿
-⣿⣿⣿⣿⣿⣿⣮⣝⡻⣿⣿⣿⣿⣿⣿
-⣿⣿⣿⣿⣿⣿⣿⣿⣿⣶⣝⡻⣿⣿⣿
-⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣶⣝⡻
-",
-            "Line not erased correctly.",
-        );
+        canvas.stroke_bezier_path(&[(0, 0), (5, 5), (10, 0)]);
     }
 
     #[test]
-    fn stroke_rect() {
+    #[should_panic(expected = "A Bézier path needs 1 + 3k points, but 0 were given.")]
+    fn stroke_bezier_path_no_points() {
         let mut canvas = TextCanvas::new(15, 5);
 
-        canvas.stroke_rect(6, 3, 20, 15);
+        canvas.stroke_bezier_path(&[]);
+    }
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⠀⠀
-⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀
-⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀
-⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⠀⠀⢸⠀⠀
-⠀⠀⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀⠀
-",
-        );
+    #[test]
+    fn blend_mode() {
+        let cases = [(false, false), (false, true), (true, false), (true, true)];
+        let blend = |mode: BlendMode| cases.map(|(a, b)| mode.blend(a, b));
+
+        assert_eq!(blend(BlendMode::Or), [false, true, true, true]);
+        assert_eq!(blend(BlendMode::And), [false, false, false, true]);
+        assert_eq!(blend(BlendMode::Xor), [false, true, true, false]);
+        assert_eq!(blend(BlendMode::Subtract), [false, false, true, false]);
     }
 
     #[test]
-    fn frame() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn blend_canvas_with_offset() {
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.fill();
 
-        canvas.frame();
+        let mut overlay = TextCanvas::new(1, 1);
+        overlay.fill();
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⡏⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⢹
-⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
-⣇⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣸
-",
-        );
+        canvas.blend_canvas(&overlay, 2, 0, BlendMode::Subtract);
+        canvas.blend_canvas(&overlay, -1, 0, BlendMode::Subtract);
+
+        assert_eq!(canvas.to_string(), "⢸⠀⣿\n");
     }
 
     #[test]
-    fn fill_rect() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn blend_canvas_with_color_and_text() {
+        let mut canvas = TextCanvas::new(3, 1);
+        canvas.set_color(Color::new().red());
+        canvas.fill_rect(0, 0, 4, 4);
+        canvas.draw_text("a", 2, 0);
 
-        canvas.fill_rect(6, 3, 20, 15);
+        let mut overlay = TextCanvas::new(3, 1);
+        overlay.set_color(Color::new().green());
+        overlay.fill_rect(2, 0, 4, 4);
+        overlay.draw_text("b", 0, 0);
+
+        canvas.blend_canvas(&overlay, 0, 0, BlendMode::Or);
 
         assert_eq!(
             canvas.to_string(),
-            "\
-⠀⠀⠀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⠀⠀
-⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠀⠀
-⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠀⠀
-⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠀⠀
-⠀⠀⠀⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠀⠀
-",
+            "\x1b[0;32mb⣿\x1b[0m\x1b[0;31ma\x1b[0m\n"
         );
     }
 
     #[test]
-    fn stroke_triangle() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn grid_of_charts() {
+        let mut charts = Vec::new();
+        for f in [f64::sin, f64::cos, |x: f64| x, |x: f64| x * x] {
+            let mut canvas = TextCanvas::new(20, 5);
+            crate::charts::Chart::function(&mut canvas, -3.0, 3.0, &f);
+            charts.push(canvas);
+        }
+        let cells: Vec<&TextCanvas> = charts.iter().collect();
 
-        canvas.stroke_triangle(6, 3, 20, 2, 23, 18);
+        let canvas = TextCanvas::grid(&cells, 2, 1);
 
+        assert_eq!(canvas.output.width(), 41);
+        assert_eq!(canvas.output.height(), 11);
         assert_eq!(
             canvas.to_string(),
             "\
-⠀⠀⠀⣀⣀⣀⡠⠤⠤⠤⡄⠀⠀⠀⠀
-⠀⠀⠀⠈⠢⣀⠀⠀⠀⠀⢱⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠑⢄⠀⠀⠘⡄⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠑⠤⡀⡇⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠺⠀⠀⠀
-",
+⠀⠀0.9854⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀⠀⠀⠀0.9801⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⢠⠚⠉⠑⠢⢸⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⢠⠊⠉⠙⢄⠀⠀⢸⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠣⢄⠔⠉⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⡔⠁⠀⠀⠀⠀⠣⢄⢸⠀
+⠀-0.9854⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀⠀⠀-0.9900⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀
+⠀⠀⠀⠀⠀⠀⠀⠀-3⠀⠀⠀⠀⠀⠀⠀⠀⠀3⠀⠀⠀⠀⠀⠀⠀⠀⠀-3⠀⠀⠀⠀⠀⠀⠀⠀⠀3
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀3⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀⠀⠀⠀⠀⠀⠀⠀⠀9⠀⡤⠤⠤⠤⠤⠤⠤⠤⠤⢤⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀⣀⠤⠒⠋⠉⢸⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⢣⠀⠀⠀⠀⠀⡠⠊⢸⠀
+⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⡤⠒⠉⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡇⠀⠑⠤⣀⠤⠊⠁⠀⢸⠀
+⠀⠀⠀⠀⠀⠀-3⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀⠀⠀⠀0.0400⠀⠓⠒⠒⠒⠒⠒⠒⠒⠒⠚⠀
+⠀⠀⠀⠀⠀⠀⠀⠀-3⠀⠀⠀⠀⠀⠀⠀⠀⠀3⠀⠀⠀⠀⠀⠀⠀⠀⠀-3⠀⠀⠀⠀⠀⠀⠀⠀⠀3
+"
         );
     }
 
     #[test]
-    fn fill_triangle() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn grid_cells_have_uniform_size() {
+        let mut a = TextCanvas::new(2, 1);
+        a.fill();
+        let mut b = TextCanvas::new(1, 2);
+        b.fill();
 
-        canvas.fill_triangle(6, 3, 20, 2, 23, 18);
+        // More columns than cells.
+        let canvas = TextCanvas::grid(&[&a, &b], 5, 0);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⣀⣀⣀⣠⣤⣤⣤⡄⠀⠀⠀⠀
-⠀⠀⠀⠈⠻⣿⣿⣿⣿⣿⣷⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠙⢿⣿⣿⣿⡄⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠙⠿⣿⡇⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠻⠀⠀⠀
-",
-        );
+        assert_eq!(canvas.to_string(), "⣿⣿⣿⠀\n⠀⠀⣿⠀\n");
     }
 
     #[test]
-    fn stroke_circle() {
-        let mut canvas = TextCanvas::new(15, 5);
+    #[should_panic(expected = "Grid must have at least one cell.")]
+    fn grid_without_cells_panics() {
+        let _ = TextCanvas::grid(&[], 2, 0);
+    }
 
-        canvas.stroke_circle(15, 10, 7);
+    #[test]
+    #[should_panic(expected = "Grid must have at least one column.")]
+    fn grid_without_columns_panics() {
+        let canvas = TextCanvas::new(1, 1);
+        let _ = TextCanvas::grid(&[&canvas], 0, 0);
+    }
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⡠⠊⠀⠀⠀⠈⠢⡀⠀⠀⠀
-⠀⠀⠀⠀⡇⠀⠀⠀⠀⠀⠀⡇⠀⠀⠀
-⠀⠀⠀⠀⠣⡀⠀⠀⠀⠀⡠⠃⠀⠀⠀
-⠀⠀⠀⠀⠀⠈⠒⠒⠒⠊⠀⠀⠀⠀⠀
-",
-        );
+    #[test]
+    #[should_panic(expected = "Grid gap cannot be negative.")]
+    fn grid_with_negative_gap_panics() {
+        let canvas = TextCanvas::new(1, 1);
+        let _ = TextCanvas::grid(&[&canvas], 1, -1);
     }
 
     #[test]
-    fn fill_circle() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn blended_xor() {
+        let mut a = TextCanvas::new(4, 1);
+        a.map_pixels(|x, _, _| x % 2 == 0);
 
-        canvas.fill_circle(15, 10, 7);
+        let mut b = TextCanvas::new(4, 1);
+        b.map_pixels(|_, y, _| y % 2 == 0);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⣠⣾⣿⣿⣿⣿⣦⡀⠀⠀⠀
-⠀⠀⠀⠀⣿⣿⣿⣿⣿⣿⣿⡇⠀⠀⠀
-⠀⠀⠀⠀⠻⣿⣿⣿⣿⣿⡿⠃⠀⠀⠀
-⠀⠀⠀⠀⠀⠈⠛⠛⠛⠋⠀⠀⠀⠀⠀
-",
-        );
+        let canvas = TextCanvas::blended(&a, &b, BlendMode::Xor).unwrap();
+
+        assert_eq!(canvas.to_string(), "⡪⡪⡪⡪\n");
+        // Inputs are untouched.
+        assert_eq!(a.to_string(), "⡇⡇⡇⡇\n");
+        assert_eq!(b.to_string(), "⠭⠭⠭⠭\n");
     }
 
     #[test]
-    fn stroke_ngon() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn blended_different_sizes() {
+        let a = TextCanvas::new(4, 1);
+        let b = TextCanvas::new(4, 2);
 
-        canvas.stroke_ngon(canvas.cx(), canvas.cy(), 7, 6, 0.0);
+        let res = TextCanvas::blended(&a, &b, BlendMode::Or);
 
         assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⡰⠉⠉⠉⠙⡄⠀⠀⠀⠀
-⠀⠀⠀⠀⢜⠀⠀⠀⠀⠀⢘⠄⠀⠀⠀
-⠀⠀⠀⠀⠈⢆⠀⠀⠀⢠⠊⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠈⠉⠉⠉⠁⠀⠀⠀⠀⠀
-",
+            res.unwrap_err().to_string(),
+            "cannot blend canvases of different sizes"
         );
     }
 
     #[test]
-    fn stroke_ngon_at_angle() {
-        use std::f64::consts::PI;
-
-        let mut canvas = TextCanvas::new(15, 5);
+    fn draw_luminance_gradient() {
+        let mut canvas = TextCanvas::new(5, 1);
 
-        canvas.stroke_ngon(canvas.cx(), canvas.cy(), 7, 6, PI / 2.0);
+        let data = vec![vec![0.0, 0.25, 0.5, 0.75, 1.0]];
+        canvas.draw_luminance(&data, 0.5);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⠀⢀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⢠⠔⠊⠁⠉⠢⢄⠀⠀⠀⠀
-⠀⠀⠀⠀⢸⠀⠀⠀⠀⠀⢸⠀⠀⠀⠀
-⠀⠀⠀⠀⠘⠤⡀⠀⠀⣀⠼⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠈⠑⠉⠀⠀⠀⠀⠀⠀
-",
-        );
+        assert_eq!(canvas.to_string(), "⠀⠀⠀⣿⣿\n");
     }
 
     #[test]
-    fn stroke_ngon_radius_matches_circle() {
-        use std::f64::consts::PI;
+    fn draw_luminance_vertical_gradient() {
+        let mut canvas = TextCanvas::new(2, 2);
 
-        let mut canvas = TextCanvas::new(15, 5);
+        let data = vec![vec![0.0], vec![0.3], vec![0.6], vec![0.9]];
+        canvas.draw_luminance(&data, 0.5);
 
-        canvas.stroke_ngon(canvas.cx(), canvas.cy(), 7, 3, PI / 2.0);
+        assert_eq!(canvas.to_string(), "⠀⠀\n⣿⣿\n");
+    }
 
-        canvas.stroke_circle(15, 10, 7);
+    #[test]
+    fn draw_luminance_upscales_nearest_neighbor() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⣀⣀⣀⡀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⡠⠊⢠⠃⢣⠈⠢⡀⠀⠀⠀
-⠀⠀⠀⠀⡇⡰⠁⠀⠀⢣⠀⡇⠀⠀⠀
-⠀⠀⠀⠀⠳⡓⠒⠢⠤⠤⡧⠃⠀⠀⠀
-⠀⠀⠀⠀⠀⠈⠒⠒⠒⠊⠀⠀⠀⠀⠀
-",
-        );
+        let data = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+        canvas.draw_luminance(&data, 0.5);
+
+        assert_eq!(canvas.to_string(), "⠛⣤\n");
     }
 
     #[test]
-    fn fill_ngon() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn draw_luminance_empty_data() {
+        let mut canvas = TextCanvas::new(2, 1);
 
-        canvas.fill_ngon(canvas.cx(), canvas.cy(), 7, 6, 0.0);
+        canvas.draw_luminance(&[], 0.5);
+        canvas.draw_luminance(&[vec![]], 0.5);
 
-        assert_eq!(
-            canvas.to_string(),
-            "\
-⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⣰⣿⣿⣿⣿⡄⠀⠀⠀⠀
-⠀⠀⠀⠀⢼⣿⣿⣿⣿⣿⣿⠄⠀⠀⠀
-⠀⠀⠀⠀⠈⢿⣿⣿⣿⣿⠋⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠈⠉⠉⠉⠁⠀⠀⠀⠀⠀
-",
-        );
+        assert_eq!(canvas.to_string(), "⠀⠀\n");
     }
 
     #[test]
-    #[should_panic(expected = "Minimum 3 sides needed to draw an n-gon, but only 2 requested.")]
-    fn fill_ngon_not_enough_sides() {
-        let mut canvas = TextCanvas::new(15, 5);
+    fn draw_luminance_leaves_dark_pixels_untouched() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.fill();
 
-        canvas.fill_ngon(canvas.cx(), canvas.cy(), 7, 2, 0.0);
+        canvas.draw_luminance(&[vec![0.0, 1.0]], 0.5);
+
+        assert_eq!(canvas.to_string(), "⣿⣿\n");
     }
 
     #[test]
@@ -2953,11 +10103,11 @@ mod tests {
         assert_eq!(
             canvas.to_string(),
             "\
-⠀\x1b[0;31m⢀\x1b[0m\x1b[0;31m⣀\x1b[0m\x1b[0;31m⣀\x1b[0m\x1b[0;31m⣀\x1b[0m\x1b[0;31m⣀\x1b[0m\x1b[0;31m⡀\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀
-⠀\x1b[0;31m⢸\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⡇\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀
-⠀\x1b[0;31m⢸\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⣿\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀\x1b[0;31m⠈\x1b[0m\x1b[0;31m⠉\x1b[0m\x1b[0;31m⠉\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m⠀⠀
+⠀\x1b[0;31m⢀⣀⣀⣀⣀⡀\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀
+⠀\x1b[0;31m⢸⣿⣿⣿⣿⡇\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀
+⠀\x1b[0;31m⢸⣿⣿\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀\x1b[0;31m⠈⠉⠉\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀\x1b[0;32m⣿⣿⣿⣿⣿\x1b[0m⠀⠀
 "
         );
     }
@@ -2986,7 +10136,7 @@ mod tests {
 ⠀⢸⣿⣿⣿⣿⡇⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⢸⣿⣿⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⠈⠉⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀⠀⠀⠀⠀\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m⠀⠀
+⠀⠀⠀⠀⠀⠀⠀⠀\x1b[0;32m⣿⣿⣿⣿⣿\x1b[0m⠀⠀
 "
         );
     }
@@ -3013,6 +10163,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn draw_canvas_preserve_text_keeps_labels() {
+        let mut canvas = TextCanvas::new(7, 3);
+        canvas.draw_text("abcde", 1, 1);
+
+        let mut overlay = TextCanvas::new(7, 3);
+        overlay.stroke_line(0, 0, overlay.w(), overlay.h());
+        overlay.draw_text("012", 2, 2);
+
+        canvas.draw_canvas_preserve_text(&overlay, 0, 0);
+
+        assert_eq!(
+            canvas.to_string(),
+            "\
+⠑⢄⡀⠀⠀⠀⠀
+⠀abcde⠀
+⠀⠀012⠑⢄
+"
+        );
+    }
+
+    #[test]
+    fn draw_canvas_preserve_text_overrides_pixels() {
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.fill();
+        canvas.draw_text("a", 0, 0);
+
+        let mut overlay = TextCanvas::new(2, 1);
+        overlay.draw_text("", 0, 0); // Textual, but empty.
+
+        canvas.draw_canvas_preserve_text(&overlay, 0, 0);
+
+        assert_eq!(canvas.to_string(), "a⠀\n");
+    }
+
     #[test]
     fn draw_canvas_with_colored_text() {
         let mut canvas = TextCanvas::new(7, 3);
@@ -3031,7 +10216,7 @@ mod tests {
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀
-⠀\x1b[0;31ma\x1b[0m⠀⠀\x1b[0;32m0\x1b[0m\x1b[0;32m1\x1b[0m\x1b[0;32m2\x1b[0m
+⠀\x1b[0;31ma\x1b[0m⠀⠀\x1b[0;32m012\x1b[0m
 ⠀⠀⠀⠀⠀⠀⠀
 "
         );
@@ -3059,7 +10244,7 @@ mod tests {
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀\x1b[0;32m0\x1b[0m\x1b[0;32m1\x1b[0m\x1b[0;32m2\x1b[0m
+⠀⠀⠀⠀\x1b[0;32m012\x1b[0m
 ⠀⠀⠀⠀⠀⠀⠀
 "
         );
@@ -3088,12 +10273,122 @@ mod tests {
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀
-⠀a⠀⠀\x1b[0;32m0\x1b[0m\x1b[0;32m1\x1b[0m\x1b[0;32m2\x1b[0m
+⠀a⠀⠀\x1b[0;32m012\x1b[0m
 ⠀⠀⠀⠀⠀⠀⠀
 "
         );
     }
 
+    #[test]
+    fn draw_canvas_scaled_halves_size() {
+        let mut drawing = TextCanvas::new(8, 4);
+        drawing.fill_rect(0, 0, 8, 8);
+        drawing.fill_rect(8, 8, 8, 8);
+
+        let mut canvas = TextCanvas::new(8, 4);
+        canvas.draw_canvas_scaled(&drawing, 0, 0, 0.5, 0.5);
+
+        let mut expected = TextCanvas::new(8, 4);
+        expected.fill_rect(0, 0, 4, 4);
+        expected.fill_rect(4, 4, 4, 4);
+
+        assert_eq!(canvas, expected);
+    }
+
+    #[test]
+    fn draw_canvas_scaled_doubles_size() {
+        let mut drawing = TextCanvas::new(2, 1);
+        drawing.set_pixel(0, 0, true);
+        drawing.set_pixel(3, 3, true);
+
+        let mut canvas = TextCanvas::new(4, 2);
+        canvas.draw_canvas_scaled(&drawing, 0, 0, 2.0, 2.0);
+
+        let mut expected = TextCanvas::new(4, 2);
+        expected.fill_rect(0, 0, 2, 2);
+        expected.fill_rect(6, 6, 2, 2);
+
+        assert_eq!(canvas, expected);
+    }
+
+    #[test]
+    fn draw_canvas_scaled_with_overflow() {
+        let mut drawing = TextCanvas::new(2, 1);
+        drawing.fill();
+
+        let mut canvas = TextCanvas::new(3, 2);
+        canvas.draw_canvas_scaled(&drawing, 4, 6, 2.0, 2.0);
+        canvas.draw_canvas_scaled(&drawing, -6, -6, 2.0, 2.0);
+
+        assert_eq!(canvas.to_string(), "⠛⠀⠀\n⠀⠀⣤\n");
+    }
+
+    #[test]
+    fn draw_canvas_scaled_only_iterates_visible_part() {
+        let mut drawing = TextCanvas::new(2, 1);
+        drawing.fill();
+
+        let mut canvas = TextCanvas::new(3, 2);
+        // Would be billions of pixels if iterated in full.
+        canvas.draw_canvas_scaled(&drawing, i32::MAX, 0, 1e9, 1e9);
+        canvas.draw_canvas_scaled(&drawing, 0, i32::MIN, 1e9, 1.0);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀\n⠀⠀⠀\n");
+
+        canvas.draw_canvas_scaled(&drawing, -i32::MAX, -i32::MAX, 1e9, 1e9);
+
+        assert_eq!(canvas.to_string(), "⣿⣿⣿\n⣿⣿⣿\n");
+    }
+
+    #[test]
+    fn visible_range() {
+        assert_eq!(TextCanvas::visible_range(0, 4, 10), 0..4);
+        assert_eq!(TextCanvas::visible_range(8, 4, 10), 8..10);
+        assert_eq!(TextCanvas::visible_range(-2, 4, 10), 0..2);
+        assert_eq!(TextCanvas::visible_range(-2, 40, 10), 0..10);
+        assert!(TextCanvas::visible_range(10, 4, 10).is_empty());
+        assert!(TextCanvas::visible_range(-4, 4, 10).is_empty());
+        assert_eq!(TextCanvas::visible_range(i32::MIN, usize::MAX, 10), 0..10);
+        assert!(TextCanvas::visible_range(i32::MAX, usize::MAX, 10).is_empty());
+    }
+
+    #[test]
+    fn draw_canvas_scaled_with_color() {
+        let mut drawing = TextCanvas::new(2, 1);
+        drawing.set_color(Color::new().red());
+        drawing.fill_rect(0, 0, 2, 4);
+
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.draw_canvas_scaled(&drawing, 0, 0, 0.5, 1.0);
+
+        assert_eq!(canvas.to_string(), "\x1b[0;31m⡇\x1b[0m⠀\n");
+    }
+
+    #[test]
+    fn draw_canvas_scaled_ignores_text() {
+        let mut drawing = TextCanvas::new(2, 1);
+        drawing.draw_text("ab", 0, 0);
+
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.draw_canvas_scaled(&drawing, 0, 0, 1.0, 1.0);
+
+        assert_eq!(canvas.to_string(), "⠀⠀\n");
+    }
+
+    #[test]
+    fn draw_canvas_scaled_with_invalid_scale() {
+        let mut drawing = TextCanvas::new(2, 1);
+        drawing.fill();
+
+        let mut canvas = TextCanvas::new(2, 1);
+        canvas.draw_canvas_scaled(&drawing, 0, 0, 0.0, 1.0);
+        canvas.draw_canvas_scaled(&drawing, 0, 0, 1.0, -1.0);
+        canvas.draw_canvas_scaled(&drawing, 0, 0, f64::NAN, 1.0);
+        canvas.draw_canvas_scaled(&drawing, 0, 0, 1.0, f64::INFINITY);
+
+        assert_eq!(canvas.to_string(), "⠀⠀\n");
+    }
+
     #[test]
     fn merge_canvas() {
         let mut canvas = TextCanvas::new(15, 5);
@@ -3162,11 +10457,11 @@ mod tests {
         assert_eq!(
             canvas.to_string(),
             "\
-⠀\x1b[0;31m⢀\x1b[0m\x1b[0;31m⣀\x1b[0m\x1b[0;31m⣀\x1b[0m\x1b[0;31m⣀\x1b[0m\x1b[0;31m⣀\x1b[0m\x1b[0;31m⡀\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀
-⠀\x1b[0;31m⢸\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⡇\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀
-⠀\x1b[0;31m⢸\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;31m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m⠀⠀⠀⠀⠀⠀
-⠀\x1b[0;31m⠈\x1b[0m\x1b[0;31m⠉\x1b[0m\x1b[0;31m⠉\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀\x1b[0;32m⠛\x1b[0m\x1b[0;32m⠛\x1b[0m\x1b[0;32m⠛\x1b[0m\x1b[0;32m⠛\x1b[0m\x1b[0;32m⠛\x1b[0m⠀⠀⠀⠀⠀⠀
+⠀\x1b[0;31m⢀⣀⣀⣀⣀⡀\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀
+⠀\x1b[0;31m⢸⣿⣿⣿⣿⡇\x1b[0m⠀⠀⠀⠀⠀⠀⠀⠀
+⠀\x1b[0;31m⢸⣿⣿\x1b[0m\x1b[0;32m⣿⣿⣿⣿⣿\x1b[0m⠀⠀⠀⠀⠀⠀
+⠀\x1b[0;31m⠈⠉⠉\x1b[0m\x1b[0;32m⣿⣿⣿⣿⣿\x1b[0m⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀\x1b[0;32m⠛⠛⠛⠛⠛\x1b[0m⠀⠀⠀⠀⠀⠀
 "
         );
     }
@@ -3193,9 +10488,9 @@ mod tests {
             "\
 ⠀⢀⣀⣀⣀⣀⡀⠀⠀⠀⠀⠀⠀⠀⠀
 ⠀⢸⣿⣿⣿⣿⡇⠀⠀⠀⠀⠀⠀⠀⠀
-⠀⢸⣿⣿\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m⠀⠀⠀⠀⠀⠀
-⠀⠈⠉⠉\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m\x1b[0;32m⣿\x1b[0m⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀\x1b[0;32m⠛\x1b[0m\x1b[0;32m⠛\x1b[0m\x1b[0;32m⠛\x1b[0m\x1b[0;32m⠛\x1b[0m\x1b[0;32m⠛\x1b[0m⠀⠀⠀⠀⠀⠀
+⠀⢸⣿⣿\x1b[0;32m⣿⣿⣿⣿⣿\x1b[0m⠀⠀⠀⠀⠀⠀
+⠀⠈⠉⠉\x1b[0;32m⣿⣿⣿⣿⣿\x1b[0m⠀⠀⠀⠀⠀⠀
+⠀⠀⠀⠀\x1b[0;32m⠛⠛⠛⠛⠛\x1b[0m⠀⠀⠀⠀⠀⠀
 "
         );
     }
@@ -3240,7 +10535,7 @@ mod tests {
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀
-⠀\x1b[0;31ma\x1b[0m\x1b[0;31mb\x1b[0m\x1b[0;31mc\x1b[0m\x1b[0;32m0\x1b[0m\x1b[0;32m1\x1b[0m\x1b[0;32m2\x1b[0m
+⠀\x1b[0;31mabc\x1b[0m\x1b[0;32m012\x1b[0m
 ⠀⠀⠀⠀⠀⠀⠀
 "
         );
@@ -3268,7 +10563,7 @@ mod tests {
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀
-⠀⠀⠀⠀\x1b[0;32m0\x1b[0m\x1b[0;32m1\x1b[0m\x1b[0;32m2\x1b[0m
+⠀⠀⠀⠀\x1b[0;32m012\x1b[0m
 ⠀⠀⠀⠀⠀⠀⠀
 "
         );
@@ -3297,7 +10592,7 @@ mod tests {
             canvas.to_string(),
             "\
 ⠀⠀⠀⠀⠀⠀⠀
-⠀abc\x1b[0;32m0\x1b[0m\x1b[0;32m1\x1b[0m\x1b[0;32m2\x1b[0m
+⠀abc\x1b[0;32m012\x1b[0m
 ⠀⠀⠀⠀⠀⠀⠀
 "
         );
@@ -3326,10 +10621,109 @@ mod tests {
             "\
 ⠀⠀⠀\x1b[0;33m⢸\x1b[0m⠀⠀⠀
 ⠀⠀⠀\x1b[0;32m0\x1b[0m⠀⠀⠀
-\x1b[0;31ma\x1b[0m\x1b[0;31mb\x1b[0m\x1b[0;31mc\x1b[0m\x1b[0;32m1\x1b[0m\x1b[0;31me\x1b[0m\x1b[0;31mf\x1b[0m\x1b[0;31mg\x1b[0m
-\x1b[0;34m⠒\x1b[0m\x1b[0;34m⠒\x1b[0m\x1b[0;34m⠒\x1b[0m\x1b[0;32m2\x1b[0m\x1b[0;34m⠒\x1b[0m\x1b[0;34m⠒\x1b[0m\x1b[0;34m⠒\x1b[0m
+\x1b[0;31mabc\x1b[0m\x1b[0;32m1\x1b[0m\x1b[0;31mefg\x1b[0m
+\x1b[0;34m⠒⠒⠒\x1b[0m\x1b[0;32m2\x1b[0m\x1b[0;34m⠒⠒⠒\x1b[0m
 ⠀⠀⠀\x1b[0;33m⢸\x1b[0m⠀⠀⠀
 "
         );
     }
+
+    // View.
+
+    #[test]
+    fn view_draws_through_to_parent() {
+        let mut canvas = TextCanvas::new(4, 2);
+
+        let mut view = canvas.view(2, 4, 4, 4);
+        view.set_pixel(0, 0, true);
+        view.set_pixel(3, 3, true);
+
+        assert_eq!(view.get_pixel(0, 0), Some(true));
+
+        assert_eq!(
+            canvas.buffer,
+            [
+                [false, false, false, false, false, false, false, false],
+                [false, false, false, false, false, false, false, false],
+                [false, false, false, false, false, false, false, false],
+                [false, false, false, false, false, false, false, false],
+                [false, false, true, false, false, false, false, false],
+                [false, false, false, false, false, false, false, false],
+                [false, false, false, false, false, false, false, false],
+                [false, false, false, false, false, true, false, false],
+            ]
+        );
+    }
+
+    #[test]
+    fn view_clips_to_region() {
+        let mut canvas = TextCanvas::new(4, 2);
+
+        let mut view = canvas.view(2, 4, 4, 4);
+        view.stroke_line(-10, 1, 10, 1);
+        view.fill_rect(-10, 3, 20, 20);
+
+        assert_eq!(view.get_pixel(-1, 1), None);
+        assert_eq!(view.get_pixel(4, 1), None);
+
+        assert_eq!(
+            canvas.to_string(),
+            "⠀⠀⠀⠀
+⠀⣒⣒⠀
+"
+        );
+    }
+
+    #[test]
+    fn view_dimensions() {
+        let mut canvas = TextCanvas::new(15, 5);
+
+        let view = canvas.view(2, 4, 10, 8);
+
+        assert_eq!(view.w(), 9);
+        assert_eq!(view.h(), 7);
+        assert_eq!(view.cx(), 5);
+        assert_eq!(view.cy(), 4);
+    }
+
+    #[test]
+    fn view_text_is_offset_and_clipped() {
+        let mut canvas = TextCanvas::new(5, 3);
+
+        let mut view = canvas.view(2, 4, 6, 4);
+        view.draw_text("abcd", 0, 0);
+        view.draw_text("efgh", 0, 1);
+
+        assert_eq!(canvas.to_string(), "⠀⠀⠀⠀⠀\n⠀abc⠀\n⠀⠀⠀⠀⠀\n");
+    }
+
+    #[test]
+    fn view_with_color() {
+        let mut canvas = TextCanvas::new(3, 1);
+
+        let mut view = canvas.view(2, 0, 2, 4);
+        view.set_color(Color::new().red());
+        view.fill_rect(0, 0, 6, 4);
+
+        assert_eq!(canvas.to_string(), "⠀\x1b[0;31m⣿\x1b[0m⠀\n");
+    }
+
+    #[test]
+    fn view_does_not_clip_parent() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        let mut view = canvas.view(0, 0, 1, 1);
+        view.fill_rect(0, 0, 4, 4);
+        canvas.set_pixel(3, 3, true);
+
+        assert_eq!(canvas.to_string(), "⠁⢀\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "CanvasView's minimal size is 1×1.")]
+    fn view_size_zero() {
+        let mut canvas = TextCanvas::new(2, 1);
+
+        let _ = canvas.view(0, 0, 0, 1);
+    }
 }
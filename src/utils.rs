@@ -279,6 +279,12 @@ impl GameLoop<'_> {
     }
 }
 
+impl Default for GameLoop<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;